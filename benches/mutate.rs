@@ -0,0 +1,116 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use genomic::columnar::ColumnarMutator;
+use genomic::prelude::*;
+use genomic::wrapper::{FixedBits, UniformCh};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn mutate_small(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1408);
+    let mut genome = (0u8, 0u8);
+
+    c.bench_function("mutate (u8, u8) x1M", |b| {
+        b.iter(|| {
+            for _ in 0..1_000_000 {
+                genomic::mutate(black_box(&mut genome), black_box(0.1), &mut rng);
+            }
+        })
+    });
+}
+
+fn mutate_large(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1408);
+    let mut genome = vec![0u64; 1024];
+
+    c.bench_function("mutate Vec<u64> (1024)", |b| {
+        b.iter(|| genomic::mutate(black_box(&mut genome), black_box(0.1), &mut rng))
+    });
+}
+
+fn crossover_small(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1408);
+    let mut left = (0u8, 0u8);
+    let mut right = (1u8, 1u8);
+
+    c.bench_function("crossover (u8, u8) x1M", |b| {
+        b.iter(|| {
+            for _ in 0..1_000_000 {
+                genomic::crossover(
+                    black_box(&mut left),
+                    black_box(&mut right),
+                    CrossoverMethod::Uniform(0.5),
+                    &mut rng,
+                );
+            }
+        })
+    });
+}
+
+#[derive(Clone)]
+struct Particle {
+    position: f64,
+    flags: u8,
+    alive: bool,
+}
+
+impl Genome for Particle {
+    fn mutate(&mut self, mutator: &mut Mutator<impl genomic::Rng>) {
+        mutator
+            .with(&mut UniformCh::new(0.0, 1.0), &mut self.position)
+            .with(&mut FixedBits::new(4), &mut self.flags)
+            .chromosome(&mut self.alive);
+    }
+
+    fn crossover(&mut self, _other: &mut Self, _crossover: &mut Crossover<impl genomic::Rng>) {
+        unimplemented!()
+    }
+
+    fn size_hint(&self) -> usize {
+        3
+    }
+}
+
+fn mutate_columnar(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1408);
+    let size = 100_000;
+    let mut group = c.benchmark_group("mutate 100K particles");
+
+    let mut particles = vec![
+        Particle {
+            position: 0.5,
+            flags: 0,
+            alive: true,
+        };
+        size
+    ];
+    group.bench_function("array of structs", |b| {
+        b.iter(|| {
+            for particle in particles.iter_mut() {
+                genomic::mutate(black_box(particle), black_box(0.1), &mut rng);
+            }
+        })
+    });
+
+    let mut positions = vec![0.5f64; size];
+    let mut flags = vec![0u8; size];
+    let mut alive = vec![true; size];
+    let mut columns = ColumnarMutator::new()
+        .column(&mut positions, UniformCh::new(0.0, 1.0))
+        .column(&mut flags, FixedBits::new(4))
+        .chromosomes(&mut alive);
+    group.bench_function("struct of arrays", |b| {
+        b.iter(|| {
+            columns.mutate(black_box(0.1), &mut rng);
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    mutate_small,
+    mutate_large,
+    crossover_small,
+    mutate_columnar
+);
+criterion_main!(benches);
@@ -0,0 +1,185 @@
+//! Evolves the weights of a small fully-connected network towards a target set of weights.
+//!
+//! Each weight is mutated by adding some gaussian noise to it,
+//! and the crossover operation swaps individual weights between the two parents.
+
+use genomic::prelude::*;
+use genomic::wrapper::MutationWrapper;
+use rand::{Rng, SeedableRng};
+
+/// Adds gaussian noise with a standard deviation of `sigma` to the mutated weights.
+///
+/// Each weight has a probability of `rate` of being mutated.
+struct Gaussian {
+    sigma: f64,
+}
+
+impl MutationWrapper<&mut f64> for Gaussian {
+    fn mutate_with(&mut self, value: &mut f64, mutator: &mut Mutator<impl Rng>) {
+        let rate = mutator.get_rate();
+        let rng = mutator.get_rng();
+
+        if rng.gen_bool(rate) {
+            // Box-Muller transform
+            let u: f64 = 1.0 - rng.gen::<f64>();
+            let v: f64 = rng.gen();
+            let normal = (-2.0 * u.ln()).sqrt() * (std::f64::consts::TAU * v).cos();
+
+            *value += normal * self.sigma;
+        }
+    }
+}
+
+/// A fully-connected layer, with its weights stored as a flat, row-major matrix.
+#[derive(Clone, Debug)]
+struct Layer {
+    inputs: usize,
+    outputs: usize,
+    weights: Vec<f64>,
+}
+
+impl Layer {
+    fn new(inputs: usize, outputs: usize) -> Self {
+        Self {
+            inputs,
+            outputs,
+            weights: vec![0.0; inputs * outputs],
+        }
+    }
+
+    fn forward(&self, input: &[f64]) -> Vec<f64> {
+        debug_assert_eq!(input.len(), self.inputs);
+
+        self.weights
+            .chunks(self.inputs)
+            .map(|row| {
+                let sum: f64 = row.iter().zip(input).map(|(w, x)| w * x).sum();
+                sum.tanh()
+            })
+            .collect()
+    }
+}
+
+impl Genome for Layer {
+    fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+        mutator.iter_with(&mut Gaussian { sigma: 0.1 }, &mut self.weights);
+    }
+
+    fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+        for (left, right) in self.weights.iter_mut().zip(other.weights.iter_mut()) {
+            crossover.chromosome(left, right);
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        self.inputs * self.outputs
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Network {
+    layers: Vec<Layer>,
+}
+
+impl Network {
+    /// Creates a network with all of its weights set to zero; `shape` lists the size of each layer.
+    fn new(shape: &[usize]) -> Self {
+        Self {
+            layers: shape
+                .windows(2)
+                .map(|pair| Layer::new(pair[0], pair[1]))
+                .collect(),
+        }
+    }
+
+    fn forward(&self, input: &[f64]) -> Vec<f64> {
+        self.layers
+            .iter()
+            .fold(input.to_vec(), |values, layer| layer.forward(&values))
+    }
+
+    /// Returns the squared distance between the weights of `self` and those of `target`.
+    fn error(&self, target: &Network) -> f64 {
+        self.layers
+            .iter()
+            .zip(target.layers.iter())
+            .flat_map(|(layer, target)| layer.weights.iter().zip(target.weights.iter()))
+            .map(|(w, t)| (w - t) * (w - t))
+            .sum()
+    }
+}
+
+impl Genome for Network {
+    fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+        mutator.iter(&mut self.layers);
+    }
+
+    fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+        crossover.iter(&mut self.layers, &mut other.layers);
+    }
+
+    fn size_hint(&self) -> usize {
+        self.layers.size_hint()
+    }
+}
+
+/// Runs a simple truncation-selection loop for `generations` generations, returning the best network.
+fn evolve(target: &Network, generations: usize, rng: &mut impl Rng) -> Network {
+    let shape = [2, 4, 1];
+    let mut population = vec![Network::new(&shape); 32];
+
+    for _ in 0..generations {
+        population.sort_by(|a, b| a.error(target).total_cmp(&b.error(target)));
+        population.truncate(8);
+
+        let mut next = population.clone();
+        while next.len() < 32 {
+            let left = &population[rng.gen_range(0..population.len())];
+            let right = &population[rng.gen_range(0..population.len())];
+            let (child_left, child_right) =
+                genomic::reproduce(left, right, CrossoverMethod::Uniform(1.0), 0.2, rng);
+            next.push(child_left);
+            next.push(child_right);
+        }
+        population = next;
+    }
+
+    population
+        .into_iter()
+        .min_by(|a, b| a.error(target).total_cmp(&b.error(target)))
+        .expect("The population should not be empty")
+}
+
+fn target_network() -> Network {
+    let mut target = Network::new(&[2, 4, 1]);
+    target.layers[0].weights = vec![0.5, -0.5, 1.0, 0.25, -1.0, 0.75, 0.0, -0.25];
+    target.layers[1].weights = vec![1.0, -1.0, 0.5, -0.5];
+    target
+}
+
+fn main() {
+    let target = target_network();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+
+    let best = evolve(&target, 200, &mut rng);
+
+    println!("Error: {}", best.error(&target));
+    println!("Output for [1, 1]: {:?}", best.forward(&[1.0, 1.0]));
+    println!("Target for [1, 1]: {:?}", target.forward(&[1.0, 1.0]));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_evolve_towards_target() {
+        let target = target_network();
+        let initial_error = Network::new(&[2, 4, 1]).error(&target);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1380);
+
+        let best = evolve(&target, 200, &mut rng);
+
+        assert!(best.error(&target) < initial_error * 0.1);
+    }
+}
@@ -8,6 +8,7 @@ use super::*;
 ///
 /// **Deprecated:** use [`wrapper::FixedBits`](crate::wrapper::FixedBits) instead.
 #[deprecated(note = "use genomic::wrapper::FixedBits instead")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedBits<T> {
     pub value: T,
     pub bits: u8,
@@ -17,16 +18,28 @@ impl<T> FixedBits<T> {
     pub fn new(value: T, bits: u8) -> Self {
         Self { value, bits }
     }
+
+    /// Returns the wrapped value, discarding the bit count.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> crate::genome::FixedSizeGenome for FixedBits<T>
+where
+    FixedBits<T>: Chromosome,
+{
+    const SIZE: usize = 1;
 }
 
 macro_rules! impl_fixed_uint {
     ( $type:ty ) => {
         impl Chromosome for FixedBits<$type> {
             fn mutate(&mut self, rate: f64, rng: &mut impl Rng) {
-                debug_assert!(rate <= 1.0);
                 debug_assert!(rate >= 0.0);
-                let distribution = rand::distributions::Bernoulli::new(rate * 0.5)
-                    .expect("`rate` should be between 0.0 and 1.0");
+                let distribution =
+                    rand::distributions::Bernoulli::new((rate * 0.5).clamp(0.0, 1.0))
+                        .expect("`rate` should not be NaN");
 
                 for (bit, should_flip) in (0..self.bits).zip(distribution.sample_iter(rng)) {
                     if should_flip {
@@ -48,10 +61,10 @@ macro_rules! impl_fixed_int {
     ( $type:ty ) => {
         impl Chromosome for FixedBits<$type> {
             fn mutate(&mut self, rate: f64, rng: &mut impl Rng) {
-                debug_assert!(rate <= 1.0);
                 debug_assert!(rate >= 0.0);
-                let distribution = rand::distributions::Bernoulli::new(rate * 0.5)
-                    .expect("`rate` should be between 0.0 and 1.0");
+                let distribution =
+                    rand::distributions::Bernoulli::new((rate * 0.5).clamp(0.0, 1.0))
+                        .expect("`rate` should not be NaN");
 
                 if self.bits != <$type>::BITS as u8 {
                     let depth = 1 << (self.bits - 1);
@@ -93,11 +106,13 @@ impl_fixed_uint!(u16);
 impl_fixed_uint!(u32);
 impl_fixed_uint!(u64);
 impl_fixed_uint!(u128);
+impl_fixed_uint!(usize);
 
 impl_fixed_int!(i8);
 impl_fixed_int!(i16);
 impl_fixed_int!(i32);
 impl_fixed_int!(i64);
 impl_fixed_int!(i128);
+impl_fixed_int!(isize);
 
 // TODO: implement this for floats?
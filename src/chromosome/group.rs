@@ -0,0 +1,141 @@
+use super::*;
+
+/// Wraps a tuple of chromosomes so that it behaves as a single chromosome.
+///
+/// Tuples are [genomes](crate::genome::Genome) on their own, where each element is a separate chromosome:
+/// a crossover may thus swap some of the elements of two tuples, but not the others.
+/// Wrapping the tuple in `TupleGroupCh` instead makes its elements recombine together, as a single gene,
+/// and the tuple only counts for one chromosome in [size_hint](crate::genome::Genome::size_hint).
+///
+/// When mutated, each element of the tuple is mutated with the same rate.
+/// [Chromosome] is implemented for tuples of up to 4 chromosomes.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::chromosome::TupleGroupCh;
+///
+/// struct Palette {
+///     // The three components of each color are always inherited from the same parent
+///     colors: [TupleGroupCh<(u8, u8, u8)>; 4],
+/// }
+///
+/// impl Genome for Palette {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.genome(&mut self.colors);
+///     }
+///
+///     fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///         crossover.genome(&mut self.colors, &mut other.colors);
+///     }
+///
+///     fn size_hint(&self) -> usize {
+///         self.colors.size_hint()
+///     }
+/// }
+///
+/// # let palette = Palette { colors: std::array::from_fn(|_| TupleGroupCh((0, 0, 0))) };
+/// assert_eq!(palette.size_hint(), 4);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TupleGroupCh<T>(pub T);
+
+impl<T> TupleGroupCh<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the wrapped tuple.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for TupleGroupCh<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> crate::genome::FixedSizeGenome for TupleGroupCh<T>
+where
+    TupleGroupCh<T>: Chromosome,
+{
+    const SIZE: usize = 1;
+}
+
+macro_rules! impl_tuple_group_ch {
+    ( $( $name:ident => $id:tt ),+ ) => {
+        impl<$($name : Chromosome),+> Chromosome for TupleGroupCh<($($name,)+)> {
+            fn mutate(&mut self, rate: f64, rng: &mut impl Rng) {
+                $(self.0.$id.mutate(rate, rng);)+
+            }
+        }
+    }
+}
+
+impl_tuple_group_ch!(C1 => 0);
+impl_tuple_group_ch!(C1 => 0, C2 => 1);
+impl_tuple_group_ch!(C1 => 0, C2 => 1, C3 => 2);
+impl_tuple_group_ch!(C1 => 0, C2 => 1, C3 => 2, C4 => 3);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::genome::{FixedSizeGenome, Genome};
+    use crate::traverse::CrossoverMethod;
+
+    #[test]
+    fn test_grouped_crossover() {
+        let mut rng = crate::test_rng();
+        let left = (0..16)
+            .map(|x| TupleGroupCh((x, x as u16, x % 2 == 0)))
+            .collect::<Vec<_>>();
+        let right = (0..16)
+            .map(|x| TupleGroupCh((100 + x, 100 + x as u16, x % 2 == 1)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(left.size_hint(), 16);
+        assert_eq!(<TupleGroupCh<(u8, u8, u8, u8)>>::SIZE, 1);
+
+        let mut swapped = 0;
+        for _ in 0..100 {
+            let mut child_left = left.clone();
+            let mut child_right = right.clone();
+            crate::crossover(
+                &mut child_left,
+                &mut child_right,
+                CrossoverMethod::Uniform(1.0),
+                &mut rng,
+            );
+
+            for (index, (child_left, child_right)) in
+                child_left.iter().zip(&child_right).enumerate()
+            {
+                if *child_left == right[index] {
+                    assert_eq!(*child_right, left[index]);
+                    swapped += 1;
+                } else {
+                    assert_eq!(*child_left, left[index]);
+                    assert_eq!(*child_right, right[index]);
+                }
+            }
+        }
+        assert!((400..1200).contains(&swapped));
+    }
+
+    #[test]
+    fn test_grouped_mutate() {
+        let mut rng = crate::test_rng();
+        let mut changed = [false; 2];
+        for _ in 0..10 {
+            let mut group = TupleGroupCh((0u8, 0u32));
+            Chromosome::mutate(&mut group, 1.0, &mut rng);
+            changed[0] |= group.0 .0 != 0;
+            changed[1] |= group.0 .1 != 0;
+        }
+        assert_eq!(changed, [true, true]);
+    }
+}
@@ -6,6 +6,9 @@ pub use uniform::*;
 mod fixed;
 pub use fixed::*;
 
+mod group;
+pub use group::TupleGroupCh;
+
 pub trait Chromosome {
     /// Mutates the chromosome, with `rate` being a number between `0.0` and `1.0`.
     ///
@@ -13,20 +16,77 @@ pub trait Chromosome {
     fn mutate(&mut self, rate: f64, rng: &mut impl Rng);
 }
 
+/// A chromosome whose mutation reads (and may update) a context shared with other chromosomes,
+/// like the step sizes of a self-adaptive evolution strategy.
+///
+/// These chromosomes are mutated with [Mutator::chromosome_ctx](crate::traverse::Mutator::chromosome_ctx),
+/// which passes the context along; the context is usually another field of the genome.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::chromosome::ContextualChromosome;
+/// use genomic::prelude::*;
+/// use genomic::Rng;
+///
+/// struct StepSize(f64);
+///
+/// impl ContextualChromosome<StepSize> for f64 {
+///     fn mutate_ctx(&mut self, rate: f64, ctx: &mut StepSize, rng: &mut impl Rng) {
+///         *self += rate * ctx.0 * rng.gen_range(-1.0..1.0);
+///     }
+/// }
+///
+/// struct Point {
+///     coordinates: [f64; 3],
+///     step: StepSize,
+/// }
+///
+/// impl Genome for Point {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         // Let the step size itself evolve, then use it for every coordinate
+///         mutator.custom(|rate, rng| self.step.0 *= (rate * rng.gen_range(-0.2..0.2)).exp());
+///
+///         for coordinate in self.coordinates.iter_mut() {
+///             mutator.chromosome_ctx(coordinate, &mut self.step);
+///         }
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         4
+///     }
+/// }
+/// ```
+pub trait ContextualChromosome<Ctx: ?Sized> {
+    /// Mutates the chromosome like [Chromosome::mutate], with access to the shared context `ctx`.
+    fn mutate_ctx(&mut self, rate: f64, ctx: &mut Ctx, rng: &mut impl Rng);
+}
+
 macro_rules! impl_ch_int {
     ( $type:ty ) => {
         impl Chromosome for $type {
+            #[inline]
             fn mutate(&mut self, rate: f64, rng: &mut impl Rng) {
-                debug_assert!(rate <= 1.0);
                 debug_assert!(rate >= 0.0);
-                let distribution = rand::distributions::Bernoulli::new(rate * 0.5)
-                    .expect("`rate` should be between 0.0 and 1.0");
+                let distribution =
+                    rand::distributions::Bernoulli::new((rate * 0.5).clamp(0.0, 1.0))
+                        .expect("`rate` should not be NaN");
 
-                for (bit, should_flip) in (0..<$type>::BITS).zip(distribution.sample_iter(rng)) {
-                    if should_flip {
-                        *self ^= 1 << bit;
+                // The flipped bits are accumulated in a mask and applied at once,
+                // which lets small integers be mutated without going through `sample_iter`.
+                let mut mask: $type = 0;
+                for bit in 0..<$type>::BITS {
+                    if distribution.sample(rng) {
+                        mask |= 1 << bit;
                     }
                 }
+
+                *self ^= mask;
             }
         }
     };
@@ -37,11 +97,55 @@ impl_ch_int!(u16);
 impl_ch_int!(u32);
 impl_ch_int!(u64);
 impl_ch_int!(u128);
+impl_ch_int!(usize);
 impl_ch_int!(i8);
 impl_ch_int!(i16);
 impl_ch_int!(i32);
 impl_ch_int!(i64);
 impl_ch_int!(i128);
+impl_ch_int!(isize);
+
+/// Implements [Chromosome] for a fieldless enum, given the list of its variants.
+///
+/// When mutated, the enum has a probability of `rate * 0.5` to take a random variant
+/// (which may be the same as its current variant).
+/// Enums with a single variant are never modified, and explicit discriminants are supported.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum Direction {
+///     North,
+///     East,
+///     South = 10,
+///     West,
+/// }
+///
+/// genomic::impl_chromosome_enum!(Direction { North, East, South, West });
+///
+/// let mut direction = Direction::North;
+/// genomic::mutate(&mut direction, 1.0, &mut rand::thread_rng());
+/// ```
+#[macro_export]
+macro_rules! impl_chromosome_enum {
+    ( $name:ident { $( $variant:ident ),+ $(,)? } ) => {
+        impl $crate::chromosome::Chromosome for $name {
+            fn mutate(&mut self, rate: f64, rng: &mut impl $crate::Rng) {
+                let variants = [$( $name::$variant ),+];
+                if variants.len() > 1 && rng.gen_bool((rate * 0.5).clamp(0.0, 1.0)) {
+                    // Sampled as a `u64`, like the other indices of the crate, so that seeds are portable
+                    let index = rng.gen_range(0..variants.len() as u64) as usize;
+                    if let Some(variant) = variants.into_iter().nth(index) {
+                        *self = variant;
+                    }
+                }
+            }
+        }
+    };
+}
 
 impl Chromosome for () {
     /// Does nothing
@@ -58,8 +162,215 @@ impl<T: Chromosome + ?Sized> Chromosome for Box<T> {
 
 impl Chromosome for bool {
     fn mutate(&mut self, rate: f64, rng: &mut impl Rng) {
-        if rng.gen_bool(rate * 0.5) {
+        if rng.gen_bool((rate * 0.5).clamp(0.0, 1.0)) {
             *self = !*self;
         }
     }
 }
+
+/// With a probability of `rate * 0.5`, toggles between `None` and `Some`,
+/// and otherwise mutates the inner value if there is one.
+///
+/// When going from `None` to `Some`, a fresh value is generated by fully mutating `T::default()` (with a rate of `1.0`).
+/// This means that at a rate of `1.0`, `None` and `Some` are equally likely.
+/// To control how often `None` appears, or how fresh values are generated, use [crate::wrapper::OptionCh] instead.
+impl<T: Chromosome + Default> Chromosome for Option<T> {
+    fn mutate(&mut self, rate: f64, rng: &mut impl Rng) {
+        if rng.gen_bool((rate * 0.5).clamp(0.0, 1.0)) {
+            *self = match self {
+                Some(_) => None,
+                None => {
+                    let mut value = T::default();
+                    value.mutate(1.0, rng);
+                    Some(value)
+                }
+            };
+        } else if let Some(value) = self {
+            value.mutate(rate, rng);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #![allow(deprecated)]
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    enum Suit {
+        Clubs = 1,
+        Diamonds = 2,
+        Hearts = 4,
+        Spades = 8,
+    }
+
+    impl_chromosome_enum!(Suit {
+        Clubs,
+        Diamonds,
+        Hearts,
+        Spades,
+    });
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Single {
+        Only,
+    }
+
+    impl_chromosome_enum!(Single { Only });
+
+    #[test]
+    fn test_enum() {
+        let mut rng = crate::test_rng();
+        let mut suit = Suit::Clubs;
+        let mut seen = std::collections::HashSet::new();
+
+        for _ in 0..100 {
+            suit.mutate(1.0, &mut rng);
+            seen.insert(suit);
+        }
+        assert_eq!(seen.len(), 4);
+
+        let mut single = Single::Only;
+        single.mutate(1.0, &mut rng);
+        assert_eq!(single, Single::Only);
+    }
+
+    #[test]
+    fn test_rate_above_one() {
+        let mut rng = crate::test_rng();
+        let rate = 1.0 + f64::EPSILON;
+
+        let mut value = 0u32;
+        value.mutate(rate, &mut rng);
+        let mut value = true;
+        value.mutate(rate, &mut rng);
+        let mut value = FixedBits::new(0i16, 8);
+        value.mutate(rate, &mut rng);
+
+        let mut value = 0i64;
+        crate::Mutator::new(rate, &mut rng)
+            .with(&mut crate::wrapper::FixedBits::new(12), &mut value);
+
+        // Rates passed to `mutate` are not clamped beforehand
+        let mut value = [0u8; 4];
+        crate::mutate(&mut value, 2.5, &mut rng);
+    }
+
+    #[test]
+    fn test_pointer_sized() {
+        let mut rng = crate::test_rng();
+
+        let mut value = 0usize;
+        value.mutate(1.0, &mut rng);
+        assert_ne!(value, 0);
+
+        let mut value = 0isize;
+        value.mutate(0.0, &mut rng);
+        assert_eq!(value, 0);
+
+        // A struct with a `usize` field can use the bare implementation
+        let mut individual = (0usize, [0isize; 2]);
+        crate::mutate(&mut individual, 1.0, &mut rng);
+        assert!(individual.0 != 0 && individual.1.iter().all(|&value| value != 0));
+
+        let mut uniform = UniformCh::new(5usize, 0, 10);
+        uniform.mutate(1.0, &mut rng);
+        assert!(uniform.value <= 10);
+
+        let mut fixed = FixedBits::new(0isize, 4);
+        fixed.mutate(1.0, &mut rng);
+        assert!((-8..8).contains(&fixed.value));
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let mut rng = crate::test_rng();
+
+        let mut uniform = UniformCh::new(5i32, 0, 10);
+        uniform.mutate(1.0, &mut rng);
+        let value = uniform.value;
+        assert_eq!(uniform.into_inner(), value);
+        assert!((0..=10).contains(&value));
+
+        let mut fixed = FixedBits::new(3u8, 2);
+        fixed.mutate(1.0, &mut rng);
+        let value = fixed.value;
+        assert_eq!(fixed.into_inner(), value);
+        assert!(value < 4);
+    }
+
+    #[test]
+    fn test_uniform_into_wrapper() {
+        let (value, wrapper): (i32, crate::wrapper::UniformCh<i32>) =
+            UniformCh::new(5i32, -3, 10).into();
+        assert_eq!(value, 5);
+        assert_eq!((wrapper.min, wrapper.max), (-3, 10));
+        assert_eq!(wrapper.boundary, crate::wrapper::BoundaryPolicy::Clamp);
+
+        let (value, wrapper): (f32, _) = UniformCh::new(0.25f32, 0.0, 1.0).into();
+        assert_eq!(value, 0.25);
+        assert_eq!(wrapper, crate::wrapper::UniformCh::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_int_matches_per_bit() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(1408);
+        let mut reference_rng = rng.clone();
+
+        for rate in [0.0, 0.1, 0.5, 1.0, 2.0] {
+            for _ in 0..100 {
+                let mut value = 0b1010_0101u8;
+                value.mutate(rate, &mut rng);
+
+                let mut expected = 0b1010_0101u8;
+                for bit in 0..u8::BITS {
+                    if reference_rng.gen_bool((rate * 0.5).clamp(0.0, 1.0)) {
+                        expected ^= 1 << bit;
+                    }
+                }
+
+                assert_eq!(value, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_option() {
+        let mut rng = crate::test_rng();
+        let mut value: Option<u8> = None;
+        let (mut some, mut none) = (0, 0);
+        let mut values = std::collections::HashSet::new();
+
+        for _ in 0..1000 {
+            value.mutate(1.0, &mut rng);
+            match value {
+                Some(inner) => {
+                    some += 1;
+                    values.insert(inner);
+                }
+                None => none += 1,
+            }
+        }
+        assert!(some > 300 && none > 300);
+        assert!(values.len() > 50);
+
+        // The inner value is mutated while the option stays `Some`
+        let mut changed = 0;
+        for _ in 0..1000 {
+            let mut value = Some(0u8);
+            value.mutate(0.1, &mut rng);
+            if let Some(inner) = value {
+                if inner != 0 {
+                    changed += 1;
+                }
+            }
+        }
+        assert!(changed > 100);
+
+        let mut value = Some(17u8);
+        value.mutate(0.0, &mut rng);
+        assert_eq!(value, Some(17));
+    }
+}
@@ -7,8 +7,28 @@ use super::*;
 /// If `rate` is `1.0`, then the mutated value will become any number between `min` and `max`.
 ///
 /// **Deprecated:** use [`wrapper::UniformCh`](crate::wrapper::UniformCh) instead
+///
+/// # Migration
+///
+/// The new wrapper only holds the bounds, while the value is stored directly in your genome.
+/// An existing chromosome can be split into its value and the equivalent wrapper with [From]:
+///
+/// ```rust
+/// # #![allow(deprecated)]
+/// use genomic::wrapper;
+///
+/// let old = genomic::chromosome::UniformCh::new(0.5f64, 0.0, 1.0);
+/// let (value, uniform): (f64, wrapper::UniformCh<f64>) = old.into();
+///
+/// assert_eq!(value, 0.5);
+/// assert_eq!(uniform, wrapper::UniformCh::new(0.0, 1.0));
+/// ```
+///
+/// Within [Genome::mutate](crate::genome::Genome::mutate), `mutator.chromosome(&mut self.weight)` then becomes
+/// `mutator.with(&mut UniformCh::new(0.0, 1.0), &mut self.weight)`, where `self.weight` is now a plain `f64`.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[deprecated(note = "use wrapper::UniformCh instead")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformCh<T> {
     pub value: T,
     pub min: T,
@@ -21,6 +41,28 @@ impl<T> UniformCh<T> {
     pub fn new(value: T, min: T, max: T) -> Self {
         Self { value, min, max }
     }
+
+    /// Returns the wrapped value, discarding the bounds.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+/// Splits the chromosome into its value and the [wrapper](crate::wrapper::UniformCh) holding its bounds.
+impl<T> From<UniformCh<T>> for (T, crate::wrapper::UniformCh<T>) {
+    fn from(uniform: UniformCh<T>) -> Self {
+        (
+            uniform.value,
+            crate::wrapper::UniformCh::new(uniform.min, uniform.max),
+        )
+    }
+}
+
+impl<T> crate::genome::FixedSizeGenome for UniformCh<T>
+where
+    UniformCh<T>: Chromosome,
+{
+    const SIZE: usize = 1;
 }
 
 macro_rules! impl_uniform_int {
@@ -64,6 +106,12 @@ macro_rules! impl_uniform_float {
                 let range = range * rate as $type;
                 let half_range = range / 2.0;
 
+                // An empty range can't be sampled from, and wouldn't move the value anyway
+                if range <= 0.0 {
+                    self.value = self.value.clamp(self.min, self.max);
+                    return;
+                }
+
                 let range = if self.value - self.min < half_range {
                     self.min..(self.min + range)
                 } else if self.max - self.value < half_range {
@@ -92,11 +140,13 @@ impl_uniform_int!(u16);
 impl_uniform_int!(u32);
 impl_uniform_int!(u64);
 impl_uniform_int!(u128);
+impl_uniform_int!(usize);
 impl_uniform_int!(i8);
 impl_uniform_int!(i16);
 impl_uniform_int!(i32);
 impl_uniform_int!(i64);
 impl_uniform_int!(i128);
+impl_uniform_int!(isize);
 
 impl_uniform_float!(f32);
 impl_uniform_float!(f64);
@@ -0,0 +1,277 @@
+//! Mutation of populations stored in a columnar (struct-of-arrays) layout.
+//!
+//! Instead of a `Vec` of individuals, each field of the individuals is stored in its own `Vec`, called a column.
+//! [ColumnarMutator] applies the operator of each column to the whole column in one pass,
+//! which keeps the accesses to memory sequential.
+
+use rand::Rng;
+
+use crate::chromosome::Chromosome;
+use crate::traverse::Mutator;
+use crate::wrapper::MutationWrapper;
+
+/// A column of values, along with how they are mutated.
+///
+/// This trait is implemented by the columns added through [ColumnarMutator::column] and [ColumnarMutator::chromosomes],
+/// and only needs to be implemented manually for columns that aren't stored as a slice.
+pub trait Column<R: Rng> {
+    /// The number of values in the column, which is the number of individuals.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the column contains no values.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Mutates the value of the individual at `index`.
+    fn mutate_row(&mut self, index: usize, mutator: &mut Mutator<&mut R>);
+
+    /// Mutates every value of the column.
+    fn mutate_all(&mut self, mutator: &mut Mutator<&mut R>);
+}
+
+struct WrappedColumn<'a, T, W> {
+    values: &'a mut [T],
+    wrapper: W,
+}
+
+impl<'a, T, W, R> Column<R> for WrappedColumn<'a, T, W>
+where
+    W: for<'b> MutationWrapper<&'b mut T>,
+    R: Rng,
+{
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn mutate_row(&mut self, index: usize, mutator: &mut Mutator<&mut R>) {
+        mutator.with(&mut self.wrapper, &mut self.values[index]);
+    }
+
+    fn mutate_all(&mut self, mutator: &mut Mutator<&mut R>) {
+        mutator.iter_with(&mut self.wrapper, self.values.iter_mut());
+    }
+}
+
+struct ChromosomeColumn<'a, T> {
+    values: &'a mut [T],
+}
+
+impl<'a, T: Chromosome, R: Rng> Column<R> for ChromosomeColumn<'a, T> {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn mutate_row(&mut self, index: usize, mutator: &mut Mutator<&mut R>) {
+        mutator.chromosome(&mut self.values[index]);
+    }
+
+    fn mutate_all(&mut self, mutator: &mut Mutator<&mut R>) {
+        for value in self.values.iter_mut() {
+            mutator.chromosome(value);
+        }
+    }
+}
+
+/// Mutates a population stored as a set of columns of the same length, one per field of the individuals.
+///
+/// Mutating the whole population with [ColumnarMutator::mutate] goes through each column in turn,
+/// while [ColumnarMutator::mutate_row] mutates the fields of a single individual.
+/// Both give each value the same probability of being mutated as if the population was stored as a `Vec` of individuals,
+/// but the order in which the values are mutated (and thus the random numbers they receive) differs.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::columnar::ColumnarMutator;
+/// use genomic::wrapper::UniformCh;
+///
+/// let mut positions = vec![0.5f64; 100];
+/// let mut alive = vec![true; 100];
+///
+/// let mut rng = rand::thread_rng();
+/// ColumnarMutator::new()
+///     .column(&mut positions, UniformCh::new(0.0, 1.0))
+///     .chromosomes(&mut alive)
+///     .mutate(0.1, &mut rng);
+///
+/// assert!(positions.iter().all(|position| (0.0..=1.0).contains(position)));
+/// ```
+pub struct ColumnarMutator<'a, R: Rng> {
+    columns: Vec<Box<dyn Column<R> + 'a>>,
+}
+
+impl<'a, R: Rng> ColumnarMutator<'a, R> {
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+        }
+    }
+
+    /// Adds a column, whose values are mutated using `wrapper`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `values` differs from the length of the columns already added.
+    pub fn column<T, W>(self, values: &'a mut [T], wrapper: W) -> Self
+    where
+        T: 'a,
+        W: for<'b> MutationWrapper<&'b mut T> + 'a,
+    {
+        self.push(Box::new(WrappedColumn { values, wrapper }))
+    }
+
+    /// Adds a column of chromosomes, which are mutated as with [Mutator::chromosome].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `values` differs from the length of the columns already added.
+    pub fn chromosomes<T: Chromosome + 'a>(self, values: &'a mut [T]) -> Self {
+        self.push(Box::new(ChromosomeColumn { values }))
+    }
+
+    /// Adds a column implementing [Column].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `column` differs from the length of the columns already added.
+    pub fn push(mut self, column: Box<dyn Column<R> + 'a>) -> Self {
+        if let Some(first) = self.columns.first() {
+            assert_eq!(
+                first.len(),
+                column.len(),
+                "All of the columns should have the same length"
+            );
+        }
+        self.columns.push(column);
+        self
+    }
+
+    /// Returns the number of individuals, or `0` if there are no columns.
+    pub fn len(&self) -> usize {
+        self.columns.first().map(|column| column.len()).unwrap_or(0)
+    }
+
+    /// Returns `true` if there are no columns or if the columns are empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Mutates every individual, one column at a time.
+    pub fn mutate(&mut self, rate: f64, rng: &mut R) -> &mut Self {
+        let mut mutator = Mutator::new(rate, rng);
+        for column in self.columns.iter_mut() {
+            column.mutate_all(&mut mutator);
+        }
+        self
+    }
+
+    /// Mutates the individual at `index`, leaving the other individuals untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn mutate_row(&mut self, index: usize, rate: f64, rng: &mut R) -> &mut Self {
+        let mut mutator = Mutator::new(rate, rng);
+        for column in self.columns.iter_mut() {
+            column.mutate_row(index, &mut mutator);
+        }
+        self
+    }
+}
+
+impl<'a, R: Rng> Default for ColumnarMutator<'a, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::wrapper::{FixedBits, UniformCh};
+    use crate::NullRng;
+
+    #[test]
+    fn test_matches_array_of_structs() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Individual {
+            position: f64,
+            flags: u8,
+            alive: bool,
+        }
+
+        let individuals = (0..20)
+            .map(|index| Individual {
+                position: index as f64 / 20.0,
+                flags: index as u8,
+                alive: index % 2 == 0,
+            })
+            .collect::<Vec<_>>();
+
+        let mut positions = individuals.iter().map(|x| x.position).collect::<Vec<_>>();
+        let mut flags = individuals.iter().map(|x| x.flags).collect::<Vec<_>>();
+        let mut alive = individuals.iter().map(|x| x.alive).collect::<Vec<_>>();
+
+        // NullRng always takes the same decisions, so the order of mutation doesn't matter
+        let mut expected = individuals.clone();
+        for individual in expected.iter_mut() {
+            let mut mutator = Mutator::new(0.2, NullRng);
+            mutator
+                .with(&mut UniformCh::new(0.0, 1.0), &mut individual.position)
+                .with(&mut FixedBits::new(4), &mut individual.flags)
+                .chromosome(&mut individual.alive);
+        }
+
+        let mut columns = ColumnarMutator::new()
+            .column(&mut positions, UniformCh::new(0.0, 1.0))
+            .column(&mut flags, FixedBits::new(4))
+            .chromosomes(&mut alive);
+        assert_eq!(columns.len(), 20);
+        columns.mutate(0.2, &mut NullRng);
+        drop(columns);
+
+        let actual = (0..20)
+            .map(|index| Individual {
+                position: positions[index],
+                flags: flags[index],
+                alive: alive[index],
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+        assert_ne!(actual, individuals);
+    }
+
+    #[test]
+    fn test_mutate_row() {
+        let mut rng = crate::test_rng();
+        let mut positions = vec![0.5f64; 10];
+        let mut flags = vec![0u32; 10];
+
+        ColumnarMutator::new()
+            .column(&mut positions, UniformCh::new(0.0, 1.0))
+            .chromosomes(&mut flags)
+            .mutate_row(3, 1.0, &mut rng);
+
+        for index in 0..10 {
+            if index == 3 {
+                assert_ne!(positions[index], 0.5);
+                assert_ne!(flags[index], 0);
+            } else {
+                assert_eq!(positions[index], 0.5);
+                assert_eq!(flags[index], 0);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mismatched_lengths() {
+        let mut positions = vec![0.5f64; 10];
+        let mut flags = vec![0u32; 9];
+
+        let _ = ColumnarMutator::<NullRng>::new()
+            .column(&mut positions, UniformCh::new(0.0, 1.0))
+            .chromosomes(&mut flags);
+    }
+}
@@ -0,0 +1,414 @@
+//! Introspection of the structure of genomes, for tools like genome editors.
+//!
+//! A genome implementing [Describe] returns a [GenomeShape]: a tree whose leaves are its chromosomes,
+//! along with their type and, when known, the bounds of their values.
+//! Since the wrappers of the [wrapper](crate::wrapper) module aren't stored in the genome,
+//! the bounds of a wrapped value are reported by the wrapper itself, through [DescribeWrapper].
+
+use std::any::type_name;
+
+use crate::wrapper::{BoundedCh, DistCh, LogUniformCh, UniformCh, WrappingUniformCh};
+
+/// The structure of a genome, as returned by [Describe::describe].
+#[derive(Clone, Debug, PartialEq)]
+pub enum GenomeShape {
+    /// A single chromosome, or a wrapped value.
+    Chromosome {
+        /// The name of the type of the value, as returned by [std::any::type_name].
+        type_name: &'static str,
+        /// The smallest and largest values that the chromosome can take, if they are known.
+        bounds: Option<(Bound, Bound)>,
+    },
+    /// A list of sub-genomes without names, like the elements of a `Vec` or the members of a tuple.
+    List(Vec<GenomeShape>),
+    /// A structure with named fields.
+    Struct {
+        /// The name of the structure, as displayed to the user; it doesn't need to match the name of the Rust type.
+        type_name: &'static str,
+        /// The names and shapes of the fields, in the order in which [Genome::mutate](crate::genome::Genome::mutate)
+        /// traverses them.
+        fields: Vec<(&'static str, GenomeShape)>,
+    },
+}
+
+/// A bound of a chromosome, as found in [GenomeShape::Chromosome].
+///
+/// Integer bounds are stored exactly, since an `f64` can't represent every `i64` or `u64`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Bound {
+    /// The bound of a signed integer.
+    Int(i128),
+    /// The bound of an unsigned integer.
+    UInt(u128),
+    /// The bound of a floating-point number.
+    Float(f64),
+}
+
+impl Bound {
+    /// Returns the bound as an `f64`, rounding integers that can't be represented exactly.
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Self::Int(value) => value as f64,
+            Self::UInt(value) => value as f64,
+            Self::Float(value) => value,
+        }
+    }
+}
+
+macro_rules! impl_bound_from {
+    ( $variant:ident($inner:ty): $( $type:ty ),+ ) => {
+        $(
+            impl From<$type> for Bound {
+                fn from(value: $type) -> Self {
+                    Self::$variant(value as $inner)
+                }
+            }
+        )+
+    };
+}
+
+impl_bound_from!(Int(i128): i8, i16, i32, i64, i128, isize);
+impl_bound_from!(UInt(u128): u8, u16, u32, u64, u128, usize);
+impl_bound_from!(Float(f64): f32, f64);
+
+impl GenomeShape {
+    /// Returns the shape of a chromosome of type `T`, without bounds.
+    pub fn chromosome<T: ?Sized>() -> Self {
+        Self::Chromosome {
+            type_name: type_name::<T>(),
+            bounds: None,
+        }
+    }
+
+    /// Returns the shape of a chromosome of type `T`, whose values lie between `min` and `max`.
+    pub fn bounded<T: Into<Bound>>(min: T, max: T) -> Self {
+        Self::Chromosome {
+            type_name: type_name::<T>(),
+            bounds: Some((min.into(), max.into())),
+        }
+    }
+
+    /// Returns the number of chromosomes in this shape, which should match [Genome::size_hint](crate::genome::Genome::size_hint).
+    pub fn chromosome_count(&self) -> usize {
+        match self {
+            Self::Chromosome { .. } => 1,
+            Self::List(items) => items.iter().map(Self::chromosome_count).sum(),
+            Self::Struct { fields, .. } => fields
+                .iter()
+                .map(|(_, field)| field.chromosome_count())
+                .sum(),
+        }
+    }
+
+    /// Returns the shape of the field named `name`, if this is the shape of a structure with such a field.
+    pub fn field(&self, name: &str) -> Option<&GenomeShape> {
+        match self {
+            Self::Struct { fields, .. } => fields
+                .iter()
+                .find(|(field_name, _)| *field_name == name)
+                .map(|(_, field)| field),
+            _ => None,
+        }
+    }
+}
+
+/// A genome that can describe its own structure.
+///
+/// It is implemented for the scalar chromosomes (integers report the range of their type),
+/// and for the collections and tuples of genomes implementing it.
+/// For your own structures, list the fields in the same order as in [Genome::mutate](crate::genome::Genome::mutate),
+/// and use [DescribeWrapper] for the wrapped values.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::describe::{Describe, DescribeWrapper, GenomeShape};
+/// use genomic::wrapper::UniformCh;
+///
+/// struct Creature {
+///     speed: f64,
+///     legs: u8,
+/// }
+///
+/// impl Describe for Creature {
+///     fn describe(&self) -> GenomeShape {
+///         GenomeShape::Struct {
+///             type_name: "Creature",
+///             fields: vec![
+///                 ("speed", UniformCh::new(0.0, 10.0).describe_value(&self.speed)),
+///                 ("legs", self.legs.describe()),
+///             ],
+///         }
+///     }
+/// }
+///
+/// let shape = Creature { speed: 1.0, legs: 4 }.describe();
+/// assert_eq!(shape.field("speed"), Some(&GenomeShape::bounded(0.0, 10.0)));
+/// assert_eq!(shape.field("legs"), Some(&GenomeShape::bounded(0u8, 255)));
+/// ```
+pub trait Describe {
+    /// Should return the structure of this genome.
+    fn describe(&self) -> GenomeShape;
+}
+
+/// A wrapper that can describe the values it mutates, reporting its bounds.
+pub trait DescribeWrapper<T: ?Sized> {
+    /// Should return the shape of `value`, when mutated by this wrapper.
+    fn describe_value(&self, value: &T) -> GenomeShape;
+}
+
+macro_rules! impl_describe_int {
+    ( $( $type:ty ),+ ) => {
+        $(
+            impl Describe for $type {
+                fn describe(&self) -> GenomeShape {
+                    GenomeShape::bounded(<$type>::MIN, <$type>::MAX)
+                }
+            }
+
+            #[allow(deprecated)]
+            impl Describe for crate::chromosome::UniformCh<$type> {
+                fn describe(&self) -> GenomeShape {
+                    GenomeShape::bounded(self.min, self.max)
+                }
+            }
+        )+
+    };
+}
+
+impl_describe_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_describe_float {
+    ( $( $type:ty ),+ ) => {
+        $(
+            #[allow(deprecated)]
+            impl Describe for crate::chromosome::UniformCh<$type> {
+                fn describe(&self) -> GenomeShape {
+                    GenomeShape::bounded(self.min, self.max)
+                }
+            }
+        )+
+    };
+}
+
+impl_describe_float!(f32, f64);
+
+impl Describe for bool {
+    fn describe(&self) -> GenomeShape {
+        GenomeShape::chromosome::<bool>()
+    }
+}
+
+impl Describe for () {
+    fn describe(&self) -> GenomeShape {
+        GenomeShape::chromosome::<()>()
+    }
+}
+
+impl<G: Describe + ?Sized> Describe for Box<G> {
+    fn describe(&self) -> GenomeShape {
+        (**self).describe()
+    }
+}
+
+impl<G: Describe> Describe for [G] {
+    fn describe(&self) -> GenomeShape {
+        GenomeShape::List(self.iter().map(Describe::describe).collect())
+    }
+}
+
+impl<G: Describe, const N: usize> Describe for [G; N] {
+    fn describe(&self) -> GenomeShape {
+        self.as_slice().describe()
+    }
+}
+
+impl<G: Describe> Describe for Vec<G> {
+    fn describe(&self) -> GenomeShape {
+        self.as_slice().describe()
+    }
+}
+
+macro_rules! impl_describe_tuple {
+    ( $( $name:ident => $id:tt ),+ ) => {
+        impl<$($name : Describe),+> Describe for ($($name),+) {
+            fn describe(&self) -> GenomeShape {
+                GenomeShape::List(vec![$(self.$id.describe()),+])
+            }
+        }
+    }
+}
+
+impl_describe_tuple!(G1 => 0, G2 => 1);
+impl_describe_tuple!(G1 => 0, G2 => 1, G3 => 2);
+impl_describe_tuple!(G1 => 0, G2 => 1, G3 => 2, G4 => 3);
+impl_describe_tuple!(G1 => 0, G2 => 1, G3 => 2, G4 => 3, G5 => 4);
+impl_describe_tuple!(G1 => 0, G2 => 1, G3 => 2, G4 => 3, G5 => 4, G6 => 5);
+
+macro_rules! impl_describe_wrapper {
+    ( $wrapper:ident: $( $type:ty ),+ ) => {
+        $(
+            impl DescribeWrapper<$type> for $wrapper<$type> {
+                fn describe_value(&self, _value: &$type) -> GenomeShape {
+                    GenomeShape::bounded(self.min, self.max)
+                }
+            }
+        )+
+    };
+}
+
+impl_describe_wrapper!(
+    UniformCh: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+impl_describe_wrapper!(BoundedCh: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_describe_wrapper!(WrappingUniformCh: f32, f64);
+impl_describe_wrapper!(LogUniformCh: f32, f64);
+
+macro_rules! impl_describe_dist {
+    ( $( $type:ty ),+ ) => {
+        $(
+            impl<D> DescribeWrapper<$type> for DistCh<$type, D> {
+                fn describe_value(&self, _value: &$type) -> GenomeShape {
+                    GenomeShape::bounded(self.min, self.max)
+                }
+            }
+        )+
+    };
+}
+
+impl_describe_dist!(f32, f64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::genome::Genome;
+    use crate::traverse::{Crossover, Mutator};
+
+    #[test]
+    fn test_describe_struct() {
+        struct Creature {
+            speed: f64,
+            legs: u8,
+            colors: [bool; 2],
+            weights: Vec<f32>,
+        }
+
+        impl Genome for Creature {
+            fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+                mutator
+                    .with(&mut UniformCh::new(0.0, 10.0), &mut self.speed)
+                    .chromosome(&mut self.legs)
+                    .genome(&mut self.colors)
+                    .iter_with(&mut UniformCh::new(-1.0, 1.0), &mut self.weights);
+            }
+
+            fn crossover(&mut self, _other: &mut Self, _crossover: &mut Crossover<impl rand::Rng>) {
+                unimplemented!()
+            }
+
+            fn size_hint(&self) -> usize {
+                4 + self.weights.len()
+            }
+        }
+
+        impl Describe for Creature {
+            fn describe(&self) -> GenomeShape {
+                let weights = UniformCh::new(-1.0, 1.0);
+
+                GenomeShape::Struct {
+                    type_name: "Creature",
+                    fields: vec![
+                        (
+                            "speed",
+                            UniformCh::new(0.0, 10.0).describe_value(&self.speed),
+                        ),
+                        ("legs", self.legs.describe()),
+                        ("colors", self.colors.describe()),
+                        (
+                            "weights",
+                            GenomeShape::List(
+                                self.weights
+                                    .iter()
+                                    .map(|weight| weights.describe_value(weight))
+                                    .collect(),
+                            ),
+                        ),
+                    ],
+                }
+            }
+        }
+
+        let creature = Creature {
+            speed: 2.5,
+            legs: 4,
+            colors: [true, false],
+            weights: vec![0.0; 3],
+        };
+        let shape = creature.describe();
+
+        assert_eq!(
+            shape.field("speed"),
+            Some(&GenomeShape::Chromosome {
+                type_name: "f64",
+                bounds: Some((Bound::Float(0.0), Bound::Float(10.0))),
+            })
+        );
+        assert_eq!(shape.field("legs"), Some(&GenomeShape::bounded(0u8, 255)));
+        assert_eq!(
+            shape.field("colors"),
+            Some(&GenomeShape::List(vec![
+                GenomeShape::chromosome::<bool>();
+                2
+            ]))
+        );
+        assert_eq!(
+            shape.field("weights"),
+            Some(&GenomeShape::List(vec![
+                GenomeShape::bounded(-1.0f32, 1.0);
+                3
+            ]))
+        );
+        assert_eq!(shape.field("eyes"), None);
+        assert_eq!(shape.chromosome_count(), creature.size_hint());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_describe_builtin() {
+        assert_eq!(
+            (0i8, [0u16; 2]).describe(),
+            GenomeShape::List(vec![
+                GenomeShape::bounded(-128i8, 127),
+                GenomeShape::List(vec![GenomeShape::bounded(0u16, 65535); 2]),
+            ])
+        );
+        assert_eq!(
+            crate::chromosome::UniformCh::new(3i32, -5, 5).describe(),
+            GenomeShape::bounded(-5i32, 5)
+        );
+        assert_eq!(
+            BoundedCh::new(0u8, 100).describe_value(&50),
+            GenomeShape::bounded(0u8, 100)
+        );
+
+        // Integer bounds are exact, even where an `f64` would round them
+        assert_eq!(
+            u64::MAX.describe(),
+            GenomeShape::Chromosome {
+                type_name: "u64",
+                bounds: Some((Bound::UInt(0), Bound::UInt(u64::MAX as u128))),
+            }
+        );
+        assert_eq!(
+            UniformCh::new(i64::MAX - 1, i64::MAX).describe_value(&0),
+            GenomeShape::Chromosome {
+                type_name: "i64",
+                bounds: Some((
+                    Bound::Int(i64::MAX as i128 - 1),
+                    Bound::Int(i64::MAX as i128)
+                )),
+            }
+        );
+        assert_eq!(Vec::<u8>::new().describe().chromosome_count(), 0);
+    }
+}
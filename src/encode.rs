@@ -0,0 +1,356 @@
+//! Conversion between genomes and flat parameter vectors,
+//! to interoperate with continuous optimizers (CMA-ES, Nelder-Mead, etc.).
+
+#![allow(deprecated)]
+
+use rand::Rng;
+
+use crate::chromosome::UniformCh;
+use crate::genome::Genome;
+
+/// Converts a genome to and from a flat list of `f64` parameters.
+///
+/// [Encodable::decode] should be the inverse of [Encodable::encode], meaning that
+/// `x.decode(&x.encode())` should leave `x` unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::encode::Encodable;
+///
+/// let mut weights = vec![0.5f64, -1.0, 2.0];
+/// let mut params = weights.encode();
+/// params[1] = 1.0;
+/// weights.decode(&params);
+///
+/// assert_eq!(weights, vec![0.5, 1.0, 2.0]);
+/// ```
+pub trait Encodable {
+    /// Returns the parameters of this genome as a flat vector.
+    fn encode(&self) -> Vec<f64>;
+
+    /// Sets the parameters of this genome from `params`, which should be as long as [Encodable::encoded_len].
+    fn decode(&mut self, params: &[f64]);
+
+    /// Returns the number of parameters that [Encodable::encode] would return.
+    fn encoded_len(&self) -> usize {
+        self.encode().len()
+    }
+}
+
+macro_rules! impl_encodable_float {
+    ( $type:ty ) => {
+        impl Encodable for $type {
+            fn encode(&self) -> Vec<f64> {
+                vec![*self as f64]
+            }
+
+            fn decode(&mut self, params: &[f64]) {
+                debug_assert_eq!(params.len(), 1);
+                *self = params[0] as $type;
+            }
+
+            fn encoded_len(&self) -> usize {
+                1
+            }
+        }
+
+        impl Encodable for UniformCh<$type> {
+            fn encode(&self) -> Vec<f64> {
+                vec![self.value as f64]
+            }
+
+            fn decode(&mut self, params: &[f64]) {
+                debug_assert_eq!(params.len(), 1);
+                self.value = params[0] as $type;
+            }
+
+            fn encoded_len(&self) -> usize {
+                1
+            }
+        }
+    };
+}
+
+impl_encodable_float!(f32);
+impl_encodable_float!(f64);
+
+impl<G: Encodable> Encodable for [G] {
+    fn encode(&self) -> Vec<f64> {
+        self.iter().flat_map(|item| item.encode()).collect()
+    }
+
+    fn decode(&mut self, mut params: &[f64]) {
+        debug_assert_eq!(params.len(), self.encoded_len());
+
+        for item in self.iter_mut() {
+            let (head, tail) = params.split_at(item.encoded_len());
+            item.decode(head);
+            params = tail;
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.iter().map(|item| item.encoded_len()).sum()
+    }
+}
+
+impl<G: Encodable> Encodable for Vec<G> {
+    fn encode(&self) -> Vec<f64> {
+        self.as_slice().encode()
+    }
+
+    fn decode(&mut self, params: &[f64]) {
+        self.as_mut_slice().decode(params);
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.as_slice().encoded_len()
+    }
+}
+
+/// Returns a child whose parameters are the weighted mean of the parameters of `parents`,
+/// also known as global intermediate recombination in evolution strategies.
+///
+/// The parents are walked in lockstep through [Encodable::encode],
+/// and the child is a clone of the first parent with the averaged parameters [decoded](Encodable::decode) into it.
+/// The weights are normalized, so they don't need to sum to `1`;
+/// if they don't sum to a positive number, then every parent gets the same weight.
+/// With a single parent, the child is a clone of that parent.
+///
+/// All of the parents should have the same [Encodable::encoded_len].
+///
+/// # Panics
+///
+/// Panics if `parents` is empty, or if `weights` doesn't have one weight per parent.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::encode::recombine;
+///
+/// let parents = [vec![0.0f64, 1.0], vec![3.0, -1.0]];
+/// let child = recombine(&[&parents[0], &parents[1]], &[2.0, 1.0]);
+///
+/// assert_eq!(child, vec![1.0, 1.0 / 3.0]);
+/// ```
+pub fn recombine<G: Encodable + Clone>(parents: &[&G], weights: &[f64]) -> G {
+    assert!(!parents.is_empty(), "Cannot recombine zero parents");
+    assert_eq!(
+        parents.len(),
+        weights.len(),
+        "There should be one weight per parent"
+    );
+
+    if parents.len() == 1 {
+        return parents[0].clone();
+    }
+
+    let total = weights.iter().sum::<f64>();
+    let uniform = total.is_nan() || total <= 0.0;
+
+    let mut params = vec![0.0; parents[0].encoded_len()];
+    for (parent, weight) in parents.iter().zip(weights) {
+        let weight = if uniform {
+            1.0 / parents.len() as f64
+        } else {
+            weight / total
+        };
+
+        let parent_params = parent.encode();
+        debug_assert_eq!(parent_params.len(), params.len());
+
+        for (param, parent_param) in params.iter_mut().zip(parent_params) {
+            *param += weight * parent_param;
+        }
+    }
+
+    let mut child = parents[0].clone();
+    child.decode(&params);
+    child
+}
+
+/// Returns two children of `parent`, perturbed by opposite amounts, also known as mirrored (or antithetic) sampling.
+///
+/// The first child is mutated with [mutate](crate::mutate), and the second child receives the opposite change on each parameter:
+/// if a parameter moved by `+delta` in the first child, then it moves by `-delta` in the second one.
+/// Since the deltas are computed on floating-point numbers, they are only negated up to rounding.
+/// The parameters are walked through [Encodable::encode], so only the parts of the genome exposed by [Encodable] are mirrored.
+///
+/// This only makes sense if the mutation of each parameter is symmetric around its current value,
+/// like [DistCh](crate::wrapper::DistCh) with a symmetric distribution:
+/// bounded wrappers like [UniformCh](crate::wrapper::UniformCh) skew the mutation away from their bounds,
+/// and nothing prevents the mirrored child from crossing these bounds.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::encode::{mirrored_mutate, Encodable};
+/// use genomic::prelude::*;
+/// use genomic::wrapper::DistCh;
+/// use rand_distr::Triangular;
+///
+/// #[derive(Clone)]
+/// struct Weights(Vec<f64>);
+///
+/// impl Genome for Weights {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         // Symmetric around the current value, as long as the mutation window stays within the bounds
+///         let distribution = Triangular::new(0.0, 1.0, 0.5).unwrap();
+///         mutator.iter_with(&mut DistCh::new(-10.0, 10.0, distribution), &mut self.0);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!()
+///     # }
+///     # fn size_hint(&self) -> usize {
+///     #     self.0.len()
+///     # }
+/// }
+///
+/// impl Encodable for Weights {
+///     fn encode(&self) -> Vec<f64> {
+///         self.0.clone()
+///     }
+///
+///     fn decode(&mut self, params: &[f64]) {
+///         self.0.copy_from_slice(params);
+///     }
+/// }
+///
+/// let parent = Weights(vec![1.5, -2.0, 0.25, 3.0]);
+/// let (plus, minus) = mirrored_mutate(&parent, 0.5, &mut rand::thread_rng());
+///
+/// for ((parent, plus), minus) in parent.0.iter().zip(&plus.0).zip(&minus.0) {
+///     assert!(((minus - parent) + (plus - parent)).abs() < 1e-9);
+/// }
+/// ```
+pub fn mirrored_mutate<G: Genome + Encodable + Clone>(
+    parent: &G,
+    rate: f64,
+    rng: &mut impl Rng,
+) -> (G, G) {
+    let mut plus = parent.clone();
+    crate::mutate(&mut plus, rate, rng);
+
+    let parent_params = parent.encode();
+    let plus_params = plus.encode();
+    debug_assert_eq!(parent_params.len(), plus_params.len());
+
+    let minus_params = parent_params
+        .iter()
+        .zip(plus_params)
+        .map(|(parent_param, plus_param)| parent_param - (plus_param - parent_param))
+        .collect::<Vec<_>>();
+
+    let mut minus = parent.clone();
+    minus.decode(&minus_params);
+
+    (plus, minus)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let original = vec![vec![0.5f32, 1.25], vec![], vec![-3.0]];
+        let mut genome = vec![vec![0.0f32; 2], vec![], vec![0.0]];
+
+        let params = original.encode();
+        assert_eq!(params, vec![0.5, 1.25, -3.0]);
+
+        genome.decode(&params);
+        assert_eq!(genome, original);
+    }
+
+    #[test]
+    fn test_round_trip_uniform() {
+        let original = vec![
+            UniformCh::new(0.25f64, 0.0, 1.0),
+            UniformCh::new(0.75, 0.0, 1.0),
+        ];
+        let mut genome = vec![UniformCh::new(0.0f64, 0.0, 1.0); 2];
+
+        genome.decode(&original.encode());
+        assert_eq!(genome, original);
+    }
+
+    #[test]
+    fn test_recombine() {
+        let parents = [
+            vec![0.0f64, 4.0, -2.0],
+            vec![1.0, 0.0, 2.0],
+            vec![5.0, 8.0, 2.0],
+        ];
+        let parents = parents.iter().collect::<Vec<_>>();
+
+        assert_eq!(recombine(&parents, &[0.5, 0.25, 0.25]), vec![1.5, 4.0, 0.0]);
+        // The weights are normalized
+        assert_eq!(recombine(&parents, &[2.0, 1.0, 1.0]), vec![1.5, 4.0, 0.0]);
+        assert_eq!(recombine(&parents, &[0.0, 0.0, 1.0]), *parents[2]);
+        // Without a positive sum, every parent gets the same weight
+        assert_eq!(recombine(&parents[..2], &[0.0; 2]), vec![0.5, 2.0, 0.0]);
+
+        let single = vec![UniformCh::new(0.25f32, 0.0, 1.0)];
+        assert_eq!(recombine(&[&single], &[0.0]), single);
+    }
+
+    #[test]
+    fn test_mirrored_mutate() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Weights(Vec<f32>);
+
+        impl Genome for Weights {
+            fn mutate(&mut self, mutator: &mut crate::traverse::Mutator<impl Rng>) {
+                mutator.iter_with(
+                    &mut crate::wrapper::UniformCh::new(-10.0, 10.0),
+                    &mut self.0,
+                );
+            }
+
+            fn crossover(
+                &mut self,
+                _other: &mut Self,
+                _crossover: &mut crate::traverse::Crossover<impl Rng>,
+            ) {
+                unimplemented!()
+            }
+
+            fn size_hint(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        impl Encodable for Weights {
+            fn encode(&self) -> Vec<f64> {
+                self.0.encode()
+            }
+
+            fn decode(&mut self, params: &[f64]) {
+                self.0.decode(params);
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        let parent = Weights(vec![0.0; 16]);
+
+        let (plus, minus) = mirrored_mutate(&parent, 1.0, &mut rng);
+        assert_ne!(plus, parent);
+        for (plus, minus) in plus.0.iter().zip(&minus.0) {
+            assert_eq!(*minus, -*plus);
+        }
+
+        let parent = Weights(vec![0.25, 0.5, -1.0]);
+        let (plus, minus) = mirrored_mutate(&parent, 0.5, &mut rng);
+        for ((plus, minus), parent) in plus.0.iter().zip(&minus.0).zip(&parent.0) {
+            assert!(((plus - parent) + (minus - parent)).abs() < 1e-6);
+        }
+
+        let (plus, minus) = mirrored_mutate(&parent, 0.0, &mut rng);
+        assert_eq!(plus, parent);
+        assert_eq!(minus, parent);
+    }
+}
@@ -0,0 +1,89 @@
+//! The error type returned by the fallible (`try_*`) variants of the functions of this crate.
+
+/// The errors that can be returned by the fallible (`try_*`) functions of this crate,
+/// like [crate::try_mutate], [crate::try_crossover] and [crate::try_reproduce].
+#[derive(Debug)]
+pub enum GenomicError {
+    /// Seeding a new rng from the rng passed by the caller failed.
+    RngSeeding(rand::Error),
+    /// Two individuals have a different number of chromosomes (as defined by [crate::Genome::size_hint]).
+    SizeMismatch { left: usize, right: usize },
+    /// A mutation or crossover rate is outside of `[0, 1]`, or is `NaN`.
+    InvalidRate(f64),
+    /// A weight of [CrossoverMethod::WeightedKPoint](crate::CrossoverMethod::WeightedKPoint)
+    /// is negative, infinite or `NaN`.
+    InvalidWeight(f64),
+    /// The lower bound of a range is greater than its upper bound, or the bounds can't be compared.
+    InvalidRange,
+}
+
+impl std::fmt::Display for GenomicError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenomicError::RngSeeding(error) => {
+                write!(f, "couldn't seed a new rng from the existing rng: {error}")
+            }
+            GenomicError::SizeMismatch { left, right } => write!(
+                f,
+                "the individuals have different sizes ({left} and {right} chromosomes)"
+            ),
+            GenomicError::InvalidRate(rate) => {
+                write!(f, "invalid rate {rate} (expected a number between 0 and 1)")
+            }
+            GenomicError::InvalidWeight(weight) => write!(
+                f,
+                "invalid weight {weight} (expected a finite, non-negative number)"
+            ),
+            GenomicError::InvalidRange => write!(
+                f,
+                "invalid range (expected the lower bound to be at most the upper bound)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GenomicError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GenomicError::RngSeeding(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<rand::Error> for GenomicError {
+    fn from(value: rand::Error) -> Self {
+        GenomicError::RngSeeding(value)
+    }
+}
+
+/// Returns an error if `rate` is outside of `[0, 1]`, or is `NaN`.
+pub(crate) fn check_rate(rate: f64) -> Result<(), GenomicError> {
+    if (0.0..=1.0).contains(&rate) {
+        Ok(())
+    } else {
+        Err(GenomicError::InvalidRate(rate))
+    }
+}
+
+/// Returns an error if the parameters of `method` are invalid.
+pub(crate) fn check_method(method: &crate::CrossoverMethod) -> Result<(), GenomicError> {
+    use crate::CrossoverMethod;
+
+    match method {
+        CrossoverMethod::Uniform(rate) => check_rate(*rate),
+        CrossoverMethod::WeightedKPoint { weights, .. } => {
+            match weights
+                .iter()
+                .find(|weight| !weight.is_finite() || **weight < 0.0)
+            {
+                Some(&weight) => Err(GenomicError::InvalidWeight(weight)),
+                None => Ok(()),
+            }
+        }
+        // Any number of points is valid, and out-of-range positions are ignored
+        CrossoverMethod::KPoint(_)
+        | CrossoverMethod::FixedPoints(_)
+        | CrossoverMethod::EvenKPoint(_) => Ok(()),
+    }
+}
@@ -107,6 +107,13 @@ pub struct GAWrapper<Ch> {
     chromosome_type: PhantomData<Ch>,
 }
 
+/// Equivalent to [`GAWrapper::new`].
+impl<Ch> Default for GAWrapper<Ch> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<Ch> GAWrapper<Ch> {
     pub fn new() -> Self {
         Self {
@@ -128,7 +135,7 @@ where
     Ch: GeneT + Chromosome,
 {
     fn mutate_with(&mut self, genome: &mut G, mutator: &mut Mutator<impl rand::Rng>) {
-        mutator.iter(genome.get_dna_mut());
+        mutate_dna(genome.get_dna_mut().iter_mut(), mutator);
     }
 }
 
@@ -143,12 +150,55 @@ where
         genome_right: &mut G,
         crossover: &mut Crossover<impl rand::Rng>,
     ) {
-        genome_left
-            .get_dna_mut()
-            .into_iter()
-            .zip(genome_right.get_dna_mut())
-            .for_each(|(ch_left, ch_right)| {
-                crossover.chromosome(ch_left, ch_right);
-            });
+        crossover_dna(
+            genome_left.get_dna_mut().iter_mut(),
+            genome_right.get_dna_mut().iter_mut(),
+            crossover,
+        );
+    }
+}
+
+// `GenotypeT` exposes its DNA as a `Vec`, but the traversal itself only relies on iterators,
+// so that it can be re-used for any other DNA container.
+fn mutate_dna<'a, Ch: Chromosome + 'a>(
+    dna: impl IntoIterator<Item = &'a mut Ch>,
+    mutator: &mut Mutator<impl rand::Rng>,
+) {
+    dna.into_iter().for_each(|ch| {
+        mutator.chromosome(ch);
+    });
+}
+
+fn crossover_dna<'a, Ch: 'a>(
+    dna_left: impl IntoIterator<Item = &'a mut Ch>,
+    dna_right: impl IntoIterator<Item = &'a mut Ch>,
+    crossover: &mut Crossover<impl rand::Rng>,
+) {
+    dna_left
+        .into_iter()
+        .zip(dna_right)
+        .for_each(|(ch_left, ch_right)| {
+            crossover.chromosome(ch_left, ch_right);
+        });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_array_dna() {
+        let mut rng = crate::test_rng();
+        let mut dna_left = [0u32; 4];
+        let mut dna_right = [u32::MAX; 4];
+
+        let mut crossover = Crossover::new(&mut rng, crate::traverse::CrossoverState::Fixed(true));
+        crossover_dna(dna_left.iter_mut(), dna_right.iter_mut(), &mut crossover);
+        assert_eq!(dna_left, [u32::MAX; 4]);
+        assert_eq!(dna_right, [0; 4]);
+
+        let mut mutator = Mutator::new(1.0, &mut rng);
+        mutate_dna(dna_left.iter_mut(), &mut mutator);
+        assert_eq!(dna_left.len(), 4);
     }
 }
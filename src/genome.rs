@@ -1,3 +1,5 @@
+use std::collections::BinaryHeap;
+
 use rand::Rng;
 
 use crate::{Chromosome, Crossover, Mutator};
@@ -83,6 +85,20 @@ impl<Ch: Chromosome> Genome for Ch {
     }
 }
 
+impl<G: Genome, const N: usize> Genome for [G; N] {
+    fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+        mutator.iter(self.iter_mut());
+    }
+
+    fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+        crossover.iter(self.iter_mut(), other.iter_mut());
+    }
+
+    fn size_hint(&self) -> usize {
+        self.iter().map(|item| item.size_hint()).sum()
+    }
+}
+
 impl<G: Genome> Genome for Vec<G> {
     fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
         mutator.iter(self.iter_mut());
@@ -97,6 +113,22 @@ impl<G: Genome> Genome for Vec<G> {
     }
 }
 
+/// Only available with the `smallvec` feature flag.
+#[cfg(feature = "smallvec")]
+impl<G: Genome, A: smallvec::Array<Item = G>> Genome for smallvec::SmallVec<A> {
+    fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+        mutator.iter(self.iter_mut());
+    }
+
+    fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+        crossover.iter(self.iter_mut(), other.iter_mut());
+    }
+
+    fn size_hint(&self) -> usize {
+        self.iter().map(|item| item.size_hint()).sum()
+    }
+}
+
 impl<G: Genome> Genome for [G] {
     fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
         mutator.iter(self.iter_mut());
@@ -111,6 +143,413 @@ impl<G: Genome> Genome for [G] {
     }
 }
 
+/// The heap is drained into a vector, whose elements are then mutated or crossed over,
+/// before being rebuilt into a heap, so that the ordering invariant of the heap still holds afterwards.
+///
+/// During crossover, the elements of both heaps are aligned by order (from the lowest to the greatest).
+impl<G: Genome + Ord> Genome for BinaryHeap<G> {
+    fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+        let mut genomes = std::mem::take(self).into_vec();
+
+        mutator.iter(genomes.iter_mut());
+
+        *self = BinaryHeap::from(genomes);
+    }
+
+    fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+        let mut genomes_left = std::mem::take(self).into_sorted_vec();
+        let mut genomes_right = std::mem::take(other).into_sorted_vec();
+
+        crossover.iter(genomes_left.iter_mut(), genomes_right.iter_mut());
+
+        *self = BinaryHeap::from(genomes_left);
+        *other = BinaryHeap::from(genomes_right);
+    }
+
+    fn size_hint(&self) -> usize {
+        self.iter().map(|item| item.size_hint()).sum()
+    }
+}
+
+/// Only the `Ok` variant is mutated, while `Err` values are left untouched.
+/// The crossover operation is only performed if both individuals are `Ok`.
+///
+/// An `Err` value has a [Genome::size_hint] of `0`, so an `Ok` and an `Err` individual usually have different sizes:
+/// [crate::crossover] and [crate::reproduce] reject such a pair (with a debug assertion),
+/// as well as pairs of genomes whose size hints add up these sizes.
+/// Parents should thus either both be `Ok`, or both be `Err`.
+impl<G: Genome, E> Genome for Result<G, E> {
+    fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+        if let Ok(genome) = self {
+            mutator.genome(genome);
+        }
+    }
+
+    fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+        if let (Ok(genome_left), Ok(genome_right)) = (self, other) {
+            crossover.genome(genome_left, genome_right);
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            Ok(genome) => genome.size_hint(),
+            Err(_) => 0,
+        }
+    }
+}
+
+/// An array of bytes, treated as a single, long bitstring chromosome.
+///
+/// Mutation flips each bit of the array with a probability of `rate * 0.5`, like the integer chromosomes do,
+/// and crossover is a bit-level `K`-point crossover across the whole array:
+/// `K` distinct cut points are picked between the bits, and every other segment is swapped.
+/// Whether the first segment is swapped is decided by the crossover method, as for any other chromosome,
+/// so `size_hint` is `1` no matter the length of the array.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::PackedBits;
+///
+/// let mut left = PackedBits::<[u8; 4], 2>([0x00; 4]);
+/// let mut right = PackedBits::<[u8; 4], 2>([0xFF; 4]);
+///
+/// genomic::crossover(&mut left, &mut right, genomic::prelude::CrossoverMethod::Uniform(1.0), &mut rand::thread_rng());
+/// genomic::mutate(&mut left, 0.1, &mut rand::thread_rng());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PackedBits<A, const K: usize = 1>(pub A);
+
+impl<const N: usize, const K: usize> Genome for PackedBits<[u8; N], K> {
+    fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+        mutator.custom(|rate, rng| {
+            for byte in self.0.iter_mut() {
+                Chromosome::mutate(byte, rate, rng);
+            }
+        });
+    }
+
+    fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+        crossover.custom(|should_flip, rng| {
+            let length = N * 8;
+            let mut points = crate::sample_indices(rng, length.saturating_sub(1), K)
+                .into_iter()
+                .map(|point| point + 1)
+                .collect::<Vec<_>>();
+            points.sort_unstable();
+
+            let mut points = points.into_iter().peekable();
+            let mut swap = should_flip;
+            for bit in 0..length {
+                if points.next_if_eq(&bit).is_some() {
+                    swap = !swap;
+                }
+
+                if swap {
+                    let mask = 1 << (bit % 8);
+                    let difference = (self.0[bit / 8] ^ other.0[bit / 8]) & mask;
+                    self.0[bit / 8] ^= difference;
+                    other.0[bit / 8] ^= difference;
+                }
+            }
+        });
+    }
+
+    fn size_hint(&self) -> usize {
+        1
+    }
+}
+
+/// Lets two parents produce a child of a different type through the crossover operation,
+/// instead of swapping their chromosomes in place.
+///
+/// This generalizes [Genome::crossover], which can be seen as `CrossoverInto<(Self, Self)>`
+/// where both children have the same type as their parents.
+/// The implementation should traverse the chromosomes of both parents with the `crossover` helper,
+/// swapping copies of them as [Genome::crossover] would, and build the child from the copies on the left side.
+///
+/// Use [crate::crossover_into] to perform this operation.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::genome::CrossoverInto;
+///
+/// // The parents are stored as human-readable configurations...
+/// struct Config {
+///     layers: u8,
+///     width: u8,
+/// }
+///
+/// // ... while the children are stored as a raw list of genes
+/// #[derive(Debug, PartialEq)]
+/// struct RawGenome(Vec<u8>);
+///
+/// impl CrossoverInto<RawGenome> for Config {
+///     fn crossover_into(&self, other: &Self, crossover: &mut Crossover<impl rand::Rng>) -> RawGenome {
+///         let (mut layers, mut other_layers) = (self.layers, other.layers);
+///         let (mut width, mut other_width) = (self.width, other.width);
+///
+///         crossover
+///             .chromosome(&mut layers, &mut other_layers)
+///             .chromosome(&mut width, &mut other_width);
+///
+///         RawGenome(vec![layers, width])
+///     }
+///
+///     fn crossover_size_hint(&self) -> usize {
+///         2
+///     }
+/// }
+///
+/// let left = Config { layers: 1, width: 16 };
+/// let right = Config { layers: 2, width: 32 };
+///
+/// let child: RawGenome = genomic::crossover_into(
+///     &left,
+///     &right,
+///     CrossoverMethod::FixedPoints(vec![1]),
+///     &mut rand::thread_rng(),
+/// );
+/// assert_eq!(child, RawGenome(vec![1, 32]));
+/// ```
+pub trait CrossoverInto<Child> {
+    /// Should perform a crossover over the chromosomes of `self` and `other`, using the `crossover` helper,
+    /// and return the resulting child.
+    fn crossover_into(&self, other: &Self, crossover: &mut Crossover<impl Rng>) -> Child;
+
+    /// Should return the number of chromosomes visited by [CrossoverInto::crossover_into],
+    /// like [Genome::size_hint] does for [Genome::crossover].
+    fn crossover_size_hint(&self) -> usize;
+}
+
+/// Lets a genome declare invariants on its internal structure,
+/// which are checked by [crate::checked_mutate] and [crate::checked_crossover] in debug builds.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::Validate;
+///
+/// struct Tour(Vec<usize>);
+///
+/// impl Validate for Tour {
+///     fn is_valid(&self) -> bool {
+///         let mut sorted = self.0.clone();
+///         sorted.sort();
+///         sorted.into_iter().enumerate().all(|(index, node)| index == node)
+///     }
+/// }
+/// ```
+pub trait Validate {
+    /// Should return `true` if the invariants of the genome hold.
+    fn is_valid(&self) -> bool;
+}
+
+/// Lets a genome be evaluated, for the selection of the fittest individuals.
+///
+/// Higher values mean fitter individuals. A fitness of `NaN` is considered worse than any other fitness.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::Fitness;
+///
+/// struct Guess(u8);
+///
+/// impl Fitness for Guess {
+///     fn fitness(&self) -> f64 {
+///         -(self.0 as f64 - 42.0).abs()
+///     }
+/// }
+/// ```
+pub trait Fitness {
+    /// Should return the fitness of this individual, with higher values meaning a fitter individual.
+    fn fitness(&self) -> f64;
+}
+
+/// Evaluates the fitness of a whole population at once,
+/// which is useful when the evaluation is expensive but can be vectorized (on a GPU, for instance).
+///
+/// The helpers of this crate, like [Population](crate::population::Population), evaluate individuals through this trait.
+/// It is implemented for every type implementing [Fitness], by calling [Fitness::fitness] on each individual;
+/// to evaluate a population in one shot, implement this trait instead of [Fitness].
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::BatchFitness;
+///
+/// struct Guess(u8);
+///
+/// impl BatchFitness for Guess {
+///     fn fitness_batch(population: &[Self]) -> Vec<f64> {
+///         // Imagine that this is sent to a GPU
+///         population.iter().map(|guess| -(guess.0 as f64 - 42.0).abs()).collect()
+///     }
+/// }
+/// ```
+pub trait BatchFitness: Sized {
+    /// Should return the fitness of each individual in `population`, in the same order.
+    fn fitness_batch(population: &[Self]) -> Vec<f64>;
+}
+
+impl<G: Fitness> BatchFitness for G {
+    fn fitness_batch(population: &[Self]) -> Vec<f64> {
+        population
+            .iter()
+            .map(|individual| individual.fitness())
+            .collect()
+    }
+}
+
+/// Measures how far apart two individuals are, for niching methods like [shared_fitness](crate::population::shared_fitness).
+///
+/// The distance should be non-negative, symmetric, and `0.0` between an individual and itself.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::Distance;
+///
+/// struct Point(f64, f64);
+///
+/// impl Distance for Point {
+///     fn distance(&self, other: &Self) -> f64 {
+///         (self.0 - other.0).hypot(self.1 - other.1)
+///     }
+/// }
+/// ```
+pub trait Distance {
+    /// Should return the distance between `self` and `other`.
+    fn distance(&self, other: &Self) -> f64;
+}
+
+/// A genome whose number of chromosomes is known at compile time.
+///
+/// `SIZE` should be equal to the value returned by [Genome::size_hint].
+/// Genomes whose number of chromosomes can vary, like `Vec<G>`, do not implement this trait.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::FixedSizeGenome;
+///
+/// assert_eq!(<(u8, u16)>::SIZE, 2);
+/// assert_eq!(<[(u8, bool); 4]>::SIZE, 8);
+/// ```
+pub trait FixedSizeGenome: Genome {
+    /// The number of chromosomes in this genome.
+    const SIZE: usize;
+}
+
+macro_rules! impl_fixed_size_ch {
+    ( $( $type:ty ),+ ) => {
+        $(
+            impl FixedSizeGenome for $type {
+                const SIZE: usize = 1;
+            }
+        )+
+    }
+}
+
+impl_fixed_size_ch!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    bool,
+    ()
+);
+
+impl<G: FixedSizeGenome, const N: usize> FixedSizeGenome for [G; N] {
+    const SIZE: usize = G::SIZE * N;
+}
+
+/// A genome that can be created at random, for instance to build the initial population of a genetic algorithm.
+///
+/// The integers and booleans are drawn uniformly over all of their possible values.
+/// For genomes whose size isn't known in advance, like `Vec<G>`, see [RandomInitSized].
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::RandomInit;
+///
+/// let individual = <(u8, [bool; 4])>::random(&mut rand::thread_rng());
+/// ```
+pub trait RandomInit: Sized {
+    /// Should return a new random instance of this genome.
+    fn random(rng: &mut impl Rng) -> Self;
+}
+
+/// A genome of variable size that can be created at random, given its size.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::RandomInitSized;
+///
+/// let individual = Vec::<u16>::random_sized(8, &mut rand::thread_rng());
+/// assert_eq!(individual.len(), 8);
+/// ```
+pub trait RandomInitSized: Sized {
+    /// Should return a new random instance of this genome, containing `length` elements.
+    fn random_sized(length: usize, rng: &mut impl Rng) -> Self;
+}
+
+macro_rules! impl_random_init_ch {
+    ( $( $type:ty ),+ ) => {
+        $(
+            impl RandomInit for $type {
+                fn random(rng: &mut impl Rng) -> Self {
+                    rng.gen()
+                }
+            }
+        )+
+    }
+}
+
+impl_random_init_ch!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    bool,
+    ()
+);
+
+impl<G: RandomInit, const N: usize> RandomInit for [G; N] {
+    fn random(rng: &mut impl Rng) -> Self {
+        std::array::from_fn(|_| G::random(rng))
+    }
+}
+
+impl<G: RandomInit> RandomInitSized for Vec<G> {
+    fn random_sized(length: usize, rng: &mut impl Rng) -> Self {
+        (0..length).map(|_| G::random(rng)).collect()
+    }
+}
+
 macro_rules! impl_genome_tuple {
     ( $( $name:ident => $id:tt ),+ ) => {
         impl<$($name : Genome),+> Genome for ($($name),+) {
@@ -129,6 +568,16 @@ macro_rules! impl_genome_tuple {
                 $(+ self.$id.size_hint())+
             }
         }
+
+        impl<$($name : FixedSizeGenome),+> FixedSizeGenome for ($($name),+) {
+            const SIZE: usize = 0 $(+ $name::SIZE)+;
+        }
+
+        impl<$($name : RandomInit),+> RandomInit for ($($name),+) {
+            fn random(rng: &mut impl Rng) -> Self {
+                ($($name::random(rng)),+)
+            }
+        }
     }
 }
 
@@ -137,3 +586,504 @@ impl_genome_tuple!(G1 => 0, G2 => 1, G3 => 2);
 impl_genome_tuple!(G1 => 0, G2 => 1, G3 => 2, G4 => 3);
 impl_genome_tuple!(G1 => 0, G2 => 1, G3 => 2, G4 => 3, G5 => 4);
 impl_genome_tuple!(G1 => 0, G2 => 1, G3 => 2, G4 => 3, G5 => 4, G6 => 5);
+
+/// Implements [Genome] for an enum, given the list of its variants and of their fields.
+///
+/// Only the fields of the active variant are mutated, and [Genome::size_hint] is the sum of their size hints.
+/// Each field must implement [Genome]; the fields of tuple variants are listed by their position (`0`, `1`, etc.),
+/// and variants without fields (or whose fields should be left untouched) are listed without braces.
+///
+/// Crossing over two individuals in different variants is ambiguous, so by default nothing happens in that case,
+/// while the fields of two individuals in the same variant are crossed over one by one.
+/// Since the size hint depends on the variant, two individuals in different variants can't be crossed over
+/// with [crate::crossover] then, which asserts that both sizes match.
+///
+/// Prefixing the enum with `#[genome(variant_crossover)]` instead makes the enum a single [Crossover::group],
+/// whose size hint is always `1`: two individuals in different variants are swapped as a whole,
+/// and two individuals in the same variant have all of their fields swapped, or none of them.
+/// The fields of the active variant are still mutated one by one, within a [Mutator::group].
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Shape {
+///     Circle { radius: u8 },
+///     Rectangle(u8, u8),
+///     Empty,
+/// }
+///
+/// genomic::impl_genome_enum!(#[genome(variant_crossover)] Shape {
+///     Circle { radius },
+///     Rectangle { 0, 1 },
+///     Empty,
+/// });
+///
+/// let mut shape = Shape::Rectangle(0, 0);
+/// genomic::mutate(&mut shape, 1.0, &mut rand::thread_rng());
+/// assert!(matches!(shape, Shape::Rectangle(_, _)));
+/// assert_eq!(shape.size_hint(), 1);
+///
+/// let mut other = Shape::Circle { radius: 1 };
+/// genomic::crossover(&mut shape, &mut other, CrossoverMethod::FixedPoints(vec![0]), &mut rand::thread_rng());
+/// assert_eq!(shape, Shape::Circle { radius: 1 });
+/// ```
+#[macro_export]
+macro_rules! impl_genome_enum {
+    (
+        #[genome(variant_crossover)]
+        $name:ident { $( $variant:ident $( { $( $field:tt ),* $(,)? } )? ),+ $(,)? }
+    ) => {
+        impl $crate::genome::Genome for $name {
+            fn mutate(&mut self, mutator: &mut $crate::traverse::Mutator<impl $crate::Rng>) {
+                mutator.group(|mutator| {
+                    $($($(
+                        if let $name::$variant { $field: value, .. } = &mut *self {
+                            mutator.genome(value);
+                        }
+                    )*)?)+
+                });
+            }
+
+            fn crossover(
+                &mut self,
+                other: &mut Self,
+                crossover: &mut $crate::traverse::Crossover<impl $crate::Rng>,
+            ) {
+                // Within the group, every field follows the same decision as the variants
+                crossover.group(|crossover| {
+                    if ::core::mem::discriminant(self) != ::core::mem::discriminant(other) {
+                        crossover.chromosome(self, other);
+                        return;
+                    }
+
+                    $($($(
+                        if let ($name::$variant { $field: left, .. }, $name::$variant { $field: right, .. }) =
+                            (&mut *self, &mut *other)
+                        {
+                            crossover.genome(left, right);
+                        }
+                    )*)?)+
+                });
+            }
+
+            fn size_hint(&self) -> usize {
+                1
+            }
+        }
+    };
+    ( $name:ident { $( $variant:ident $( { $( $field:tt ),* $(,)? } )? ),+ $(,)? } ) => {
+        impl $crate::genome::Genome for $name {
+            fn mutate(&mut self, mutator: &mut $crate::traverse::Mutator<impl $crate::Rng>) {
+                $($($(
+                    if let $name::$variant { $field: value, .. } = self {
+                        mutator.genome(value);
+                    }
+                )*)?)+
+            }
+
+            fn crossover(
+                &mut self,
+                other: &mut Self,
+                crossover: &mut $crate::traverse::Crossover<impl $crate::Rng>,
+            ) {
+                if ::core::mem::discriminant(self) != ::core::mem::discriminant(other) {
+                    return;
+                }
+
+                $($($(
+                    if let ($name::$variant { $field: left, .. }, $name::$variant { $field: right, .. }) =
+                        (&mut *self, &mut *other)
+                    {
+                        crossover.genome(left, right);
+                    }
+                )*)?)+
+            }
+
+            fn size_hint(&self) -> usize {
+                0 $($($(
+                    + if let $name::$variant { $field: value, .. } = self {
+                        $crate::genome::Genome::size_hint(value)
+                    } else {
+                        0
+                    }
+                )*)?)+
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traverse::{CrossoverMethod, CrossoverState};
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_nested_genome() {
+        use crate::chromosome::UniformCh;
+
+        type Layer = (u32, [UniformCh<f64>; 3]);
+
+        fn layer(id: u32, value: f64) -> Layer {
+            (
+                id,
+                std::array::from_fn(|_| UniformCh::new(value, -1.0, 1.0)),
+            )
+        }
+
+        fn flatten(genome: &[Layer]) -> Vec<f64> {
+            genome
+                .iter()
+                .flat_map(|(id, weights)| {
+                    std::iter::once(*id as f64).chain(weights.iter().map(|weight| weight.value))
+                })
+                .collect()
+        }
+
+        let mut rng = crate::test_rng();
+        assert_eq!(Layer::SIZE, 4);
+
+        let mut left = (0..5).map(|id| layer(id, -0.5)).collect::<Vec<_>>();
+        let mut right = (0..5).map(|id| layer(id + 100, 0.5)).collect::<Vec<_>>();
+        assert_eq!(left.size_hint(), 20);
+        assert_eq!(crate::dry_run_mutate(&mut left).len(), 20);
+
+        // A single point splits the flattened genome in two, across every layer
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![6]),
+            &mut rng,
+        );
+        let (flat_left, flat_right) = (flatten(&left), flatten(&right));
+        for (index, (left, right)) in flat_left.iter().zip(&flat_right).enumerate() {
+            let left_is_original = if index % 4 == 0 {
+                *left < 100.0
+            } else {
+                *left == -0.5
+            };
+            assert_eq!(left_is_original, index < 6, "{index}");
+            assert_ne!(left, right);
+        }
+
+        let snapshot = left.clone();
+        crate::mutate(&mut left, 0.0, &mut rng);
+        assert_eq!(left, snapshot);
+
+        crate::mutate(&mut left, 0.5, &mut rng);
+        assert_ne!(left, snapshot);
+        assert!(left
+            .iter()
+            .flat_map(|(_, weights)| weights)
+            .all(|weight| (-1.0..=1.0).contains(&weight.value)));
+    }
+
+    #[test]
+    fn test_result() {
+        let mut rng = crate::test_rng();
+
+        let mut error: Result<u32, u32> = Err(0);
+        let mut ok: Result<u32, u32> = Ok(0);
+        assert_eq!(error.size_hint(), 0);
+        assert_eq!(ok.size_hint(), 1);
+
+        for _ in 0..10 {
+            crate::mutate(&mut error, 1.0, &mut rng);
+            assert_eq!(error, Err(0));
+        }
+
+        // Mixed pairs can't go through `crate::crossover` (see `test_result_mixed`), so the helper is used directly
+        let mut other: Result<u32, u32> = Ok(1);
+        let mut crossover = Crossover::new(&mut rng, CrossoverState::Fixed(true));
+        error.crossover(&mut other, &mut crossover);
+        assert_eq!(error, Err(0));
+        assert_eq!(other, Ok(1));
+
+        crate::crossover(
+            &mut ok,
+            &mut other,
+            crate::CrossoverMethod::FixedPoints(vec![0]),
+            &mut rng,
+        );
+        assert_eq!(ok, Ok(1));
+        assert_eq!(other, Ok(0));
+
+        let (mut error_left, mut error_right): (Result<u32, u32>, Result<u32, u32>) =
+            (Err(0), Err(1));
+        crate::crossover(
+            &mut error_left,
+            &mut error_right,
+            crate::CrossoverMethod::FixedPoints(vec![0]),
+            &mut rng,
+        );
+        assert_eq!((error_left, error_right), (Err(0), Err(1)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_result_mixed() {
+        let mut error: Result<u32, u32> = Err(0);
+        let mut ok: Result<u32, u32> = Ok(1);
+
+        crate::crossover(
+            &mut error,
+            &mut ok,
+            crate::CrossoverMethod::FixedPoints(vec![0]),
+            &mut crate::test_rng(),
+        );
+    }
+
+    #[test]
+    fn test_fixed_size() {
+        assert_eq!(<(u8, u16)>::SIZE, 2);
+        assert_eq!(<[u32; 5]>::SIZE, 5);
+        assert_eq!(<([(u8, i8); 3], bool, u64)>::SIZE, 8);
+
+        let genome = ([(0u8, 0i8); 3], false, 0u64);
+        assert_eq!(genome.size_hint(), <([(u8, i8); 3], bool, u64)>::SIZE);
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn test_smallvec() {
+        use smallvec::{smallvec, SmallVec};
+
+        let mut instance_a: SmallVec<[u32; 4]> = smallvec![0; 4];
+        let mut instance_b: SmallVec<[u32; 4]> = smallvec![1; 4];
+        assert_eq!(instance_a.size_hint(), 4);
+
+        let mut rng = crate::test_rng();
+        crate::crossover(
+            &mut instance_a,
+            &mut instance_b,
+            crate::CrossoverMethod::KPoint(1),
+            &mut rng,
+        );
+        for i in 0..4 {
+            assert_eq!(instance_a[i] + instance_b[i], 1);
+        }
+
+        crate::mutate(&mut instance_a, 1.0, &mut rng);
+        assert_eq!(instance_a.len(), 4);
+        assert!(!instance_a.spilled());
+    }
+
+    #[test]
+    fn test_packed_bits() {
+        let mut rng = crate::test_rng();
+
+        let count_runs = |bytes: &[u8; 4]| {
+            (1..32)
+                .filter(|&bit| {
+                    (bytes[bit / 8] >> (bit % 8)) & 1
+                        != (bytes[(bit - 1) / 8] >> ((bit - 1) % 8)) & 1
+                })
+                .count()
+                + 1
+        };
+
+        for _ in 0..100 {
+            let mut left = PackedBits::<[u8; 4], 2>([0x00; 4]);
+            let mut right = PackedBits::<[u8; 4], 2>([0xFF; 4]);
+            assert_eq!(left.size_hint(), 1);
+
+            crate::crossover(
+                &mut left,
+                &mut right,
+                crate::CrossoverMethod::Uniform(1.0),
+                &mut rng,
+            );
+
+            // The children are complementary, and made of at most 3 contiguous runs
+            for (byte_left, byte_right) in left.0.iter().zip(right.0.iter()) {
+                assert_eq!(byte_left ^ byte_right, 0xFF);
+            }
+            assert!(count_runs(&left.0) <= 3);
+            assert!(left.0 != [0x00; 4] && left.0 != [0xFF; 4]);
+        }
+
+        let mut flipped = 0;
+        for _ in 0..100 {
+            let mut bits = PackedBits::<[u8; 8]>([0; 8]);
+            crate::mutate(&mut bits, 0.5, &mut rng);
+            flipped += bits.0.iter().map(|byte| byte.count_ones()).sum::<u32>();
+        }
+        // Each of the 6400 bits is flipped with a probability of 0.25
+        assert!((1400..1800).contains(&flipped));
+    }
+
+    #[test]
+    fn test_binary_heap() {
+        let mut rng = crate::test_rng();
+        let mut heap = (0..16u8).collect::<BinaryHeap<_>>();
+        let mut other = (100..116u8).collect::<BinaryHeap<_>>();
+        assert_eq!(heap.size_hint(), 16);
+
+        for _ in 0..100 {
+            crate::mutate(&mut heap, 0.5, &mut rng);
+            assert_eq!(heap.len(), 16);
+            assert_eq!(heap.peek(), heap.iter().max());
+
+            crate::crossover(
+                &mut heap,
+                &mut other,
+                crate::CrossoverMethod::Uniform(1.0),
+                &mut rng,
+            );
+            assert_eq!(heap.peek(), heap.iter().max());
+            assert_eq!(other.peek(), other.iter().max());
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle { radius: u8, filled: bool },
+        Polygon([u8; 3]),
+        Empty,
+    }
+
+    impl_genome_enum!(Shape {
+        Circle { radius, filled },
+        Polygon { 0 },
+        Empty,
+    });
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum SwappableShape {
+        Circle { radius: u8 },
+        Polygon([u8; 3]),
+    }
+
+    impl_genome_enum!(#[genome(variant_crossover)] SwappableShape {
+        Circle { radius },
+        Polygon { 0 },
+    });
+
+    #[test]
+    fn test_enum_mutate() {
+        let mut rng = crate::test_rng();
+
+        let mut shape = Shape::Polygon([0; 3]);
+        assert_eq!(shape.size_hint(), 3);
+        crate::mutate(&mut shape, 1.0, &mut rng);
+        assert!(matches!(shape, Shape::Polygon(sides) if sides != [0; 3]));
+
+        let mut shape = Shape::Circle {
+            radius: 0,
+            filled: false,
+        };
+        assert_eq!(shape.size_hint(), 2);
+        crate::mutate(&mut shape, 1.0, &mut rng);
+        assert!(matches!(shape, Shape::Circle { radius, .. } if radius != 0));
+
+        let mut shape = Shape::Empty;
+        assert_eq!(shape.size_hint(), 0);
+        crate::mutate(&mut shape, 1.0, &mut rng);
+        assert_eq!(shape, Shape::Empty);
+    }
+
+    #[test]
+    fn test_enum_same_variant_crossover() {
+        let mut rng = crate::test_rng();
+        let mut left = Shape::Polygon([0, 1, 2]);
+        let mut right = Shape::Polygon([10, 11, 12]);
+
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![1, 2]),
+            &mut rng,
+        );
+        assert_eq!(left, Shape::Polygon([0, 11, 2]));
+        assert_eq!(right, Shape::Polygon([10, 1, 12]));
+
+        let mut left = SwappableShape::Circle { radius: 1 };
+        let mut right = SwappableShape::Circle { radius: 2 };
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![0]),
+            &mut rng,
+        );
+        assert_eq!(left, SwappableShape::Circle { radius: 2 });
+        assert_eq!(right, SwappableShape::Circle { radius: 1 });
+    }
+
+    #[test]
+    fn test_enum_cross_variant_crossover() {
+        let mut rng = crate::test_rng();
+
+        // Without `variant_crossover`, nothing happens; the sizes differ,
+        // so `crate::crossover` would reject this pair and the helper is used directly
+        let mut left = Shape::Circle {
+            radius: 1,
+            filled: true,
+        };
+        let mut right = Shape::Polygon([1, 2, 3]);
+        Crossover::new(&mut rng, CrossoverState::Fixed(true)).genome(&mut left, &mut right);
+        assert!(matches!(
+            left,
+            Shape::Circle {
+                radius: 1,
+                filled: true
+            }
+        ));
+        assert_eq!(right, Shape::Polygon([1, 2, 3]));
+
+        // With it, the whole variants are swapped
+        let mut left = SwappableShape::Circle { radius: 1 };
+        let mut right = SwappableShape::Polygon([1, 2, 3]);
+        assert_eq!(left.size_hint(), right.size_hint());
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![0]),
+            &mut rng,
+        );
+        assert_eq!(left, SwappableShape::Polygon([1, 2, 3]));
+        assert_eq!(right, SwappableShape::Circle { radius: 1 });
+
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![]),
+            &mut rng,
+        );
+        assert_eq!(left, SwappableShape::Polygon([1, 2, 3]));
+
+        // Within a larger genome, a uniform crossover never mixes the fields of a variant
+        for _ in 0..20 {
+            let mut left = vec![SwappableShape::Polygon([1, 2, 3]); 4];
+            let mut right = vec![
+                SwappableShape::Polygon([4, 5, 6]),
+                SwappableShape::Circle { radius: 7 },
+                SwappableShape::Polygon([4, 5, 6]),
+                SwappableShape::Circle { radius: 7 },
+            ];
+            crate::crossover(
+                &mut left,
+                &mut right,
+                CrossoverMethod::Uniform(1.0),
+                &mut rng,
+            );
+            for shape in left.iter().chain(&right) {
+                assert!(matches!(
+                    shape,
+                    SwappableShape::Polygon([1, 2, 3])
+                        | SwappableShape::Polygon([4, 5, 6])
+                        | SwappableShape::Circle { radius: 7 }
+                ));
+            }
+        }
+
+        let mut shape = SwappableShape::Polygon([0; 3]);
+        assert_eq!(
+            crate::dry_run_mutate(&mut shape),
+            crate::dry_run_crossover(&mut shape.clone(), &mut shape.clone())
+        );
+    }
+}
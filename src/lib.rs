@@ -5,6 +5,7 @@ use chromosome::*;
 
 pub mod traverse;
 use rand::SeedableRng;
+use std::sync::{Arc, Mutex};
 use traverse::*;
 
 pub mod genome;
@@ -12,6 +13,22 @@ use genome::*;
 
 pub mod wrapper;
 
+pub mod encode;
+
+pub mod population;
+
+pub mod selection;
+
+pub mod schema;
+
+pub mod columnar;
+
+pub mod describe;
+
+pub mod error;
+pub use error::GenomicError;
+use error::{check_method, check_rate};
+
 /// A module for enabling interoperability with the [genetic_algorithms](https://crates.io/crates/genetic_algorithms) crate,
 /// only available with the `genetic_algorithms` feature flag.
 #[cfg(feature = "genetic_algorithms")]
@@ -25,8 +42,8 @@ pub use rand::Rng;
 /// A module to quickly import the necessary types for using this library
 pub mod prelude {
     pub use crate::chromosome::Chromosome;
-    pub use crate::genome::Genome;
-    pub use crate::traverse::{Crossover, CrossoverMethod, Mutator};
+    pub use crate::genome::{Fitness, Genome};
+    pub use crate::traverse::{Crossover, CrossoverMethod, CrossoverMethodKind, Mutator};
 }
 
 /// Mutates all of the chromosomes in `genome` with a rate of `rate`.
@@ -40,6 +57,147 @@ pub fn mutate<G: Genome>(individual: &mut G, rate: f64, rng: &mut impl rand::Rng
     individual.mutate(&mut mutator);
 }
 
+/// Mutates exactly `n` of the chromosomes of `individual`, chosen uniformly at random, at a rate of `1.0`,
+/// leaving the other chromosomes untouched.
+///
+/// This is done in two passes: the chromosomes are first counted with [dry_run_mutate],
+/// then `n` distinct indices are sampled, and `individual` is mutated with only the chromosomes at those indices enabled.
+/// The chromosomes are counted like in [Genome::size_hint]: each chromosome, wrapped value ([Mutator::with]),
+/// custom operation ([Mutator::custom]) and group ([Mutator::group]) counts as one,
+/// and a selected wrapped value or group is mutated as a whole.
+/// Because of this, the traversal of [Genome::mutate] should not depend on randomness.
+///
+/// If `n` is greater than the number of chromosomes, then every chromosome is mutated.
+/// Note that a selected chromosome may still end up with the same value, if its mutation happens to leave it unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// let mut individual = [0u64; 16];
+/// genomic::mutate_n_genes(&mut individual, 3, &mut rand::thread_rng());
+///
+/// assert!(individual.iter().filter(|&&gene| gene != 0).count() <= 3);
+/// ```
+pub fn mutate_n_genes<G: Genome>(individual: &mut G, n: usize, rng: &mut impl rand::Rng) {
+    let count = count_traversal_units(&dry_run_mutate(individual));
+
+    let mut selected = vec![false; count];
+    for index in sample_indices(rng, count, n) {
+        selected[index] = true;
+    }
+
+    let mut mutator = Mutator::new(1.0, rng).select(selected);
+
+    individual.mutate(&mut mutator);
+}
+
+/// Mutates `individual` with a mutation budget: a total number of chromosomes to mutate,
+/// spread across the whole genome instead of being derived from a per-chromosome rate.
+///
+/// The chromosomes are counted like in [mutate_n_genes], and the budget is spread evenly across them:
+/// each sub-genome thus receives a share of the budget proportional to its number of chromosomes
+/// (its [Genome::size_hint], if it is accurate), no matter how deeply it is nested.
+/// The integer part of `budget` is spent exactly, by mutating that many distinct chromosomes at a rate of `1.0`,
+/// and its fractional part is the probability of mutating one more chromosome,
+/// so that the number of mutated chromosomes is `budget` on average.
+///
+/// A negative or `NaN` budget mutates nothing, and a budget greater than the number of chromosomes mutates all of them.
+///
+/// # Example
+///
+/// ```rust
+/// let mut individual = (vec![0u64; 12], [0u64; 4]);
+/// genomic::mutate_with_budget(&mut individual, 2.5, &mut rand::thread_rng());
+///
+/// // 2 or 3 chromosomes were mutated, more likely in the vector than in the array
+/// let changed = individual.0.iter().chain(&individual.1).filter(|&&gene| gene != 0).count();
+/// assert!(changed <= 3);
+/// ```
+pub fn mutate_with_budget<G: Genome>(individual: &mut G, budget: f64, rng: &mut impl rand::Rng) {
+    let budget = if budget > 0.0 {
+        budget.min(usize::MAX as f64)
+    } else {
+        0.0
+    };
+    let n = budget.trunc() as usize + rng.gen_bool(budget.fract()) as usize;
+
+    mutate_n_genes(individual, n, rng);
+}
+
+/// Counts the top-level steps of a traversal, each group counting as a single step.
+fn count_traversal_units(trace: &[TraversalStep]) -> usize {
+    let mut depth = 0usize;
+    let mut count = 0;
+
+    for step in trace {
+        match step {
+            TraversalStep::GroupStart => {
+                if depth == 0 {
+                    count += 1;
+                }
+                depth += 1;
+            }
+            TraversalStep::GroupEnd => depth = depth.saturating_sub(1),
+            TraversalStep::Chromosome(_) | TraversalStep::Custom => {
+                if depth == 0 {
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+/// Mutates `individual` like [mutate], but returns an error instead of panicking if `rate` is invalid.
+///
+/// # Errors
+///
+/// Returns [GenomicError::InvalidRate] if `rate` is outside of `[0, 1]`, or is `NaN`.
+pub fn try_mutate<G: Genome>(
+    individual: &mut G,
+    rate: f64,
+    rng: &mut impl rand::Rng,
+) -> Result<(), GenomicError> {
+    check_rate(rate)?;
+
+    mutate(individual, rate, rng);
+
+    Ok(())
+}
+
+/// Returns the `rate` to pass to [mutate] so that about `expected` genes change per individual,
+/// out of `genome_size` genes.
+///
+/// This assumes that each gene is an independent unit that changes with a probability of `rate * 0.5`,
+/// which is the convention followed by the chromosomes of this crate:
+/// a `bool`, an enum implemented with [impl_chromosome_enum], or a single bit of an integer chromosome
+/// each count as one gene (so a `u32` counts as `32` genes).
+/// Chromosomes that don't follow this convention, like [wrapper::UniformCh], will yield a different number of changes.
+/// With [wrapper::FieldWise], each field is a single gene, but its `field_rate` should be set to `expected / genome_size` instead.
+///
+/// The returned rate is clamped between `0.0` and `1.0`, so at most half of the genes change on average.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::rate_for_expected_changes;
+///
+/// let mut individual = [false; 50];
+/// let rate = rate_for_expected_changes(2.0, 50);
+/// assert_eq!(rate, 0.08);
+///
+/// // About 2 of the booleans will be set to true
+/// genomic::mutate(&mut individual, rate, &mut rand::thread_rng());
+/// ```
+pub fn rate_for_expected_changes(expected: f64, genome_size: usize) -> f64 {
+    if genome_size == 0 {
+        return 0.0;
+    }
+
+    (2.0 * expected / genome_size as f64).clamp(0.0, 1.0)
+}
+
 /// Performs the crossover operation on `genome_left` and `genome_right`.
 /// Depending on `method`, different chromosomes between the two individuals will be swapped,
 /// mixing their genetical code.
@@ -55,18 +213,668 @@ pub fn crossover<G: Genome>(
 ) {
     debug_assert_eq!(individual_left.size_hint(), individual_right.size_hint());
 
-    let method = match method {
+    let method = crossover_state(method, individual_left.size_hint(), rng);
+    let mut crossover = Crossover::new(rng, method);
+
+    individual_left.crossover(individual_right, &mut crossover);
+}
+
+/// Performs the crossover operation like [crossover], but checks its parameters beforehand.
+///
+/// # Errors
+///
+/// - Returns [GenomicError::SizeMismatch] if the two individuals have a different [Genome::size_hint].
+/// - Returns [GenomicError::InvalidRate] if `method` is [CrossoverMethod::Uniform]
+///   with a rate that is outside of `[0, 1]`, or is `NaN`.
+/// - Returns [GenomicError::InvalidWeight] if `method` is [CrossoverMethod::WeightedKPoint]
+///   with a weight that is negative, infinite or `NaN`.
+pub fn try_crossover<G: Genome>(
+    individual_left: &mut G,
+    individual_right: &mut G,
+    method: CrossoverMethod,
+    rng: &mut impl rand::Rng,
+) -> Result<(), GenomicError> {
+    check_sizes(individual_left, individual_right)?;
+    check_method(&method)?;
+
+    crossover(individual_left, individual_right, method, rng);
+
+    Ok(())
+}
+
+/// Returns an error if `individual_left` and `individual_right` have a different [Genome::size_hint].
+fn check_sizes<G: Genome>(individual_left: &G, individual_right: &G) -> Result<(), GenomicError> {
+    let (left, right) = (individual_left.size_hint(), individual_right.size_hint());
+
+    if left == right {
+        Ok(())
+    } else {
+        Err(GenomicError::SizeMismatch { left, right })
+    }
+}
+
+/// Creates a single child from `parent_a` and `parent_b`, taking each of its chromosomes from either parent.
+///
+/// Each chromosome comes from `parent_b` with a probability of `rate * 0.5`, and from `parent_a` otherwise,
+/// like the left individual of a [CrossoverMethod::Uniform] crossover: a rate of `0.0` returns a copy of `parent_a`,
+/// and a rate of `1.0` picks each chromosome from either parent with a coin flip.
+///
+/// # Example
+///
+/// ```rust
+/// let child = genomic::merge(&[0u8; 8], &[1u8; 8], 1.0, &mut rand::thread_rng());
+///
+/// assert!(child.iter().all(|&gene| gene == 0 || gene == 1));
+/// ```
+pub fn merge<G: Genome + Clone>(
+    parent_a: &G,
+    parent_b: &G,
+    rate: f64,
+    rng: &mut impl rand::Rng,
+) -> G {
+    let mut child = parent_a.clone();
+    let mut other = parent_b.clone();
+
+    crossover(&mut child, &mut other, CrossoverMethod::Uniform(rate), rng);
+
+    child
+}
+
+/// Performs the crossover operation like [crossover], and returns the number of effective swaps,
+/// as counted by [Crossover::chromosome_if_different].
+///
+/// Swaps performed by other methods, like [Crossover::chromosome], are not counted.
+#[inline]
+pub fn crossover_counting_swaps<G: Genome>(
+    individual_left: &mut G,
+    individual_right: &mut G,
+    method: CrossoverMethod,
+    rng: &mut impl rand::Rng,
+) -> u64 {
+    debug_assert_eq!(individual_left.size_hint(), individual_right.size_hint());
+
+    let method = crossover_state(method, individual_left.size_hint(), rng);
+    let mut crossover = Crossover::new(rng, method);
+
+    individual_left.crossover(individual_right, &mut crossover);
+
+    crossover.effective_swaps()
+}
+
+/// Performs the crossover operation like [crossover], and returns whether each chromosome was swapped,
+/// in traversal order.
+///
+/// The mask has one entry per swap decision of the crossover method: one per call to [Crossover::chromosome],
+/// [Crossover::chromosome_if_different] and [Crossover::custom], and one per [Crossover::group].
+/// Its length is thus [Genome::size_hint] for genomes that count their chromosomes accordingly.
+/// Chromosomes within a skipped [Crossover::subset] or [Crossover::genome_if] have a `false` entry each,
+/// while wrappers that don't rely on the crossover method have no entry in the mask.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+///
+/// let mut left = [0u8; 4];
+/// let mut right = [1u8; 4];
+/// let mask = genomic::crossover_with_mask(
+///     &mut left,
+///     &mut right,
+///     CrossoverMethod::FixedPoints(vec![1, 3]),
+///     &mut rand::thread_rng(),
+/// );
+///
+/// assert_eq!(mask, [false, true, true, false]);
+/// assert_eq!(left, [0, 1, 1, 0]);
+/// ```
+pub fn crossover_with_mask<G: Genome>(
+    individual_left: &mut G,
+    individual_right: &mut G,
+    method: CrossoverMethod,
+    rng: &mut impl rand::Rng,
+) -> Vec<bool> {
+    debug_assert_eq!(individual_left.size_hint(), individual_right.size_hint());
+
+    let method = crossover_state(method, individual_left.size_hint(), rng);
+    let mut crossover = Crossover::new(rng, method).record_mask();
+
+    individual_left.crossover(individual_right, &mut crossover);
+
+    crossover.into_mask()
+}
+
+/// Initializes the internal state of the crossover helper for `method`, given the size of the individuals.
+fn crossover_state(
+    method: CrossoverMethod,
+    size: usize,
+    rng: &mut impl rand::Rng,
+) -> CrossoverState {
+    match method {
         CrossoverMethod::Uniform(rate) => CrossoverState::Uniform(rate),
         CrossoverMethod::KPoint(amount) => CrossoverState::KPoint {
             count: 0,
-            length: individual_left.size_hint() as u64,
+            length: size as u64,
             swapped: 0,
             desired: amount,
         },
-    };
+        CrossoverMethod::FixedPoints(mut points) => {
+            points.sort_unstable();
+            points.dedup();
+
+            CrossoverState::FixedPoints {
+                count: 0,
+                points,
+                passed: 0,
+                kind: CrossoverMethodKind::FixedPoints,
+            }
+        }
+        CrossoverMethod::WeightedKPoint { k, weights } => {
+            let mut points = sample_weighted_points(k, weights, rng);
+            points.sort_unstable();
+
+            CrossoverState::FixedPoints {
+                count: 0,
+                points,
+                passed: 0,
+                kind: CrossoverMethodKind::WeightedKPoint,
+            }
+        }
+        CrossoverMethod::EvenKPoint(k) => {
+            let length = size as u64;
+            let mut points = (0..k.min(length))
+                .map(|index| {
+                    if k >= length {
+                        index + 1
+                    } else {
+                        length * (index + 1) / (k + 1)
+                    }
+                })
+                .filter(|&point| point < length)
+                .collect::<Vec<_>>();
+            points.dedup();
+
+            CrossoverState::FixedPoints {
+                count: 0,
+                points,
+                passed: 0,
+                kind: CrossoverMethodKind::EvenKPoint,
+            }
+        }
+    }
+}
+
+/// Performs the crossover operation on `parent_left` and `parent_right`, producing a child of a different type.
+///
+/// This is the equivalent of [crossover] for [CrossoverInto], where the parents are left untouched.
+/// The two parents should have the same amount of chromosomes (as defined by [CrossoverInto::crossover_size_hint]).
+#[inline]
+pub fn crossover_into<G: CrossoverInto<C>, C>(
+    parent_left: &G,
+    parent_right: &G,
+    method: CrossoverMethod,
+    rng: &mut impl rand::Rng,
+) -> C {
+    debug_assert_eq!(
+        parent_left.crossover_size_hint(),
+        parent_right.crossover_size_hint()
+    );
+
+    let method = crossover_state(method, parent_left.crossover_size_hint(), rng);
     let mut crossover = Crossover::new(rng, method);
 
+    parent_left.crossover_into(parent_right, &mut crossover)
+}
+
+/// Mutates an individual shared behind a mutex, holding the lock for the duration of the mutation.
+///
+/// # Panics
+///
+/// Panics if the mutex is poisoned.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+///
+/// let individual = Arc::new(Mutex::new([0u8; 4]));
+/// genomic::mutate_shared(&individual, 0.5, &mut rand::thread_rng());
+/// ```
+pub fn mutate_shared<G: Genome>(individual: &Arc<Mutex<G>>, rate: f64, rng: &mut impl rand::Rng) {
+    let mut guard = individual.lock().expect("The mutex should not be poisoned");
+
+    mutate(&mut *guard, rate, rng);
+}
+
+/// Performs the crossover operation like [crossover] on two individuals shared behind mutexes.
+///
+/// Both mutexes are locked for the duration of the crossover, always in the same order:
+/// the mutex with the lowest address is locked first, no matter which side it is passed as.
+/// Two threads crossing over the same pair of individuals (in any order) thus can't deadlock each other,
+/// as long as no other lock on these individuals is held while calling this function.
+///
+/// If both sides point to the same individual, then nothing happens, as it would only be crossed over with itself.
+///
+/// # Panics
+///
+/// Panics if either mutex is poisoned.
+pub fn crossover_shared<G: Genome>(
+    individual_left: &Arc<Mutex<G>>,
+    individual_right: &Arc<Mutex<G>>,
+    method: CrossoverMethod,
+    rng: &mut impl rand::Rng,
+) {
+    if Arc::ptr_eq(individual_left, individual_right) {
+        return;
+    }
+
+    let left_first = Arc::as_ptr(individual_left) < Arc::as_ptr(individual_right);
+    let (first, second) = if left_first {
+        (individual_left, individual_right)
+    } else {
+        (individual_right, individual_left)
+    };
+
+    let mut guard_first = first.lock().expect("The mutex should not be poisoned");
+    let mut guard_second = second.lock().expect("The mutex should not be poisoned");
+    let (guard_left, guard_right) = if left_first {
+        (&mut guard_first, &mut guard_second)
+    } else {
+        (&mut guard_second, &mut guard_first)
+    };
+
+    crossover(&mut **guard_left, &mut **guard_right, method, rng);
+}
+
+/// Returns a random index in `0..len`.
+///
+/// The index is sampled as a `u64`, so that a given seed yields the same indices on 32-bit and 64-bit platforms,
+/// which wouldn't be the case when sampling a `usize` directly.
+#[inline]
+pub(crate) fn gen_index(rng: &mut (impl rand::Rng + ?Sized), len: usize) -> usize {
+    rng.gen_range(0..len as u64) as usize
+}
+
+/// Samples `amount` distinct indices out of `0..length` (or all of them if `amount > length`), in no particular order.
+///
+/// This uses Floyd's algorithm, which draws exactly one number per index:
+/// unlike [rand::seq::index::sample], which may resort to rejection sampling,
+/// it terminates even with a degenerate rng like [NullRng].
+pub(crate) fn sample_indices(
+    rng: &mut (impl rand::Rng + ?Sized),
+    length: usize,
+    amount: usize,
+) -> Vec<usize> {
+    let amount = amount.min(length);
+    let mut indices = Vec::with_capacity(amount);
+    let mut picked = std::collections::HashSet::with_capacity(amount);
+
+    for upper in (length - amount)..length {
+        let mut index = gen_index(rng, upper + 1);
+        if !picked.insert(index) {
+            index = upper;
+            picked.insert(index);
+        }
+        indices.push(index);
+    }
+
+    indices
+}
+
+/// A degenerate rng that only ever returns zeros, for writing tests whose outcome can be verified by hand.
+///
+/// The operations of this crate don't assume that the rng output is random, and terminate with this rng.
+/// Since every random number is `0`, the outcome of each operation is fully deterministic:
+/// - every event with a non-zero probability happens: at a non-zero rate, every bit of an integer is flipped,
+///   every `bool` is negated, and [Uniform](CrossoverMethod::Uniform) crossover swaps every chromosome
+/// - every value drawn from a range is its lower bound, and every index drawn is `0`
+///
+/// A rate of `0.0` still never changes anything.
+/// Distributions from other crates that rely on rejection sampling may never terminate with this rng.
+/// For tests that need realistic randomness while staying reproducible, use a seeded rng instead,
+/// like `rand::rngs::StdRng::seed_from_u64`.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::NullRng;
+///
+/// let mut individual = (5u8, true, [1u16; 2]);
+/// genomic::mutate(&mut individual, 0.1, &mut NullRng);
+///
+/// assert_eq!(individual, (!5, false, [!1; 2]));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NullRng;
+
+impl rand::RngCore for NullRng {
+    fn next_u32(&mut self) -> u32 {
+        0
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.fill(0);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        dest.fill(0);
+        Ok(())
+    }
+}
+
+/// Returns a rng with a fixed seed, so that the tests can't fail (or succeed) by chance.
+#[cfg(test)]
+pub(crate) fn test_rng() -> rand::rngs::StdRng {
+    rand::rngs::StdRng::seed_from_u64(0x67656e6f6d6963)
+}
+
+/// Samples `k` distinct positions, proportionally to `weights`.
+fn sample_weighted_points(k: u64, mut weights: Vec<f64>, rng: &mut impl rand::Rng) -> Vec<u64> {
+    let k = (k as usize).min(weights.len());
+    let mut chosen = vec![false; weights.len()];
+    let mut points = Vec::with_capacity(k);
+
+    for _ in 0..k {
+        let index = match rand::distributions::WeightedIndex::new(&weights) {
+            Ok(distribution) => rng.sample(distribution),
+            Err(_) => {
+                let remaining = (0..chosen.len())
+                    .filter(|&index| !chosen[index])
+                    .collect::<Vec<_>>();
+                remaining[gen_index(rng, remaining.len())]
+            }
+        };
+
+        chosen[index] = true;
+        weights[index] = 0.0;
+        points.push(index as u64);
+    }
+
+    points
+}
+
+/// Mutates `individual` like [mutate], then checks its invariants (as defined by [Validate]) in debug builds.
+///
+/// # Panics
+///
+/// In debug builds, panics if `individual` is invalid after the mutation.
+#[inline]
+pub fn checked_mutate<G: Genome + Validate>(
+    individual: &mut G,
+    rate: f64,
+    rng: &mut impl rand::Rng,
+) {
+    mutate(individual, rate, rng);
+
+    debug_assert!(
+        individual.is_valid(),
+        "The individual is invalid after mutation"
+    );
+}
+
+/// Performs the crossover operation like [crossover], then checks the invariants of both individuals
+/// (as defined by [Validate]) in debug builds.
+///
+/// # Panics
+///
+/// In debug builds, panics if either individual is invalid after the crossover.
+#[inline]
+pub fn checked_crossover<G: Genome + Validate>(
+    individual_left: &mut G,
+    individual_right: &mut G,
+    method: CrossoverMethod,
+    rng: &mut impl rand::Rng,
+) {
+    crossover(individual_left, individual_right, method, rng);
+
+    debug_assert!(
+        individual_left.is_valid() && individual_right.is_valid(),
+        "The individuals are invalid after crossover"
+    );
+}
+
+/// Performs a greedy local search step on `individual`: it is mutated `tries` times (each time starting from `individual`),
+/// and replaced by the fittest of these mutants if it is fitter than `individual`.
+///
+/// `fitness` should return higher values for fitter individuals, as with [Fitness];
+/// a `NaN` fitness is worse than any other fitness, and a mutant with the same fitness as `individual` isn't kept.
+/// Returns `true` if `individual` was replaced, in which case its fitness strictly increased.
+/// `fitness` is called `tries + 1` times.
+///
+/// This is meant for memetic algorithms, which refine the individuals of a genetic algorithm with a local search.
+///
+/// # Example
+///
+/// ```rust
+/// let fitness = |guess: &u8| -(*guess as f64 - 42.0).abs();
+///
+/// let mut guess = 0u8;
+/// for _ in 0..100 {
+///     genomic::improving_mutate(&mut guess, fitness, 0.2, 10, &mut rand::thread_rng());
+/// }
+///
+/// assert!(fitness(&guess) >= fitness(&0));
+/// ```
+pub fn improving_mutate<G, F>(
+    individual: &mut G,
+    fitness: F,
+    rate: f64,
+    tries: usize,
+    rng: &mut impl rand::Rng,
+) -> bool
+where
+    G: Genome + Clone,
+    F: Fn(&G) -> f64,
+{
+    let mut best_fitness = fitness(individual);
+    let mut best = None;
+
+    for _ in 0..tries {
+        let mut mutant = individual.clone();
+        mutate(&mut mutant, rate, rng);
+
+        let mutant_fitness = fitness(&mutant);
+        if selection::compare_fitness(mutant_fitness, best_fitness) == std::cmp::Ordering::Greater {
+            best_fitness = mutant_fitness;
+            best = Some(mutant);
+        }
+    }
+
+    match best {
+        Some(best) => {
+            *individual = best;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Mutates a clone of `individual`, and replaces `individual` with it according to the Metropolis criterion
+/// of simulated annealing.
+///
+/// `fitness` should return higher values for fitter individuals, as with [Fitness].
+/// A mutant that is at least as fit as `individual` is always accepted, while a mutant whose fitness is lower by `delta`
+/// is accepted with a probability of `exp(-delta / temperature)`.
+/// The temperature is thus on the same scale as the fitness: at a temperature `t`, a move worsening the fitness by `t`
+/// is accepted about 37% of the time. High temperatures accept almost every move, like [mutate],
+/// while a temperature of `0.0` (or below) only accepts moves that don't worsen the fitness.
+/// A mutant with a `NaN` fitness is never accepted, unless the fitness of `individual` is `NaN` too,
+/// and neither is a mutant whose fitness is infinitely lower, even at an infinite temperature.
+///
+/// Returns `true` if the mutant was accepted. `fitness` is called twice.
+///
+/// # Example
+///
+/// ```rust
+/// let fitness = |guess: &u8| -(*guess as f64 - 42.0).abs();
+///
+/// let mut guess = 0u8;
+/// let mut rng = rand::thread_rng();
+/// for step in 0..1000 {
+///     // The temperature decreases over time
+///     let temperature = 50.0 * (1.0 - step as f64 / 1000.0);
+///     genomic::annealed_mutate(&mut guess, fitness, 0.2, temperature, &mut rng);
+/// }
+/// ```
+pub fn annealed_mutate<G, F>(
+    individual: &mut G,
+    fitness: F,
+    rate: f64,
+    temperature: f64,
+    rng: &mut impl rand::Rng,
+) -> bool
+where
+    G: Genome + Clone,
+    F: Fn(&G) -> f64,
+{
+    let mut mutant = individual.clone();
+    mutate(&mut mutant, rate, rng);
+
+    let (current_fitness, mutant_fitness) = (fitness(individual), fitness(&mutant));
+    let accepted = match selection::compare_fitness(mutant_fitness, current_fitness) {
+        std::cmp::Ordering::Less if mutant_fitness.is_nan() => false,
+        std::cmp::Ordering::Less if temperature > 0.0 => {
+            let delta = current_fitness - mutant_fitness;
+            // `delta / temperature` is NaN if both are infinite, in which case the mutant is rejected
+            let probability = (-delta / temperature).exp();
+            rng.gen::<f64>() < probability
+        }
+        std::cmp::Ordering::Less => false,
+        _ => true,
+    };
+
+    if accepted {
+        *individual = mutant;
+    }
+
+    accepted
+}
+
+/// Records the traversal of `individual` by [Genome::mutate], without mutating it.
+///
+/// Each chromosome, wrapped value, custom operation and group is recorded as a [TraversalStep],
+/// in the order in which they are visited.
+/// This is mostly useful in tests, to check that the traversals of [Genome::mutate] and [Genome::crossover] match,
+/// by comparing the result of this function with the one of [dry_run_crossover].
+///
+/// Wrappers are recorded as a single step and are not called,
+/// and randomness drawn directly from [Mutator::get_rng] is not intercepted.
+pub fn dry_run_mutate<G: Genome>(individual: &mut G) -> Vec<TraversalStep> {
+    let mut mutator = Mutator::dry_run(rand::rngs::StdRng::seed_from_u64(0));
+
+    individual.mutate(&mut mutator);
+
+    mutator.into_trace()
+}
+
+/// Records the traversal of `individual_left` and `individual_right` by [Genome::crossover],
+/// without performing the crossover.
+///
+/// See [dry_run_mutate] for more information.
+pub fn dry_run_crossover<G: Genome>(
+    individual_left: &mut G,
+    individual_right: &mut G,
+) -> Vec<TraversalStep> {
+    let mut crossover = Crossover::dry_run(rand::rngs::StdRng::seed_from_u64(0));
+
     individual_left.crossover(individual_right, &mut crossover);
+
+    crossover.into_trace()
+}
+
+/// Measures the locality of the mapping from genotype to phenotype around `individual`,
+/// as the average distance between the phenotype of `individual` and the phenotypes of mutated copies of it.
+///
+/// This is a diagnostic tool for evaluating a representation, especially with indirect encodings:
+/// ideally, small mutations of the genome should yield small changes of the phenotype,
+/// so the score should grow smoothly with `rate`.
+/// Scores are only comparable with each other when they use the same `distance` function.
+///
+/// `decode` computes the phenotype of a genome, and `distance` returns how different two phenotypes are.
+/// `samples` copies of `individual` are each mutated once (as with [mutate]) with a rate of `rate`,
+/// and the average of their distances to the phenotype of `individual` is returned.
+/// If `samples` is `0`, then `0.0` is returned.
+///
+/// # Example
+///
+/// ```rust
+/// let individual = [0u8; 4];
+/// // The phenotype is the number of bits set, which changes little when few bits are flipped
+/// let decode = |genome: &[u8; 4]| genome.iter().map(|x| x.count_ones() as f64).sum::<f64>();
+/// let distance = |left: &f64, right: &f64| (left - right).abs();
+///
+/// let mut rng = rand::thread_rng();
+/// let score = genomic::locality_score(&individual, decode, distance, 100, 0.1, &mut rng);
+/// assert!(score < 8.0);
+/// ```
+pub fn locality_score<G, P, F, D>(
+    individual: &G,
+    decode: F,
+    distance: D,
+    samples: usize,
+    rate: f64,
+    rng: &mut impl rand::Rng,
+) -> f64
+where
+    G: Genome + Clone,
+    F: Fn(&G) -> P,
+    D: Fn(&P, &P) -> f64,
+{
+    if samples == 0 {
+        return 0.0;
+    }
+
+    let phenotype = decode(individual);
+    let mut total = 0.0;
+
+    for _ in 0..samples {
+        let mut mutated = individual.clone();
+        mutate(&mut mutated, rate, rng);
+        total += distance(&phenotype, &decode(&mutated));
+    }
+
+    total / samples as f64
+}
+
+/// Returns the steps taken by `wrapper` when mutating `value` at a rate of `rate`, `samples` times.
+///
+/// This is a diagnostic tool for tuning the float wrappers, like [UniformCh](wrapper::UniformCh)
+/// or [DistCh](wrapper::DistCh): each step is the difference between the mutated value and `value`,
+/// and every sample starts from `value` again, so the returned steps are independent and can be plotted as a histogram.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::wrapper::UniformCh;
+///
+/// let mut rng = rand::thread_rng();
+/// let steps = genomic::step_distribution(&mut UniformCh::new(-10.0, 10.0), 0.0, 0.1, 1000, &mut rng);
+///
+/// // At a rate of 0.1, the value stays within a window of width 2
+/// assert!(steps.iter().all(|step| step.abs() <= 1.0));
+/// ```
+pub fn step_distribution<W>(
+    wrapper: &mut W,
+    value: f64,
+    rate: f64,
+    samples: usize,
+    rng: &mut impl rand::Rng,
+) -> Vec<f64>
+where
+    W: for<'a> wrapper::MutationWrapper<&'a mut f64>,
+{
+    let mut mutator = Mutator::new(rate, rng);
+
+    (0..samples)
+        .map(|_| {
+            let mut mutated = value;
+            mutator.with(wrapper, &mut mutated);
+            mutated - value
+        })
+        .collect()
 }
 
 /// Reproduces two parent individuals,
@@ -78,6 +886,14 @@ pub fn crossover<G: Genome>(
 /// Each child is mutated with its own `StdRng`,
 /// meaning that dropping one of the children should fully optimize out its mutation
 /// (assuming this function call gets inlined).
+///
+/// # Checkpointing
+///
+/// The `StdRng`s of the children are seeded from `rng`, so all of the randomness used by this function
+/// is drawn from `rng`, and `rng` is left in a state from which the run can be resumed.
+/// To checkpoint a long-running optimization, save your population alongside the state of `rng`
+/// (for instance by cloning it, or by serializing a serde-compatible rng like `rand_chacha::ChaCha8Rng`);
+/// resuming from those will then yield the same individuals as an uninterrupted run.
 #[inline(always)]
 pub fn reproduce<G: Genome + Clone>(
     parent_left: &G,
@@ -88,16 +904,1193 @@ pub fn reproduce<G: Genome + Clone>(
 ) -> (G, G) {
     debug_assert_eq!(parent_left.size_hint(), parent_right.size_hint());
 
+    reproduce_children(
+        parent_left,
+        parent_right,
+        mutation_rate,
+        rng,
+        |child_left, child_right, rng| {
+            crossover(child_left, child_right, crossover_method, rng);
+            Ok(())
+        },
+    )
+    .expect("Couldn't seed a new rng from the existing rng")
+}
+
+/// Clones the parents, performs the crossover of the children with `crossover`,
+/// then mutates each child with its own `StdRng` seeded from `rng`: this is the body shared by [reproduce],
+/// [reproduce_gated] and [try_reproduce].
+#[inline(always)]
+fn reproduce_children<G: Genome + Clone, R: rand::Rng>(
+    parent_left: &G,
+    parent_right: &G,
+    mutation_rate: f64,
+    rng: &mut R,
+    crossover: impl FnOnce(&mut G, &mut G, &mut R) -> Result<(), GenomicError>,
+) -> Result<(G, G), GenomicError> {
     let (mut child_left, mut child_right) = (parent_left.clone(), parent_right.clone());
 
-    crossover(&mut child_left, &mut child_right, crossover_method, rng);
+    crossover(&mut child_left, &mut child_right, rng)?;
 
-    let mut left_rng = rand::rngs::StdRng::from_rng(&mut *rng)
-        .expect("Couldn't seed a new rng from the existing rng");
-    let mut right_rng = rand::rngs::StdRng::from_rng(&mut *rng)
-        .expect("Couldn't seed a new rng from the existing rng");
+    let mut left_rng = rand::rngs::StdRng::from_rng(&mut *rng)?;
+    let mut right_rng = rand::rngs::StdRng::from_rng(&mut *rng)?;
     mutate(&mut child_left, mutation_rate, &mut left_rng);
     mutate(&mut child_right, mutation_rate, &mut right_rng);
 
+    Ok((child_left, child_right))
+}
+
+/// Reproduces two parent individuals like [reproduce], but only performs the crossover
+/// with a probability of `crossover_probability` (clamped between `0.0` and `1.0`).
+///
+/// Otherwise, the children are clones of their respective parents; they are mutated in both cases.
+/// This is the usual "crossover probability" (`p_c`) parameter of genetic algorithms.
+pub fn reproduce_gated<G: Genome + Clone>(
+    parent_left: &G,
+    parent_right: &G,
+    crossover_probability: f64,
+    crossover_method: CrossoverMethod,
+    mutation_rate: f64,
+    rng: &mut impl rand::Rng,
+) -> (G, G) {
+    debug_assert_eq!(parent_left.size_hint(), parent_right.size_hint());
+
+    reproduce_children(
+        parent_left,
+        parent_right,
+        mutation_rate,
+        rng,
+        |child_left, child_right, rng| {
+            if rng.gen_bool(crossover_probability.clamp(0.0, 1.0)) {
+                crossover(child_left, child_right, crossover_method, rng);
+            }
+            Ok(())
+        },
+    )
+    .expect("Couldn't seed a new rng from the existing rng")
+}
+
+/// Reproduces two parent individuals like [reproduce], but returns an error instead of panicking.
+///
+/// # Errors
+///
+/// - Returns [GenomicError::SizeMismatch] if the two parents have a different [Genome::size_hint].
+/// - Returns [GenomicError::InvalidRate] if `mutation_rate` or the rate of a [CrossoverMethod::Uniform]
+///   is outside of `[0, 1]`, or is `NaN`.
+/// - Returns [GenomicError::InvalidWeight] if `crossover_method` is [CrossoverMethod::WeightedKPoint]
+///   with a weight that is negative, infinite or `NaN`.
+/// - Returns [GenomicError::RngSeeding] if the rngs of the children couldn't be seeded from `rng`.
+pub fn try_reproduce<G: Genome + Clone>(
+    parent_left: &G,
+    parent_right: &G,
+    crossover_method: CrossoverMethod,
+    mutation_rate: f64,
+    rng: &mut impl rand::Rng,
+) -> Result<(G, G), GenomicError> {
+    check_rate(mutation_rate)?;
+
+    reproduce_children(
+        parent_left,
+        parent_right,
+        mutation_rate,
+        rng,
+        |child_left, child_right, rng| {
+            try_crossover(child_left, child_right, crossover_method, rng)
+        },
+    )
+}
+
+/// Reproduces two parent individuals like [reproduce], but with a distinct rng for each phase of the reproduction.
+///
+/// The phases are performed in the following order:
+/// - the crossover of the two children, using `crossover_rng`
+/// - the mutation of the left child, using `left_mutation_rng`
+/// - the mutation of the right child, using `right_mutation_rng`
+///
+/// This lets you control each source of randomness separately,
+/// for instance to keep the crossover fixed while varying the mutation.
+#[inline(always)]
+pub fn reproduce_with_rngs<G: Genome + Clone>(
+    parent_left: &G,
+    parent_right: &G,
+    crossover_method: CrossoverMethod,
+    mutation_rate: f64,
+    crossover_rng: &mut impl rand::Rng,
+    left_mutation_rng: &mut impl rand::Rng,
+    right_mutation_rng: &mut impl rand::Rng,
+) -> (G, G) {
+    debug_assert_eq!(parent_left.size_hint(), parent_right.size_hint());
+
+    let (mut child_left, mut child_right) = (parent_left.clone(), parent_right.clone());
+
+    crossover(
+        &mut child_left,
+        &mut child_right,
+        crossover_method,
+        crossover_rng,
+    );
+
+    mutate(&mut child_left, mutation_rate, left_mutation_rng);
+    mutate(&mut child_right, mutation_rate, right_mutation_rng);
+
     (child_left, child_right)
 }
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+
+    use super::*;
+
+    fn generation(population: &mut Vec<u32>, rng: &mut impl rand::Rng) {
+        let mut next = Vec::with_capacity(population.len());
+        for pair in population.chunks(2) {
+            let (left, right) = reproduce(&pair[0], &pair[1], CrossoverMethod::KPoint(1), 0.1, rng);
+            next.push(left);
+            next.push(right);
+        }
+        *population = next;
+    }
+
+    #[test]
+    fn test_reproduce_multiply_rate() {
+        #[derive(Clone)]
+        struct TwoGroups {
+            regular: Vec<u32>,
+            stable: Vec<u32>,
+        }
+
+        impl Genome for TwoGroups {
+            fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+                mutator.iter(&mut self.regular).multiply_rate(0.1, |m| {
+                    m.iter(&mut self.stable);
+                });
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+                crossover
+                    .iter(&mut self.regular, &mut other.regular)
+                    .iter(&mut self.stable, &mut other.stable);
+            }
+
+            fn size_hint(&self) -> usize {
+                self.regular.len() + self.stable.len()
+            }
+        }
+
+        let parent = TwoGroups {
+            regular: vec![0; 50],
+            stable: vec![0; 50],
+        };
+
+        let mut rng = crate::test_rng();
+        let (mut regular_flips, mut stable_flips) = (0, 0);
+        for _ in 0..100 {
+            let (left, right) = reproduce(
+                &parent,
+                &parent,
+                CrossoverMethod::Uniform(0.5),
+                0.2,
+                &mut rng,
+            );
+            for child in [left, right] {
+                regular_flips += child.regular.iter().map(|x| x.count_ones()).sum::<u32>();
+                stable_flips += child.stable.iter().map(|x| x.count_ones()).sum::<u32>();
+            }
+        }
+
+        // Expected values are 32000 and 3200 flips respectively
+        assert!(regular_flips > 25000);
+        assert!(stable_flips < 5000);
+    }
+
+    #[test]
+    fn test_reproduce_with_rngs() {
+        let parent_left = vec![0u32; 32];
+        let parent_right = vec![u32::MAX; 32];
+
+        // Chromosomes with few bits set come from the left parent
+        fn origins(child: &[u32]) -> Vec<bool> {
+            child.iter().map(|x| x.count_ones() < 16).collect()
+        }
+
+        let mut reference = None;
+        for seed in 0..10 {
+            let (left, right) = reproduce_with_rngs(
+                &parent_left,
+                &parent_right,
+                CrossoverMethod::Uniform(1.0),
+                0.1,
+                &mut StdRng::seed_from_u64(1387),
+                &mut StdRng::seed_from_u64(seed),
+                &mut StdRng::seed_from_u64(seed + 100),
+            );
+            let result = (origins(&left), origins(&right));
+
+            match &reference {
+                Some(reference) => assert_eq!(&result, reference),
+                None => reference = Some(result),
+            }
+        }
+    }
+
+    #[test]
+    fn test_even_k_point() {
+        let mut rng = crate::test_rng();
+
+        let mut instance_a = vec![0u32; 12];
+        let mut instance_b = vec![1u32; 12];
+        crossover(
+            &mut instance_a,
+            &mut instance_b,
+            CrossoverMethod::EvenKPoint(3),
+            &mut rng,
+        );
+        assert_eq!(instance_a, vec![0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1]);
+        assert_eq!(instance_b, vec![1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0]);
+
+        // Too many points split the genome between every chromosome
+        let mut instance_a = vec![0u32; 4];
+        let mut instance_b = vec![1u32; 4];
+        crossover(
+            &mut instance_a,
+            &mut instance_b,
+            CrossoverMethod::EvenKPoint(10),
+            &mut rng,
+        );
+        assert_eq!(instance_a, vec![0, 1, 0, 1]);
+
+        let mut empty_a: Vec<u32> = vec![];
+        let mut empty_b: Vec<u32> = vec![];
+        crossover(
+            &mut empty_a,
+            &mut empty_b,
+            CrossoverMethod::EvenKPoint(3),
+            &mut rng,
+        );
+        assert!(empty_a.is_empty());
+    }
+
+    #[test]
+    fn test_weighted_k_point() {
+        let mut weights = vec![1.0; 10];
+        weights[5] = 100.0;
+
+        let mut rng = crate::test_rng();
+        let mut chosen = [0; 10];
+        for _ in 0..1000 {
+            let mut instance_a = vec![0u32; 10];
+            let mut instance_b = vec![1u32; 10];
+
+            crossover(
+                &mut instance_a,
+                &mut instance_b,
+                CrossoverMethod::WeightedKPoint {
+                    k: 1,
+                    weights: weights.clone(),
+                },
+                &mut rng,
+            );
+
+            let cut = instance_a.iter().position(|&x| x == 1).unwrap_or(0);
+            assert!(instance_a[cut..].iter().all(|&x| x == 1));
+            chosen[cut] += 1;
+        }
+
+        assert!(chosen[5] > 800);
+
+        // All-zero weights and too many points
+        let mut instance_a = vec![0u32; 4];
+        let mut instance_b = vec![1u32; 4];
+        crossover(
+            &mut instance_a,
+            &mut instance_b,
+            CrossoverMethod::WeightedKPoint {
+                k: 10,
+                weights: vec![0.0; 4],
+            },
+            &mut rng,
+        );
+        assert_eq!(instance_a, vec![1, 0, 1, 0]);
+    }
+
+    struct Tour(Vec<u32>);
+
+    impl Genome for Tour {
+        fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+            mutator.with(&mut wrapper::ReorderGenome::Swap, &mut self.0);
+        }
+
+        // Swapping individual nodes between two tours doesn't preserve permutations
+        fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+            crossover.iter(&mut self.0, &mut other.0);
+        }
+
+        fn size_hint(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    impl Validate for Tour {
+        fn is_valid(&self) -> bool {
+            let mut sorted = self.0.clone();
+            sorted.sort();
+            sorted
+                .into_iter()
+                .enumerate()
+                .all(|(index, node)| index == node as usize)
+        }
+    }
+
+    #[test]
+    fn test_checked_mutate() {
+        let mut rng = crate::test_rng();
+        let mut tour = Tour(vec![0, 1, 2, 3, 4]);
+        for _ in 0..10 {
+            checked_mutate(&mut tour, 0.5, &mut rng);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "invalid after crossover")]
+    fn test_checked_crossover() {
+        let mut tour_a = Tour(vec![0, 1, 2, 3]);
+        let mut tour_b = Tour(vec![3, 2, 1, 0]);
+
+        checked_crossover(
+            &mut tour_a,
+            &mut tour_b,
+            CrossoverMethod::FixedPoints(vec![2]),
+            &mut crate::test_rng(),
+        );
+    }
+
+    #[test]
+    fn test_reproduce_checkpoint() {
+        let initial = (0..16).collect::<Vec<u32>>();
+
+        let mut rng = StdRng::seed_from_u64(1373);
+        let mut uninterrupted = initial.clone();
+        for _ in 0..10 {
+            generation(&mut uninterrupted, &mut rng);
+        }
+
+        let mut rng = StdRng::seed_from_u64(1373);
+        let mut population = initial;
+        for _ in 0..4 {
+            generation(&mut population, &mut rng);
+        }
+
+        let (mut population, mut rng) = (population.clone(), rng.clone());
+        for _ in 4..10 {
+            generation(&mut population, &mut rng);
+        }
+
+        assert_eq!(population, uninterrupted);
+    }
+
+    #[test]
+    fn test_dry_run() {
+        use crate::wrapper::UniformCh;
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Creature {
+            speed: u8,
+            size: i32,
+            colors: (bool, bool),
+        }
+
+        impl Genome for Creature {
+            fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+                mutator
+                    .chromosome(&mut self.speed)
+                    .with(&mut UniformCh::new(0, 10), &mut self.size)
+                    .group(|mutator| {
+                        mutator.genome(&mut self.colors);
+                    });
+            }
+
+            // The colors are forgotten here, which the dry run should catch
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+                crossover
+                    .chromosome(&mut self.speed, &mut other.speed)
+                    .chromosome(&mut self.size, &mut other.size)
+                    .group(|_crossover| {});
+            }
+
+            fn size_hint(&self) -> usize {
+                3
+            }
+        }
+
+        let mut creature = Creature {
+            speed: 1,
+            size: 2,
+            colors: (true, false),
+        };
+        let mut other = creature.clone();
+        let original = creature.clone();
+
+        let mutate_steps = dry_run_mutate(&mut creature);
+        let crossover_steps = dry_run_crossover(&mut creature, &mut other);
+
+        assert_eq!(
+            mutate_steps,
+            vec![
+                TraversalStep::Chromosome("u8"),
+                TraversalStep::Chromosome("i32"),
+                TraversalStep::GroupStart,
+                TraversalStep::Chromosome("bool"),
+                TraversalStep::Chromosome("bool"),
+                TraversalStep::GroupEnd,
+            ]
+        );
+        assert_eq!(crossover_steps[..2], mutate_steps[..2]);
+        assert_ne!(crossover_steps, mutate_steps);
+
+        assert_eq!(creature, original);
+        assert_eq!(other, original);
+    }
+
+    #[test]
+    fn test_rate_for_expected_changes() {
+        assert_eq!(rate_for_expected_changes(1.0, 0), 0.0);
+        assert_eq!(rate_for_expected_changes(10.0, 5), 1.0);
+        assert_eq!(rate_for_expected_changes(2.5, 5), 1.0);
+        assert_eq!(rate_for_expected_changes(-1.0, 5), 0.0);
+
+        let mut rng = StdRng::seed_from_u64(1407);
+        let rate = rate_for_expected_changes(2.0, 64);
+
+        let trials = 10000;
+        let mut changed = 0;
+        for _ in 0..trials {
+            let mut individual = [0u32, 0u32];
+            mutate(&mut individual, rate, &mut rng);
+            changed += individual.iter().map(|x| x.count_ones()).sum::<u32>();
+        }
+
+        let average = changed as f64 / trials as f64;
+        assert!((1.9..2.1).contains(&average), "{average}");
+    }
+
+    #[test]
+    fn test_locality_score() {
+        #[derive(Clone)]
+        struct Point(Vec<f64>);
+
+        impl Genome for Point {
+            fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+                mutator.iter_with(&mut wrapper::UniformCh::new(0.0, 1.0), &mut self.0);
+            }
+
+            fn crossover(&mut self, _other: &mut Self, _crossover: &mut Crossover<impl rand::Rng>) {
+                unimplemented!()
+            }
+
+            fn size_hint(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        let point = Point(vec![0.5; 8]);
+        let decode = |point: &Point| point.0.clone();
+        let distance = |left: &Vec<f64>, right: &Vec<f64>| {
+            left.iter()
+                .zip(right)
+                .map(|(left, right)| (left - right).abs())
+                .sum::<f64>()
+        };
+
+        let scores = [0.02, 0.1, 0.5, 1.0]
+            .map(|rate| locality_score(&point, decode, distance, 200, rate, &mut rng));
+        assert!(
+            scores.windows(2).all(|pair| pair[0] < pair[1]),
+            "{scores:?}"
+        );
+
+        // With the identity encoding, each value moves by a quarter of the walk's width on average
+        assert!((scores[1] - 8.0 * 0.1 / 4.0).abs() < 0.05, "{scores:?}");
+
+        assert_eq!(
+            locality_score(&point, decode, distance, 0, 1.0, &mut rng),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut rng = crate::test_rng();
+        let parent_a = [0u8; 1000];
+        let parent_b = [1u8; 1000];
+
+        assert_eq!(merge(&parent_a, &parent_b, 0.0, &mut rng), parent_a);
+        assert_eq!(merge(&parent_a, &parent_b, 2.0, &mut rng), parent_b);
+
+        let child = merge(&parent_a, &parent_b, 1.0, &mut rng);
+        let from_b = child.iter().filter(|&&gene| gene == 1).count();
+        assert!((400..600).contains(&from_b), "{from_b}");
+    }
+
+    #[test]
+    fn test_improving_mutate() {
+        let mut rng = crate::test_rng();
+        let target = [12u8, 200, 7, 99];
+        let fitness = |individual: &[u8; 4]| {
+            -individual
+                .iter()
+                .zip(&target)
+                .map(|(&value, &target)| (value as f64 - target as f64).abs())
+                .sum::<f64>()
+        };
+
+        let mut individual = [0u8; 4];
+        let mut previous = fitness(&individual);
+        let mut improvements = 0;
+        for _ in 0..200 {
+            let improved = improving_mutate(&mut individual, fitness, 0.3, 5, &mut rng);
+            let current = fitness(&individual);
+
+            assert!(current >= previous);
+            assert_eq!(improved, current > previous);
+            if improved {
+                improvements += 1;
+            }
+            previous = current;
+        }
+        assert!(improvements > 0);
+
+        // Without any tries, nothing changes
+        let before = individual;
+        assert!(!improving_mutate(
+            &mut individual,
+            fitness,
+            1.0,
+            0,
+            &mut rng
+        ));
+        assert_eq!(individual, before);
+
+        // A NaN fitness is replaced by any other fitness
+        let mut individual = 0u8;
+        let fitness = |value: &u8| if *value == 0 { f64::NAN } else { 0.0 };
+        assert!(improving_mutate(
+            &mut individual,
+            fitness,
+            1.0,
+            10,
+            &mut rng
+        ));
+        assert_ne!(individual, 0);
+    }
+
+    #[test]
+    fn test_annealed_mutate() {
+        let mut rng = crate::test_rng();
+        let fitness = |value: &u8| -(*value as f64 - 128.0).abs();
+
+        let accepted_worse = |temperature: f64, rng: &mut StdRng| {
+            let mut accepted = 0;
+            for _ in 0..1000 {
+                let mut value = 128u8;
+                annealed_mutate(&mut value, fitness, 0.5, temperature, rng);
+                if fitness(&value) < 0.0 {
+                    accepted += 1;
+                }
+            }
+            accepted
+        };
+
+        // Every mutation of the optimum is a worse move
+        assert_eq!(accepted_worse(0.0, &mut rng), 0);
+        assert_eq!(accepted_worse(-1.0, &mut rng), 0);
+        assert_eq!(accepted_worse(1e-9, &mut rng), 0);
+        assert!(accepted_worse(1e9, &mut rng) > 500);
+        let lukewarm = accepted_worse(20.0, &mut rng);
+        assert!(0 < lukewarm && lukewarm < accepted_worse(f64::INFINITY, &mut rng));
+
+        // Better moves are always accepted
+        for _ in 0..100 {
+            let mut value = 0u8;
+            let previous = fitness(&value);
+            let accepted = annealed_mutate(&mut value, fitness, 1.0, 0.0, &mut rng);
+            assert_eq!(accepted, fitness(&value) >= previous);
+            assert!(fitness(&value) >= previous);
+        }
+
+        // An infinitely worse mutant is rejected, even at an infinite temperature
+        let fitness = |value: &u8| {
+            if *value == 128 {
+                0.0
+            } else {
+                f64::NEG_INFINITY
+            }
+        };
+        for _ in 0..100 {
+            let mut value = 128u8;
+            annealed_mutate(&mut value, fitness, 1.0, f64::INFINITY, &mut rng);
+            assert_eq!(value, 128);
+        }
+    }
+
+    #[test]
+    fn test_step_distribution() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = wrapper::UniformCh::new(-10.0, 10.0);
+
+        let steps = step_distribution(&mut wrapper, 0.0, 0.5, 5000, &mut rng);
+        assert_eq!(steps.len(), 5000);
+        assert!(steps.iter().all(|step| step.abs() <= 5.0));
+
+        // UniformCh is symmetric away from the bounds, so the steps should average out
+        let mean = steps.iter().sum::<f64>() / steps.len() as f64;
+        assert!(mean.abs() < 0.1, "{mean}");
+
+        // Near a bound, the steps are skewed away from it
+        let steps = step_distribution(&mut wrapper, 10.0, 0.5, 1000, &mut rng);
+        assert!(steps.iter().all(|&step| step <= 0.0));
+
+        assert!(step_distribution(&mut wrapper, 0.0, 1.0, 0, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_reproduce_gated() {
+        let mut rng = crate::test_rng();
+        let parent_left = [0u64; 4];
+        let parent_right = [u64::MAX; 4];
+        let method = CrossoverMethod::Uniform(1.0);
+
+        for _ in 0..100 {
+            let (left, right) = reproduce_gated(
+                &parent_left,
+                &parent_right,
+                0.0,
+                method.clone(),
+                0.0,
+                &mut rng,
+            );
+            assert_eq!((left, right), (parent_left, parent_right));
+
+            // The children are mutated, but never receive any chromosome from the other parent
+            let (left, right) = reproduce_gated(
+                &parent_left,
+                &parent_right,
+                0.0,
+                method.clone(),
+                0.1,
+                &mut rng,
+            );
+            assert!(left.iter().all(|x| x.count_ones() < 16), "{left:?}");
+            assert!(right.iter().all(|x| x.count_zeros() < 16), "{right:?}");
+        }
+
+        let mut crossed = 0;
+        for _ in 0..1000 {
+            let (left, _right) = reproduce_gated(
+                &parent_left,
+                &parent_right,
+                0.5,
+                method.clone(),
+                0.0,
+                &mut rng,
+            );
+            if left != parent_left {
+                crossed += 1;
+            }
+        }
+        // Each crossover leaves the left child unchanged with a probability of `1/16`
+        assert!((400..540).contains(&crossed), "{crossed}");
+    }
+
+    #[test]
+    fn test_kpoint_empty() {
+        let mut rng = crate::test_rng();
+
+        let (mut left, mut right) = (Vec::<u32>::new(), Vec::<u32>::new());
+        crossover(&mut left, &mut right, CrossoverMethod::KPoint(2), &mut rng);
+        assert!(left.is_empty() && right.is_empty());
+
+        // A genome misreporting its size as zero is left untouched
+        struct Hidden(u32);
+
+        impl Genome for Hidden {
+            fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+                mutator.chromosome(&mut self.0);
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+                crossover.chromosome(&mut self.0, &mut other.0);
+            }
+
+            fn size_hint(&self) -> usize {
+                0
+            }
+        }
+
+        let (mut left, mut right) = (Hidden(1), Hidden(2));
+        for method in [CrossoverMethod::KPoint(0), CrossoverMethod::KPoint(2)] {
+            crossover(&mut left, &mut right, method, &mut NullRng);
+            assert_eq!((left.0, right.0), (1, 2));
+        }
+    }
+
+    #[test]
+    fn test_kpoint_switches() {
+        let mut rng = crate::test_rng();
+
+        for k in [1, 3, 20, 25] {
+            for _ in 0..100 {
+                let (mut left, mut right) = ([0u8; 20], [1u8; 20]);
+                let mask = crossover_with_mask(
+                    &mut left,
+                    &mut right,
+                    CrossoverMethod::KPoint(k),
+                    &mut rng,
+                );
+
+                // The first swapped chromosome counts as a switch from the initial, unswapped state
+                let switches = std::iter::once(&false)
+                    .chain(&mask)
+                    .zip(&mask)
+                    .filter(|(previous, current)| previous != current)
+                    .count();
+                assert_eq!(switches as u64, k.min(20), "{mask:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_crossover_with_mask() {
+        let mut rng = crate::test_rng();
+
+        for method in [
+            CrossoverMethod::Uniform(1.0),
+            CrossoverMethod::KPoint(3),
+            CrossoverMethod::EvenKPoint(2),
+        ] {
+            for _ in 0..20 {
+                let parent_left = (0..32).collect::<Vec<u32>>();
+                let parent_right = (100..132).collect::<Vec<u32>>();
+                let (mut left, mut right) = (parent_left.clone(), parent_right.clone());
+
+                let mask = crossover_with_mask(&mut left, &mut right, method.clone(), &mut rng);
+                assert_eq!(mask.len(), left.size_hint());
+
+                for (index, swapped) in mask.into_iter().enumerate() {
+                    if swapped {
+                        assert_eq!(left[index], parent_right[index]);
+                        assert_eq!(right[index], parent_left[index]);
+                    } else {
+                        assert_eq!(left[index], parent_left[index]);
+                        assert_eq!(right[index], parent_right[index]);
+                    }
+                }
+            }
+        }
+
+        // Groups only have one entry
+        struct Grouped([u8; 3], u8);
+
+        impl Genome for Grouped {
+            fn mutate(&mut self, _mutator: &mut Mutator<impl rand::Rng>) {
+                unimplemented!()
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+                crossover
+                    .group(|crossover| {
+                        crossover.genome(&mut self.0, &mut other.0);
+                    })
+                    .chromosome(&mut self.1, &mut other.1);
+            }
+
+            fn size_hint(&self) -> usize {
+                2
+            }
+        }
+
+        let mut left = Grouped([0; 3], 0);
+        let mut right = Grouped([1; 3], 1);
+        let mask = crossover_with_mask(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![1]),
+            &mut rng,
+        );
+        assert_eq!(mask, [false, true]);
+        assert_eq!((left.0, left.1), ([0; 3], 1));
+    }
+
+    #[test]
+    fn test_try_errors() {
+        /// An rng that can't be used to seed other rngs.
+        struct FailingRng(StdRng);
+
+        impl rand::RngCore for FailingRng {
+            fn next_u32(&mut self) -> u32 {
+                self.0.next_u32()
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                self.0.next_u64()
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                self.0.fill_bytes(dest)
+            }
+
+            fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), rand::Error> {
+                Err(rand::Error::new("exhausted"))
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        let mut short = vec![0u8; 3];
+        let mut long = vec![0u8; 4];
+
+        assert!(matches!(
+            try_crossover(
+                &mut short,
+                &mut long,
+                CrossoverMethod::Uniform(1.0),
+                &mut rng
+            ),
+            Err(GenomicError::SizeMismatch { left: 3, right: 4 })
+        ));
+        assert!(matches!(
+            try_reproduce(&short, &long, CrossoverMethod::KPoint(1), 0.1, &mut rng),
+            Err(GenomicError::SizeMismatch { left: 3, right: 4 })
+        ));
+
+        for rate in [-0.5, 1.5, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                try_mutate(&mut short, rate, &mut rng),
+                Err(GenomicError::InvalidRate(_))
+            ));
+            assert!(matches!(
+                try_crossover(
+                    &mut short,
+                    &mut long[..3].to_vec(),
+                    CrossoverMethod::Uniform(rate),
+                    &mut rng
+                ),
+                Err(GenomicError::InvalidRate(_))
+            ));
+            assert!(matches!(
+                try_reproduce(&short, &short, CrossoverMethod::KPoint(1), rate, &mut rng),
+                Err(GenomicError::InvalidRate(_))
+            ));
+        }
+        assert_eq!(short, [0; 3]);
+
+        let error = try_reproduce(
+            &short,
+            &short,
+            CrossoverMethod::KPoint(1),
+            0.1,
+            &mut FailingRng(crate::test_rng()),
+        )
+        .unwrap_err();
+        assert!(matches!(error, GenomicError::RngSeeding(_)));
+        assert!(std::error::Error::source(&error).is_some());
+
+        assert!(matches!(
+            wrapper::UniformCh::try_new(2, 1),
+            Err(GenomicError::InvalidRange)
+        ));
+        assert!(matches!(
+            wrapper::UniformCh::try_new(f64::NAN, 1.0),
+            Err(GenomicError::InvalidRange)
+        ));
+
+        for weight in [-1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                try_crossover(
+                    &mut short,
+                    &mut vec![1; 3],
+                    CrossoverMethod::WeightedKPoint {
+                        k: 1,
+                        weights: vec![1.0, weight, 1.0],
+                    },
+                    &mut rng
+                ),
+                Err(GenomicError::InvalidWeight(_))
+            ));
+        }
+        assert_eq!(short, [0; 3]);
+
+        assert!(try_mutate(&mut short, 1.0, &mut rng).is_ok());
+        assert!(try_crossover(
+            &mut short,
+            &mut vec![1; 3],
+            CrossoverMethod::Uniform(0.5),
+            &mut rng
+        )
+        .is_ok());
+        let (left, right) = try_reproduce(
+            &short,
+            &vec![1; 3],
+            CrossoverMethod::KPoint(1),
+            0.1,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!((left.len(), right.len()), (3, 3));
+    }
+
+    #[test]
+    fn test_shared() {
+        let mut rng = crate::test_rng();
+
+        let individual = Arc::new(Mutex::new([0u64; 4]));
+        mutate_shared(&individual, 1.0, &mut rng);
+        assert_ne!(*individual.lock().unwrap(), [0; 4]);
+
+        // The sides are kept, whichever mutex is locked first
+        let left = Arc::new(Mutex::new([0u8; 8]));
+        let right = Arc::new(Mutex::new([1u8; 8]));
+        for (a, b, expected) in [(&left, &right, [1; 8]), (&right, &left, [0; 8])] {
+            crossover_shared(a, b, CrossoverMethod::FixedPoints(vec![0]), &mut rng);
+            assert_eq!(*left.lock().unwrap(), expected);
+            assert_eq!(*right.lock().unwrap(), expected.map(|x| 1 - x));
+        }
+
+        crossover_shared(
+            &left,
+            &left.clone(),
+            CrossoverMethod::FixedPoints(vec![0]),
+            &mut rng,
+        );
+        assert_eq!(*left.lock().unwrap(), [0; 8]);
+
+        // Crossing over the same pair in opposite orders from two threads doesn't deadlock
+        std::thread::scope(|scope| {
+            for swap_sides in [false, true] {
+                let (left, right) = (&left, &right);
+                scope.spawn(move || {
+                    let mut rng = crate::test_rng();
+                    for _ in 0..1000 {
+                        if swap_sides {
+                            crossover_shared(right, left, CrossoverMethod::Uniform(1.0), &mut rng);
+                        } else {
+                            crossover_shared(left, right, CrossoverMethod::Uniform(1.0), &mut rng);
+                        }
+                    }
+                });
+            }
+        });
+
+        let (left, right) = (*left.lock().unwrap(), *right.lock().unwrap());
+        assert!(left.iter().zip(right).all(|(&a, b)| a + b == 1));
+    }
+
+    #[test]
+    fn test_null_rng() {
+        let mut individual = (5u8, -3i32, true, [1u16; 2], Some(3u8));
+        mutate(&mut individual, 0.0, &mut NullRng);
+        assert_eq!(individual, (5, -3, true, [1; 2], Some(3)));
+
+        // Any non-zero rate flips every bit
+        mutate(&mut individual, 0.1, &mut NullRng);
+        assert_eq!(individual, (250, 2, false, [65534; 2], None));
+
+        // Random walks go to the lower bound
+        let mut value = 5;
+        Mutator::new(1.0, NullRng).with(&mut crate::wrapper::UniformCh::new(-10, 10), &mut value);
+        assert_eq!(value, -10);
+
+        let mut left = [0u8; 8];
+        let mut right = [1u8; 8];
+        crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::Uniform(0.1),
+            &mut NullRng,
+        );
+        assert_eq!((left, right), ([1; 8], [0; 8]));
+
+        crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::KPoint(2),
+            &mut NullRng,
+        );
+        assert_eq!(left, [0, 1, 1, 1, 1, 1, 1, 1]);
+
+        // Sampling many distinct indices out of a large genome terminates
+        let mut left = vec![0u8; 100_000];
+        let mut right = vec![1u8; 100_000];
+        mutate_n_genes(&mut left, 200, &mut NullRng);
+        assert_eq!(left.iter().filter(|&&gene| gene != 0).count(), 200);
+        crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::KPoint(200),
+            &mut NullRng,
+        );
+
+        let mut population = vec![[0u8; 2]; 100_000];
+        crate::population::hypermutate(&mut population, 1.0, 0.01, &mut NullRng);
+        assert_eq!(
+            population
+                .iter()
+                .filter(|&&individual| individual != [0; 2])
+                .count(),
+            1000
+        );
+    }
+
+    #[test]
+    fn test_sample_indices() {
+        let mut rng = crate::test_rng();
+
+        for (length, amount) in [(0, 0), (0, 3), (5, 5), (5, 8), (100, 10)] {
+            let mut indices = sample_indices(&mut rng, length, amount);
+            assert_eq!(indices.len(), amount.min(length));
+
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(indices.len(), amount.min(length));
+            assert!(indices.iter().all(|&index| index < length));
+        }
+
+        let mut counts = [0; 10];
+        for _ in 0..10000 {
+            for index in sample_indices(&mut rng, 10, 3) {
+                counts[index] += 1;
+            }
+        }
+        assert!(counts.iter().all(|&count| (2700..3300).contains(&count)));
+    }
+
+    #[test]
+    fn test_mutate_n_genes() {
+        let mut rng = crate::test_rng();
+
+        for n in [0, 1, 3, 16] {
+            let mut seen = [false; 16];
+            for _ in 0..100 {
+                let mut individual = [0u64; 16];
+                mutate_n_genes(&mut individual, n, &mut rng);
+
+                let changed = individual.iter().filter(|&&gene| gene != 0).count();
+                assert_eq!(changed, n);
+                for (seen, gene) in seen.iter_mut().zip(individual) {
+                    *seen |= gene != 0;
+                }
+            }
+            assert!(n == 0 || seen.iter().all(|&seen| seen));
+        }
+
+        let mut individual = [0u64; 4];
+        mutate_n_genes(&mut individual, 10, &mut rng);
+        assert!(individual.iter().all(|&gene| gene != 0));
+    }
+
+    #[test]
+    fn test_mutate_with_budget() {
+        let mut rng = crate::test_rng();
+        let changed = |individual: &(Vec<[u64; 4]>, [u64; 8])| {
+            let nested = individual
+                .0
+                .iter()
+                .flatten()
+                .filter(|&&gene| gene != 0)
+                .count();
+            let flat = individual.1.iter().filter(|&&gene| gene != 0).count();
+            (nested, flat)
+        };
+
+        let trials = 1000;
+        let (mut nested_total, mut flat_total) = (0, 0);
+        for _ in 0..trials {
+            let mut individual = (vec![[0u64; 4]; 8], [0u64; 8]);
+            mutate_with_budget(&mut individual, 2.5, &mut rng);
+
+            let (nested, flat) = changed(&individual);
+            assert!((2..=3).contains(&(nested + flat)));
+            nested_total += nested;
+            flat_total += flat;
+        }
+
+        let average = (nested_total + flat_total) as f64 / trials as f64;
+        assert!((2.4..2.6).contains(&average), "{average}");
+        // The nested vector holds 32 of the 40 chromosomes, and gets as much of the budget
+        let share = nested_total as f64 / (nested_total + flat_total) as f64;
+        assert!((0.75..0.85).contains(&share), "{share}");
+
+        let mut individual = (vec![[0u64; 4]; 8], [0u64; 8]);
+        mutate_with_budget(&mut individual, 10.0, &mut rng);
+        assert_eq!(changed(&individual).0 + changed(&individual).1, 10);
+
+        for budget in [0.0, -1.0, f64::NAN] {
+            let mut individual = (vec![[0u64; 4]; 8], [0u64; 8]);
+            mutate_with_budget(&mut individual, budget, &mut rng);
+            assert_eq!(changed(&individual), (0, 0));
+        }
+
+        for budget in [100.0, f64::INFINITY] {
+            let mut individual = [0u64; 4];
+            mutate_with_budget(&mut individual, budget, &mut rng);
+            assert!(individual.iter().all(|&gene| gene != 0));
+        }
+    }
+
+    #[test]
+    fn test_mutate_n_genes_groups() {
+        struct Grouped {
+            pair: [u64; 2],
+            wrapped: (u64, u64),
+            single: u64,
+        }
+
+        impl Genome for Grouped {
+            fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+                mutator
+                    .group(|mutator| {
+                        mutator.genome(&mut self.pair);
+                    })
+                    .with(
+                        &mut (wrapper::FixedBits::default(), wrapper::FixedBits::default()),
+                        &mut self.wrapped,
+                    )
+                    .chromosome(&mut self.single);
+            }
+
+            fn crossover(&mut self, _other: &mut Self, _crossover: &mut Crossover<impl rand::Rng>) {
+                unimplemented!()
+            }
+
+            fn size_hint(&self) -> usize {
+                3
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        for _ in 0..100 {
+            let mut individual = Grouped {
+                pair: [0; 2],
+                wrapped: (0, 0),
+                single: 0,
+            };
+            mutate_n_genes(&mut individual, 1, &mut rng);
+
+            // The selected group or wrapped value is mutated as a whole
+            let changed = [
+                individual.pair.iter().all(|&gene| gene != 0),
+                individual.wrapped.0 != 0 && individual.wrapped.1 != 0,
+                individual.single != 0,
+            ];
+            assert_eq!(changed.iter().filter(|&&changed| changed).count(), 1);
+            assert_eq!(
+                individual.pair.iter().filter(|&&gene| gene != 0).count()
+                    + (individual.wrapped.0 != 0) as usize
+                    + (individual.wrapped.1 != 0) as usize
+                    + (individual.single != 0) as usize,
+                if changed[2] { 1 } else { 2 }
+            );
+        }
+    }
+}
@@ -0,0 +1,879 @@
+//! A thin, optional layer for keeping track of a population of individuals across generations.
+
+use std::cell::OnceCell;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use rand::Rng;
+
+use crate::genome::{BatchFitness, Distance, Fitness, Genome, RandomInit, RandomInitSized};
+use crate::selection::{compare_fitness, tournament_by_fitness};
+use crate::traverse::CrossoverMethod;
+
+/// The parameters used by [Population::advance] to produce the next generation.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdvanceConfig {
+    /// The crossover method used when reproducing two parents.
+    pub crossover_method: CrossoverMethod,
+    /// The mutation rate applied to each child.
+    pub mutation_rate: f64,
+    /// The number of individuals taking part in each tournament when selecting a parent.
+    /// A size of `1` picks parents uniformly at random.
+    pub tournament_size: usize,
+    /// The number of best individuals that are carried over to the next generation unchanged.
+    pub elite: usize,
+}
+
+impl Default for AdvanceConfig {
+    fn default() -> Self {
+        Self {
+            crossover_method: CrossoverMethod::Uniform(1.0),
+            mutation_rate: 0.1,
+            tournament_size: 2,
+            elite: 0,
+        }
+    }
+}
+
+/// A collection of individuals, along with the current generation number.
+///
+/// The fitness of each individual is computed lazily and cached until the individuals change,
+/// either through [Population::advance] or [Population::individuals_mut].
+///
+/// With the `serde` feature flag, a population can be serialized to checkpoint a run;
+/// the cached fitnesses aren't serialized, and are recomputed after deserialization.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::Fitness;
+/// use genomic::population::{AdvanceConfig, Population};
+///
+/// #[derive(Clone, Debug)]
+/// struct Guess(u8);
+///
+/// # impl genomic::genome::Genome for Guess {
+/// #     fn mutate(&mut self, mutator: &mut genomic::traverse::Mutator<impl rand::Rng>) {
+/// #         mutator.chromosome(&mut self.0);
+/// #     }
+/// #     fn crossover(&mut self, other: &mut Self, crossover: &mut genomic::traverse::Crossover<impl rand::Rng>) {
+/// #         crossover.chromosome(&mut self.0, &mut other.0);
+/// #     }
+/// #     fn size_hint(&self) -> usize {
+/// #         1
+/// #     }
+/// # }
+/// impl Fitness for Guess {
+///     fn fitness(&self) -> f64 {
+///         -(self.0 as f64 - 42.0).abs()
+///     }
+/// }
+///
+/// let mut population = Population::new((0..16).map(Guess).collect());
+/// let config = AdvanceConfig::default();
+///
+/// for _ in 0..10 {
+///     population.advance(&config, &mut rand::thread_rng());
+/// }
+///
+/// assert_eq!(population.generation(), 10);
+/// println!("{:?}", population.best());
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Population<G> {
+    individuals: Vec<G>,
+    generation: u64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    fitnesses: OnceCell<Vec<f64>>,
+}
+
+impl<G> Population<G> {
+    /// Creates a new population from `individuals`, starting at generation `0`.
+    pub fn new(individuals: Vec<G>) -> Self {
+        Self {
+            individuals,
+            generation: 0,
+            fitnesses: OnceCell::new(),
+        }
+    }
+
+    /// Returns the current generation number, which is incremented by each call to [Population::advance].
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn individuals(&self) -> &[G] {
+        &self.individuals
+    }
+
+    /// Returns a mutable reference to the individuals, invalidating the cached fitnesses.
+    pub fn individuals_mut(&mut self) -> &mut Vec<G> {
+        self.fitnesses.take();
+        &mut self.individuals
+    }
+
+    pub fn into_individuals(self) -> Vec<G> {
+        self.individuals
+    }
+
+    pub fn len(&self) -> usize {
+        self.individuals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.individuals.is_empty()
+    }
+}
+
+impl<G: BatchFitness> Population<G> {
+    /// Returns the fitness of each individual, computing them if they aren't cached yet.
+    ///
+    /// The whole population is evaluated at once, with [BatchFitness::fitness_batch].
+    pub fn fitnesses(&self) -> &[f64] {
+        self.fitnesses.get_or_init(|| {
+            let fitnesses = G::fitness_batch(&self.individuals);
+            assert_eq!(
+                fitnesses.len(),
+                self.individuals.len(),
+                "BatchFitness::fitness_batch should return one fitness per individual"
+            );
+            fitnesses
+        })
+    }
+
+    /// Returns the fittest individual, or `None` if the population is empty.
+    /// If several individuals share the best fitness, the first one is returned.
+    pub fn best(&self) -> Option<&G> {
+        let fitnesses = self.fitnesses();
+
+        (0..fitnesses.len())
+            .reduce(|best, index| {
+                if compare_fitness(fitnesses[index], fitnesses[best]) == Ordering::Greater {
+                    index
+                } else {
+                    best
+                }
+            })
+            .map(|index| &self.individuals[index])
+    }
+
+    /// Returns the mean fitness of the population, or `None` if the population is empty.
+    pub fn mean_fitness(&self) -> Option<f64> {
+        let fitnesses = self.fitnesses();
+
+        if fitnesses.is_empty() {
+            None
+        } else {
+            Some(fitnesses.iter().sum::<f64>() / fitnesses.len() as f64)
+        }
+    }
+}
+
+impl<G: Genome + BatchFitness + Clone> Population<G> {
+    /// Replaces the population with the next generation, keeping the same number of individuals.
+    ///
+    /// The `config.elite` fittest individuals are kept as-is,
+    /// and the rest of the next generation is made of the children of parents selected by tournament,
+    /// as produced by [crate::reproduce].
+    pub fn advance(&mut self, config: &AdvanceConfig, rng: &mut impl Rng) {
+        let length = self.individuals.len();
+        let mut next = Vec::with_capacity(length);
+
+        if length > 0 {
+            let fitnesses = self.fitnesses();
+            let mut ranking = (0..length).collect::<Vec<_>>();
+            ranking.sort_by(|&left, &right| compare_fitness(fitnesses[right], fitnesses[left]));
+            next.extend(
+                ranking
+                    .into_iter()
+                    .take(config.elite)
+                    .map(|index| self.individuals[index].clone()),
+            );
+
+            while next.len() < length {
+                let fitnesses = self.fitnesses();
+                let parent_left = tournament_by_fitness(fitnesses, config.tournament_size, rng);
+                let parent_right = tournament_by_fitness(fitnesses, config.tournament_size, rng);
+                let (child_left, child_right) = crate::reproduce(
+                    &self.individuals[parent_left],
+                    &self.individuals[parent_right],
+                    config.crossover_method.clone(),
+                    config.mutation_rate,
+                    rng,
+                );
+
+                next.push(child_left);
+                if next.len() < length {
+                    next.push(child_right);
+                }
+            }
+        }
+
+        self.individuals = next;
+        self.generation += 1;
+        self.fitnesses.take();
+    }
+}
+
+/// Builds the next generation out of the current one and a batch of offspring, keeping the population size constant.
+///
+/// The `elite` fittest `parents` are carried over first, then the remaining slots are filled with the fittest `offspring`.
+/// If there aren't enough offspring to fill the population, then the next fittest parents fill the remaining slots.
+/// `elite` is capped to the number of parents, and the returned generation always has as many individuals as `parents`.
+///
+/// The returned individuals are sorted by decreasing fitness, with `NaN` fitnesses last.
+/// Ties are broken in favor of the elite, then of the individual that comes first in its slice.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::Fitness;
+/// use genomic::population::elitist_replacement;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Score(f64);
+///
+/// impl Fitness for Score {
+///     fn fitness(&self) -> f64 {
+///         self.0
+///     }
+/// }
+///
+/// let parents = [Score(5.0), Score(1.0), Score(3.0)];
+/// let offspring = vec![Score(2.0), Score(4.0), Score(0.0)];
+///
+/// assert_eq!(
+///     elitist_replacement(&parents, offspring, 1),
+///     [Score(5.0), Score(4.0), Score(2.0)]
+/// );
+/// ```
+pub fn elitist_replacement<G: Fitness + Clone>(
+    parents: &[G],
+    offspring: Vec<G>,
+    elite: usize,
+) -> Vec<G> {
+    let length = parents.len();
+    let elite = elite.min(length);
+
+    let parent_fitnesses = parents.iter().map(Fitness::fitness).collect::<Vec<_>>();
+    let mut parent_ranking = (0..length).collect::<Vec<_>>();
+    parent_ranking
+        .sort_by(|&left, &right| compare_fitness(parent_fitnesses[right], parent_fitnesses[left]));
+
+    let mut offspring = offspring
+        .into_iter()
+        .map(|individual| (individual.fitness(), individual))
+        .collect::<Vec<_>>();
+    offspring.sort_by(|(left, _), (right, _)| compare_fitness(*right, *left));
+    offspring.truncate(length - elite);
+
+    // The parents that weren't part of the elite fill in for any missing offspring
+    let missing = length - elite - offspring.len();
+    let survivors = parent_ranking[elite..]
+        .iter()
+        .take(missing)
+        .map(|&index| (parent_fitnesses[index], parents[index].clone()));
+
+    let mut next = parent_ranking[..elite]
+        .iter()
+        .map(|&index| (parent_fitnesses[index], parents[index].clone()))
+        .chain(offspring)
+        .chain(survivors)
+        .collect::<Vec<_>>();
+    next.sort_by(|(left, _), (right, _)| compare_fitness(*right, *left));
+
+    next.into_iter().map(|(_, individual)| individual).collect()
+}
+
+/// Returns `size` random individuals, created with [RandomInit::random], to start a genetic algorithm from.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::population::{random_population, Population};
+///
+/// let individuals = random_population::<[u8; 4]>(20, &mut rand::thread_rng());
+/// let population = Population::new(individuals);
+///
+/// assert_eq!(population.individuals().len(), 20);
+/// ```
+pub fn random_population<G: RandomInit>(size: usize, rng: &mut impl Rng) -> Vec<G> {
+    (0..size).map(|_| G::random(rng)).collect()
+}
+
+/// Returns `size` random individuals of `length` elements each, created with [RandomInitSized::random_sized].
+pub fn random_population_sized<G: RandomInitSized>(
+    size: usize,
+    length: usize,
+    rng: &mut impl Rng,
+) -> Vec<G> {
+    (0..size).map(|_| G::random_sized(length, rng)).collect()
+}
+
+/// Mutates a random `fraction` of `population` in place, at the (usually high) `rate`, leaving the other individuals untouched.
+///
+/// This is meant to help a stagnating population escape a local optimum, by re-introducing diversity.
+/// `fraction` is clamped between `0.0` and `1.0`, and the number of mutated individuals is rounded to the nearest integer.
+/// The mutated individuals are chosen uniformly, without replacement, and each is mutated once with [crate::mutate].
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::population::hypermutate;
+///
+/// let mut population = vec![[0u8; 8]; 10];
+/// hypermutate(&mut population, 1.0, 0.2, &mut rand::thread_rng());
+///
+/// // Up to 2 individuals were scrambled
+/// assert!(population.iter().filter(|individual| **individual != [0; 8]).count() <= 2);
+/// ```
+pub fn hypermutate<G: Genome>(population: &mut [G], rate: f64, fraction: f64, rng: &mut impl Rng) {
+    let count = (population.len() as f64 * fraction.clamp(0.0, 1.0)).round() as usize;
+
+    for index in crate::sample_indices(rng, population.len(), count) {
+        crate::mutate(&mut population[index], rate, rng);
+    }
+}
+
+/// Returns the shared fitness of each individual of `population`, for maintaining several niches at once.
+///
+/// The fitness of each individual is divided by its niche count, `\sum_j sh(d_ij)`,
+/// with `sh(d) = 1 - (d / sigma_share)^alpha` for `d < sigma_share` and `sh(d) = 0` otherwise.
+/// Since an individual is at a distance of `0` from itself, the niche count is at least `1`,
+/// and an individual with no neighbor within `sigma_share` keeps its fitness.
+///
+/// Fitness sharing assumes non-negative fitnesses; a `NaN` fitness stays `NaN`.
+/// This evaluates every individual once and computes `O(n^2)` distances.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::genome::{Distance, Fitness};
+/// use genomic::population::shared_fitness;
+///
+/// struct Point(f64);
+///
+/// impl Fitness for Point {
+///     fn fitness(&self) -> f64 {
+///         1.0
+///     }
+/// }
+///
+/// impl Distance for Point {
+///     fn distance(&self, other: &Self) -> f64 {
+///         (self.0 - other.0).abs()
+///     }
+/// }
+///
+/// let population = [Point(0.0), Point(0.0), Point(10.0)];
+/// assert_eq!(shared_fitness(&population, 1.0, 1.0), [0.5, 0.5, 1.0]);
+/// ```
+pub fn shared_fitness<G: Fitness + Distance>(
+    population: &[G],
+    sigma_share: f64,
+    alpha: f64,
+) -> Vec<f64> {
+    population
+        .iter()
+        .map(|individual| {
+            let niche_count = population
+                .iter()
+                .map(|other| {
+                    let distance = individual.distance(other);
+                    if distance < sigma_share {
+                        1.0 - (distance / sigma_share).powf(alpha)
+                    } else {
+                        0.0
+                    }
+                })
+                .sum::<f64>();
+
+            individual.fitness() / niche_count.max(1.0)
+        })
+        .collect()
+}
+
+/// Returns the number of distinct individuals in `population`, to monitor its convergence.
+///
+/// This hashes every individual once, and stores a reference to each distinct individual;
+/// for large genomes, hashing can dominate the cost, so this is best computed every few generations.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::population::distinct_count;
+///
+/// let population = [[0u8; 4], [1; 4], [0; 4]];
+/// assert_eq!(distinct_count(&population), 2);
+/// ```
+pub fn distinct_count<G: Eq + Hash>(population: &[G]) -> usize {
+    population.iter().collect::<HashSet<_>>().len()
+}
+
+/// Returns the most common individual of `population`, along with its number of occurrences,
+/// or `None` if `population` is empty.
+///
+/// If several individuals are the most common, then the one appearing first in `population` is returned.
+/// Like [distinct_count], this hashes every individual once.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::population::most_common;
+///
+/// let population = [[1u8; 4], [0; 4], [0; 4]];
+/// assert_eq!(most_common(&population), Some((&[0; 4], 2)));
+/// ```
+pub fn most_common<G: Eq + Hash>(population: &[G]) -> Option<(&G, usize)> {
+    let mut counts = HashMap::<&G, (usize, usize)>::new();
+    for (index, individual) in population.iter().enumerate() {
+        counts.entry(individual).or_insert((index, 0)).1 += 1;
+    }
+
+    counts
+        .into_iter()
+        .max_by(|(_, (left_index, left)), (_, (right_index, right))| {
+            left.cmp(right).then(right_index.cmp(left_index))
+        })
+        .map(|(individual, (_, count))| (individual, count))
+}
+
+/// Detects when the best fitness of a population stops improving, for instance to trigger [hypermutate] or a restart.
+///
+/// Feed it the best fitness of each generation with [StagnationDetector::update].
+/// A generation only counts as an improvement if its fitness beats the best fitness seen so far by more than `epsilon`;
+/// smaller improvements (and `NaN` fitnesses) count towards the stagnation.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::population::StagnationDetector;
+///
+/// let mut detector = StagnationDetector::new(2, 0.01);
+///
+/// assert!(!detector.update(1.0));
+/// assert!(!detector.update(1.005));
+/// assert!(detector.update(1.0));
+/// // A large enough improvement resets the detector
+/// assert!(!detector.update(2.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StagnationDetector {
+    /// The number of generations without improvement after which stagnation is detected.
+    pub patience: usize,
+    /// The minimum increase of the best fitness that counts as an improvement.
+    pub epsilon: f64,
+    best: Option<f64>,
+    stalled: usize,
+}
+
+impl StagnationDetector {
+    pub fn new(patience: usize, epsilon: f64) -> Self {
+        Self {
+            patience,
+            epsilon,
+            best: None,
+            stalled: 0,
+        }
+    }
+
+    /// Records the best fitness of the current generation,
+    /// returning `true` if the fitness hasn't improved for at least `patience` generations.
+    ///
+    /// The detector keeps returning `true` until the fitness improves or [StagnationDetector::reset] is called.
+    pub fn update(&mut self, best_fitness: f64) -> bool {
+        let improved = match self.best {
+            _ if best_fitness.is_nan() => false,
+            Some(best) => best_fitness > best + self.epsilon,
+            None => true,
+        };
+
+        if improved {
+            self.best = Some(best_fitness);
+            self.stalled = 0;
+        } else {
+            self.stalled += 1;
+        }
+
+        self.stalled >= self.patience
+    }
+
+    /// Returns the number of generations since the last improvement.
+    pub fn generations_without_improvement(&self) -> usize {
+        self.stalled
+    }
+
+    /// Returns the best fitness seen since the last reset, or `None` if no (non-`NaN`) fitness was recorded yet.
+    pub fn best(&self) -> Option<f64> {
+        self.best
+    }
+
+    /// Forgets the best fitness and the number of generations without improvement,
+    /// for instance after restarting the population.
+    pub fn reset(&mut self) {
+        self.best = None;
+        self.stalled = 0;
+    }
+}
+
+impl<G> From<Vec<G>> for Population<G> {
+    fn from(individuals: Vec<G>) -> Self {
+        Self::new(individuals)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    thread_local! {
+        static EVALUATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Guess(u8);
+
+    impl Genome for Guess {
+        fn mutate(&mut self, mutator: &mut crate::traverse::Mutator<impl Rng>) {
+            mutator.chromosome(&mut self.0);
+        }
+
+        fn crossover(
+            &mut self,
+            other: &mut Self,
+            crossover: &mut crate::traverse::Crossover<impl Rng>,
+        ) {
+            crossover.chromosome(&mut self.0, &mut other.0);
+        }
+
+        fn size_hint(&self) -> usize {
+            1
+        }
+    }
+
+    impl Fitness for Guess {
+        fn fitness(&self) -> f64 {
+            EVALUATIONS.with(|evaluations| evaluations.set(evaluations.get() + 1));
+            -(self.0 as f64 - 42.0).abs()
+        }
+    }
+
+    #[test]
+    fn test_best() {
+        let population = Population::new(vec![Guess(0), Guess(40), Guess(44), Guess(100)]);
+        assert_eq!(population.best(), Some(&Guess(40)));
+        assert_eq!(
+            population.mean_fitness(),
+            Some(-(42.0 + 2.0 + 2.0 + 58.0) / 4.0)
+        );
+
+        let empty = Population::<Guess>::new(vec![]);
+        assert_eq!(empty.best(), None);
+        assert_eq!(empty.mean_fitness(), None);
+    }
+
+    #[test]
+    fn test_best_nan() {
+        struct Value(f64);
+
+        impl Fitness for Value {
+            fn fitness(&self) -> f64 {
+                self.0
+            }
+        }
+
+        let population = Population::new(vec![Value(f64::NAN), Value(-1.0), Value(f64::NAN)]);
+        assert_eq!(population.best().map(|value| value.0), Some(-1.0));
+    }
+
+    #[test]
+    fn test_cache_invalidation() {
+        let mut rng = StdRng::seed_from_u64(1410);
+        let mut population = Population::new((0..8).map(|x| Guess(x * 10)).collect());
+        EVALUATIONS.with(|evaluations| evaluations.set(0));
+
+        population.best();
+        population.mean_fitness();
+        assert_eq!(EVALUATIONS.with(Cell::get), 8);
+
+        let config = AdvanceConfig {
+            elite: 1,
+            ..AdvanceConfig::default()
+        };
+        population.advance(&config, &mut rng);
+        assert_eq!(population.generation(), 1);
+        assert_eq!(population.len(), 8);
+        assert_eq!(EVALUATIONS.with(Cell::get), 8);
+
+        // The elite is kept, and the new individuals are evaluated again
+        let best = population.best().unwrap().clone();
+        assert_eq!(EVALUATIONS.with(Cell::get), 16);
+        assert!(best.fitness() >= Guess(40).fitness());
+        assert_eq!(
+            population.fitnesses().to_vec(),
+            population
+                .individuals()
+                .iter()
+                .map(|guess| guess.fitness())
+                .collect::<Vec<_>>()
+        );
+
+        population.individuals_mut()[0] = Guess(42);
+        assert_eq!(population.best(), Some(&Guess(42)));
+    }
+
+    #[test]
+    fn test_elitist_replacement() {
+        let parents = (0..8).map(|x| Guess(x * 10)).collect::<Vec<_>>();
+
+        for elite in [0, 1, 3, 8, 20] {
+            for offspring_count in [0, 2, 8, 16] {
+                let offspring = (0..offspring_count)
+                    .map(|x| Guess(100 + x))
+                    .collect::<Vec<_>>();
+                let next = elitist_replacement(&parents, offspring, elite);
+
+                assert_eq!(next.len(), parents.len());
+                if elite > 0 || offspring_count == 0 {
+                    assert_eq!(next[0], Guess(40));
+                }
+            }
+        }
+
+        // The best offspring survive, and the elite is carried over even if the offspring are better
+        let offspring = vec![Guess(50), Guess(41), Guess(42), Guess(200), Guess(43)];
+        let next = elitist_replacement(&parents[..3], offspring.clone(), 1);
+        assert_eq!(next, [Guess(42), Guess(41), Guess(20)]);
+
+        let next = elitist_replacement(&parents[..3], offspring, 0);
+        assert_eq!(next, [Guess(42), Guess(41), Guess(43)]);
+
+        // Missing offspring are replaced by the next best parents
+        let next = elitist_replacement(&parents, vec![Guess(42)], 2);
+        assert_eq!(next[..4], [Guess(42), Guess(40), Guess(50), Guess(30)]);
+        assert_eq!(next.len(), 8);
+
+        assert!(elitist_replacement(&[] as &[Guess], vec![Guess(42)], 1).is_empty());
+    }
+
+    #[test]
+    fn test_hypermutate() {
+        let mut rng = crate::test_rng();
+
+        for (fraction, expected) in [(0.0, 0), (0.25, 4), (0.5, 8), (1.0, 16), (3.0, 16)] {
+            let mut population = vec![[0u64; 4]; 16];
+            hypermutate(&mut population, 1.0, fraction, &mut rng);
+
+            let changed = population
+                .iter()
+                .filter(|individual| **individual != [0; 4])
+                .collect::<Vec<_>>();
+            assert_eq!(changed.len(), expected);
+
+            // At a rate of `1.0`, about half of the bits are flipped
+            for individual in changed {
+                let flipped = individual.iter().map(|x| x.count_ones()).sum::<u32>();
+                assert!((64..192).contains(&flipped), "{flipped}");
+            }
+        }
+
+        hypermutate(&mut [] as &mut [u8], 1.0, 0.5, &mut rng);
+    }
+
+    #[test]
+    fn test_stagnation_detector() {
+        let mut detector = StagnationDetector::new(3, 0.1);
+
+        assert!(!detector.update(1.0));
+        assert!(!detector.update(2.0));
+        assert_eq!(detector.best(), Some(2.0));
+
+        // A plateau, with improvements within `epsilon`
+        let plateau = [2.0, 2.05, 2.1, 1.5, f64::NAN];
+        let fired = plateau
+            .into_iter()
+            .map(|fitness| detector.update(fitness))
+            .collect::<Vec<_>>();
+        assert_eq!(fired, [false, false, true, true, true]);
+        assert_eq!(detector.generations_without_improvement(), 5);
+        assert_eq!(detector.best(), Some(2.0));
+
+        assert!(!detector.update(2.2));
+        assert_eq!(detector.generations_without_improvement(), 0);
+
+        detector.reset();
+        assert_eq!(detector.best(), None);
+        assert!(!detector.update(-10.0));
+
+        // A patience of `0` fires on every generation
+        let mut detector = StagnationDetector::new(0, 0.0);
+        assert!(detector.update(f64::NAN));
+        assert!(detector.update(1.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use crate::wrapper::UniformCh;
+
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Creature {
+            speed: f32,
+            speed_bounds: UniformCh<f32>,
+            legs: u8,
+        }
+
+        impl Genome for Creature {
+            fn mutate(&mut self, mutator: &mut crate::traverse::Mutator<impl Rng>) {
+                mutator
+                    .with(&mut self.speed_bounds, &mut self.speed)
+                    .chromosome(&mut self.legs);
+            }
+
+            fn crossover(
+                &mut self,
+                other: &mut Self,
+                crossover: &mut crate::traverse::Crossover<impl Rng>,
+            ) {
+                crossover
+                    .chromosome(&mut self.speed, &mut other.speed)
+                    .chromosome(&mut self.legs, &mut other.legs);
+            }
+
+            fn size_hint(&self) -> usize {
+                2
+            }
+        }
+
+        impl Fitness for Creature {
+            fn fitness(&self) -> f64 {
+                self.speed as f64 - self.legs as f64
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        let individuals = (0..8)
+            .map(|legs| Creature {
+                speed: 0.5,
+                speed_bounds: UniformCh::new(0.0, 1.0),
+                legs,
+            })
+            .collect();
+        let mut population = Population::new(individuals);
+        let config = AdvanceConfig {
+            crossover_method: CrossoverMethod::KPoint(1),
+            ..AdvanceConfig::default()
+        };
+        population.advance(&config, &mut rng);
+        population.best();
+
+        let json = serde_json::to_string(&(&population, &config)).unwrap();
+        let (restored, restored_config): (Population<Creature>, AdvanceConfig) =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.generation(), 1);
+        assert_eq!(restored.individuals(), population.individuals());
+        assert_eq!(restored.fitnesses(), population.fitnesses());
+        assert_eq!(restored_config, config);
+    }
+
+    #[test]
+    fn test_shared_fitness() {
+        struct Peak(f64, f64);
+
+        impl Fitness for Peak {
+            fn fitness(&self) -> f64 {
+                self.1
+            }
+        }
+
+        impl Distance for Peak {
+            fn distance(&self, other: &Self) -> f64 {
+                (self.0 - other.0).abs()
+            }
+        }
+
+        let population = [Peak(0.0, 10.0), Peak(0.5, 10.0), Peak(5.0, 8.0)];
+        let shared = shared_fitness(&population, 1.0, 2.0);
+
+        // The two clustered individuals share their niche: `1 + (1 - 0.5^2)`
+        assert_eq!(shared[0], 10.0 / 1.75);
+        assert_eq!(shared[1], 10.0 / 1.75);
+        assert!(shared[0] < shared[2]);
+        // The isolated individual keeps its fitness
+        assert_eq!(shared[2], 8.0);
+
+        // A `sigma_share` of `0` disables sharing
+        assert_eq!(shared_fitness(&population, 0.0, 1.0), [10.0, 10.0, 8.0]);
+        assert!(shared_fitness(&[] as &[Peak], 1.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_distinct_count() {
+        let population = vec![[1u8, 2], [3, 4], [1, 2], [5, 6], [3, 4], [1, 2]];
+
+        assert_eq!(distinct_count(&population), 3);
+        assert_eq!(most_common(&population), Some((&[1, 2], 3)));
+
+        // Ties are broken in favor of the first individual
+        assert_eq!(most_common(&population[1..]), Some((&[3, 4], 2)));
+        assert_eq!(most_common(&[7u8, 8, 9]), Some((&7, 1)));
+
+        assert_eq!(distinct_count(&[] as &[u8]), 0);
+        assert_eq!(most_common(&[] as &[u8]), None);
+    }
+
+    #[test]
+    fn test_random_population() {
+        let mut rng = crate::test_rng();
+
+        let population = random_population::<(u16, [bool; 3])>(100, &mut rng);
+        assert_eq!(population.len(), 100);
+        assert!(distinct_count(&population) > 90);
+        assert!(population.iter().any(|individual| individual.1[0]));
+        assert!(population.iter().any(|individual| !individual.1[0]));
+
+        let population = random_population_sized::<Vec<i8>>(10, 5, &mut rng);
+        assert!(population.iter().all(|individual| individual.len() == 5));
+        assert_eq!(distinct_count(&population), 10);
+
+        assert!(random_population::<u8>(0, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_batch_fitness() {
+        struct Batched(u8);
+
+        impl BatchFitness for Batched {
+            fn fitness_batch(population: &[Self]) -> Vec<f64> {
+                population
+                    .iter()
+                    .map(|guess| Guess(guess.0).fitness())
+                    .collect()
+            }
+        }
+
+        let values = [3, 50, 41, 200, 42, 0];
+        let guesses = values.iter().map(|&value| Guess(value)).collect::<Vec<_>>();
+        let batched = values
+            .iter()
+            .map(|&value| Batched(value))
+            .collect::<Vec<_>>();
+
+        let per_item = guesses.iter().map(Fitness::fitness).collect::<Vec<_>>();
+        assert_eq!(Guess::fitness_batch(&guesses), per_item);
+        assert_eq!(Batched::fitness_batch(&batched), per_item);
+
+        let population = Population::new(batched);
+        assert_eq!(population.fitnesses(), &per_item[..]);
+        assert_eq!(population.best().map(|guess| guess.0), Some(42));
+    }
+}
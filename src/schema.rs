@@ -0,0 +1,300 @@
+//! Genomes stored as flat byte buffers, with their layout described at runtime by a schema.
+//!
+//! This is useful for genomes whose structure is only known at runtime,
+//! or that are kept in a serialized form and shouldn't be deserialized just to be mutated.
+
+use std::ops::Range;
+
+use rand::Rng;
+
+use crate::chromosome::Chromosome;
+use crate::genome::Genome;
+use crate::traverse::{Crossover, Mutator};
+use crate::wrapper::UniformCh;
+
+/// How the bytes of a field of a [SchemaGenome] are mutated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FieldOperator {
+    /// Each bit of the field is flipped with a probability of `rate * 0.5`, like the integer chromosomes.
+    BitFlip,
+    /// The field is read as a little-endian unsigned integer of up to 8 bytes,
+    /// and mutated like [UniformCh] between `min` and `max` (inclusive).
+    Uniform { min: u64, max: u64 },
+}
+
+/// The description of a field of a [SchemaGenome]: its position in the buffer and how it's mutated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldSpec {
+    /// The index of the first byte of the field.
+    pub offset: usize,
+    /// The number of bytes of the field.
+    pub width: usize,
+    pub operator: FieldOperator,
+}
+
+impl FieldSpec {
+    /// Creates the description of a field of `width` bytes, starting at the byte `offset`, and mutated by `operator`.
+    pub fn new(offset: usize, width: usize, operator: FieldOperator) -> Self {
+        Self {
+            offset,
+            width,
+            operator,
+        }
+    }
+
+    fn range(&self) -> Range<usize> {
+        self.offset..(self.offset + self.width)
+    }
+
+    /// Panics if this field doesn't fit in a buffer of `length` bytes, or if its operator doesn't fit in its width.
+    fn check(&self, length: usize) {
+        assert!(
+            self.offset
+                .checked_add(self.width)
+                .is_some_and(|end| end <= length),
+            "The field at offset {} should not go past the end of the buffer",
+            self.offset
+        );
+
+        if let FieldOperator::Uniform { min, max } = self.operator {
+            assert!(
+                self.width <= 8,
+                "Uniform fields should not be wider than 8 bytes"
+            );
+            assert!(min <= max, "`min` should not be greater than `max`");
+            assert!(
+                self.width == 8 || max < 1 << (8 * self.width),
+                "`max` should fit in the {} bytes of the field at offset {}",
+                self.width,
+                self.offset
+            );
+        }
+    }
+}
+
+/// A genome stored as a flat byte buffer, whose fields are described by a schema.
+///
+/// Each field of the schema is a chromosome: it is mutated according to its [FieldOperator],
+/// and swapped as a whole during crossover. Bytes that aren't part of any field are left untouched.
+/// Both individuals of a crossover should share the same schema; the schema of the left individual is used.
+///
+/// # Panics
+///
+/// Mutating or crossing over a genome panics if its schema doesn't fit its bytes, as described in [SchemaGenome::new]:
+/// the fields are checked by `new`, but not when they are set directly or deserialized.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::schema::{FieldOperator, FieldSpec, SchemaGenome};
+///
+/// let schema = vec![
+///     // A `u16` between 100 and 1000, stored in the two first bytes
+///     FieldSpec::new(0, 2, FieldOperator::Uniform { min: 100, max: 1000 }),
+///     // A set of 8 flags
+///     FieldSpec::new(2, 1, FieldOperator::BitFlip),
+/// ];
+/// let mut genome = SchemaGenome::new(schema, vec![100, 0, 0]);
+///
+/// genomic::mutate(&mut genome, 0.5, &mut rand::thread_rng());
+/// let value = u16::from_le_bytes([genome.bytes[0], genome.bytes[1]]);
+/// assert!((100..=1000).contains(&value));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaGenome {
+    pub schema: Vec<FieldSpec>,
+    pub bytes: Vec<u8>,
+}
+
+impl SchemaGenome {
+    /// Creates a new genome from its schema and its bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a field goes past the end of `bytes`,
+    /// or if a [FieldOperator::Uniform] field is wider than 8 bytes, has `min` greater than `max`,
+    /// or has a `max` that doesn't fit in the width of the field.
+    pub fn new(schema: Vec<FieldSpec>, bytes: Vec<u8>) -> Self {
+        for field in schema.iter() {
+            field.check(bytes.len());
+        }
+
+        Self { schema, bytes }
+    }
+}
+
+/// Reads `bytes` as a little-endian unsigned integer.
+fn read_le(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .rev()
+        .fold(0, |value, &byte| (value << 8) | byte as u64)
+}
+
+/// Writes `value` as a little-endian unsigned integer into `bytes`, truncating it if needed.
+fn write_le(bytes: &mut [u8], value: u64) {
+    for (index, byte) in bytes.iter_mut().enumerate() {
+        *byte = (value >> (8 * index)) as u8;
+    }
+}
+
+impl Genome for SchemaGenome {
+    fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+        for field in self.schema.iter() {
+            field.check(self.bytes.len());
+            let bytes = &mut self.bytes[field.range()];
+
+            match field.operator {
+                FieldOperator::BitFlip => {
+                    mutator.custom(|rate, rng| {
+                        for byte in bytes.iter_mut() {
+                            Chromosome::mutate(byte, rate, rng);
+                        }
+                    });
+                }
+                FieldOperator::Uniform { min, max } => {
+                    let mut value = read_le(bytes);
+                    mutator.with(&mut UniformCh::new(min, max), &mut value);
+                    write_le(bytes, value);
+                }
+            }
+        }
+    }
+
+    fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+        debug_assert_eq!(self.bytes.len(), other.bytes.len());
+
+        for field in self.schema.iter() {
+            field.check(self.bytes.len().min(other.bytes.len()));
+            let (bytes_left, bytes_right) = (
+                &mut self.bytes[field.range()],
+                &mut other.bytes[field.range()],
+            );
+
+            crossover.custom(|should_flip, _rng| {
+                if should_flip {
+                    bytes_left.swap_with_slice(bytes_right);
+                }
+            });
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        self.schema.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traverse::CrossoverMethod;
+
+    fn two_fields() -> SchemaGenome {
+        SchemaGenome::new(
+            vec![
+                FieldSpec::new(1, 1, FieldOperator::Uniform { min: 10, max: 20 }),
+                FieldSpec::new(3, 2, FieldOperator::BitFlip),
+            ],
+            vec![0xAA, 15, 0xBB, 0, 0, 0xCC],
+        )
+    }
+
+    #[test]
+    fn test_schema_mutate() {
+        let mut rng = crate::test_rng();
+        let mut changed = [false; 6];
+
+        for _ in 0..100 {
+            let mut genome = two_fields();
+            crate::mutate(&mut genome, 1.0, &mut rng);
+
+            assert_eq!(
+                [genome.bytes[0], genome.bytes[2], genome.bytes[5]],
+                [0xAA, 0xBB, 0xCC]
+            );
+            assert!((10..=20).contains(&genome.bytes[1]));
+
+            for (changed, (byte, initial)) in changed
+                .iter_mut()
+                .zip(genome.bytes.iter().zip(two_fields().bytes))
+            {
+                *changed |= *byte != initial;
+            }
+        }
+
+        assert_eq!(changed, [false, true, false, true, true, false]);
+    }
+
+    #[test]
+    fn test_schema_crossover() {
+        let mut rng = crate::test_rng();
+        let mut left = two_fields();
+        let mut right = two_fields();
+        right.bytes = vec![1, 2, 3, 4, 5, 6];
+
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![1]),
+            &mut rng,
+        );
+
+        assert_eq!(left.bytes, [0xAA, 15, 0xBB, 4, 5, 0xCC]);
+        assert_eq!(right.bytes, [1, 2, 3, 0, 0, 6]);
+    }
+
+    #[test]
+    fn test_read_write_le() {
+        let mut bytes = [0u8; 3];
+        write_le(&mut bytes, 0x12345678);
+        assert_eq!(bytes, [0x78, 0x56, 0x34]);
+        assert_eq!(read_le(&bytes), 0x345678);
+    }
+
+    #[test]
+    #[should_panic(expected = "past the end")]
+    fn test_schema_out_of_bounds() {
+        SchemaGenome::new(
+            vec![FieldSpec::new(2, 2, FieldOperator::BitFlip)],
+            vec![0; 3],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "should fit in the 1 bytes")]
+    fn test_schema_uniform_too_narrow() {
+        SchemaGenome::new(
+            vec![FieldSpec::new(
+                0,
+                1,
+                FieldOperator::Uniform { min: 0, max: 1000 },
+            )],
+            vec![0; 2],
+        );
+    }
+
+    #[test]
+    fn test_schema_uniform_widths() {
+        for (width, max) in [(1, 255), (2, 1000), (8, u64::MAX)] {
+            SchemaGenome::new(
+                vec![FieldSpec::new(
+                    0,
+                    width,
+                    FieldOperator::Uniform { min: 0, max },
+                )],
+                vec![0; 8],
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "past the end")]
+    fn test_schema_checked_on_mutate() {
+        let mut genome = two_fields();
+        genome.bytes.truncate(4);
+        crate::mutate(&mut genome, 0.5, &mut crate::test_rng());
+    }
+}
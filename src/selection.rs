@@ -0,0 +1,236 @@
+//! Helpers for selecting the parents of the next generation.
+//!
+//! Each selection method comes in two flavors: one taking the individuals themselves,
+//! evaluated through the [Fitness] trait, and one taking a slice of precomputed fitnesses and returning an index.
+//! Both flavors share the same rules:
+//! - higher fitnesses are better, and `NaN` is worse than any other fitness
+//! - ties are broken in favor of the individual that was picked first
+
+use std::cmp::Ordering;
+
+use rand::Rng;
+
+use crate::genome::Fitness;
+
+/// Compares two fitnesses, treating `NaN` as worse than any other fitness.
+pub(crate) fn compare_fitness(left: f64, right: f64) -> Ordering {
+    match (left.is_nan(), right.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => left.partial_cmp(&right).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// Runs a tournament among `size` individuals picked uniformly (with replacement) out of `length`,
+/// with `fitness` returning the fitness of the individual at a given index.
+fn tournament_with(
+    length: usize,
+    size: usize,
+    mut fitness: impl FnMut(usize) -> f64,
+    rng: &mut impl Rng,
+) -> usize {
+    assert!(
+        length > 0,
+        "Cannot select an individual from an empty population"
+    );
+
+    let mut best = crate::gen_index(rng, length);
+    let mut best_fitness = fitness(best);
+
+    for _ in 1..size {
+        let index = crate::gen_index(rng, length);
+        let index_fitness = fitness(index);
+
+        if compare_fitness(index_fitness, best_fitness) == Ordering::Greater {
+            best = index;
+            best_fitness = index_fitness;
+        }
+    }
+
+    best
+}
+
+/// Returns the weights used by roulette selection: the fitnesses are shifted so that the lowest one is `0`,
+/// and `NaN` fitnesses get a weight of `0`.
+fn roulette_weights(fitnesses: impl Iterator<Item = f64> + Clone) -> Vec<f64> {
+    let min = fitnesses
+        .clone()
+        .filter(|fitness| !fitness.is_nan())
+        .fold(f64::INFINITY, f64::min)
+        .min(0.0);
+
+    fitnesses
+        .map(|fitness| if fitness.is_nan() { 0.0 } else { fitness - min })
+        .collect()
+}
+
+/// Picks an index with a probability proportional to its weight,
+/// falling back to a uniform pick if no index has a positive weight.
+fn roulette_with(weights: &[f64], rng: &mut impl Rng) -> usize {
+    assert!(
+        !weights.is_empty(),
+        "Cannot select an individual from an empty population"
+    );
+
+    match rand::distributions::WeightedIndex::new(weights) {
+        Ok(distribution) => rng.sample(distribution),
+        Err(_) => crate::gen_index(rng, weights.len()),
+    }
+}
+
+/// Picks `size` individuals at random (with replacement) and returns the index of the fittest,
+/// given the fitness of each individual.
+///
+/// A `size` of `0` or `1` picks an individual uniformly at random.
+///
+/// # Panics
+///
+/// Panics if `fitnesses` is empty.
+pub fn tournament_by_fitness(fitnesses: &[f64], size: usize, rng: &mut impl Rng) -> usize {
+    tournament_with(fitnesses.len(), size, |index| fitnesses[index], rng)
+}
+
+/// Picks `size` individuals at random (with replacement) and returns the fittest.
+///
+/// Only the individuals taking part in the tournament are evaluated.
+/// See [tournament_by_fitness] for a version working on precomputed fitnesses.
+///
+/// # Panics
+///
+/// Panics if `population` is empty.
+pub fn tournament<'a, G: Fitness>(population: &'a [G], size: usize, rng: &mut impl Rng) -> &'a G {
+    let index = tournament_with(
+        population.len(),
+        size,
+        |index| population[index].fitness(),
+        rng,
+    );
+
+    &population[index]
+}
+
+/// Returns the index of an individual picked with a probability proportional to its fitness,
+/// given the fitness of each individual.
+///
+/// If some fitnesses are negative, then all of the fitnesses are shifted so that the lowest one is `0`.
+/// Individuals with a fitness of `NaN` are never picked, unless no individual has a positive weight,
+/// in which case the index is picked uniformly.
+///
+/// # Panics
+///
+/// Panics if `fitnesses` is empty.
+pub fn roulette_by_fitness(fitnesses: &[f64], rng: &mut impl Rng) -> usize {
+    roulette_with(&roulette_weights(fitnesses.iter().copied()), rng)
+}
+
+/// Returns an individual picked with a probability proportional to its fitness.
+///
+/// Every individual is evaluated.
+/// See [roulette_by_fitness] for the details, and for a version working on precomputed fitnesses.
+///
+/// # Panics
+///
+/// Panics if `population` is empty.
+pub fn roulette<'a, G: Fitness>(population: &'a [G], rng: &mut impl Rng) -> &'a G {
+    let fitnesses = population.iter().map(Fitness::fitness).collect::<Vec<_>>();
+
+    &population[roulette_by_fitness(&fitnesses, rng)]
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    struct Value(f64);
+
+    impl Fitness for Value {
+        fn fitness(&self) -> f64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_tournament_by_fitness() {
+        let mut rng = crate::test_rng();
+        let fitnesses = [1.0, f64::NAN, 5.0, -3.0];
+
+        // A large tournament almost always contains the best individual
+        let mut best = 0;
+        for _ in 0..100 {
+            if tournament_by_fitness(&fitnesses, 32, &mut rng) == 2 {
+                best += 1;
+            }
+        }
+        assert!(best > 95);
+
+        // `NaN` only wins against other `NaN`s
+        for _ in 0..100 {
+            assert_eq!(tournament_by_fitness(&[f64::NAN, -1.0], 32, &mut rng), 1);
+        }
+        assert_eq!(tournament_by_fitness(&[f64::NAN], 4, &mut rng), 0);
+
+        let mut picked = [0; 4];
+        for _ in 0..1000 {
+            picked[tournament_by_fitness(&fitnesses, 1, &mut rng)] += 1;
+        }
+        assert!(picked.into_iter().all(|count| count > 150));
+    }
+
+    #[test]
+    fn test_roulette_by_fitness() {
+        let mut rng = crate::test_rng();
+
+        let mut picked = [0; 3];
+        for _ in 0..10000 {
+            picked[roulette_by_fitness(&[1.0, 3.0, f64::NAN], &mut rng)] += 1;
+        }
+        assert!((2200..2800).contains(&picked[0]));
+        assert_eq!(picked[2], 0);
+
+        // Negative fitnesses are shifted, so the worst individual is never picked
+        for _ in 0..100 {
+            assert_ne!(roulette_by_fitness(&[-2.0, -1.0, 0.0], &mut rng), 0);
+        }
+
+        // Without any positive weight, the pick is uniform
+        let mut picked = [0; 2];
+        for _ in 0..1000 {
+            picked[roulette_by_fitness(&[f64::NAN, f64::NAN], &mut rng)] += 1;
+        }
+        assert!(picked[0] > 400 && picked[1] > 400);
+    }
+
+    #[test]
+    fn test_trait_matches_slice() {
+        let population = [
+            Value(1.0),
+            Value(f64::NAN),
+            Value(5.0),
+            Value(-3.0),
+            Value(5.0),
+        ];
+        let fitnesses = population.iter().map(|value| value.0).collect::<Vec<_>>();
+
+        for size in [1, 2, 5] {
+            let mut rng = StdRng::seed_from_u64(1418);
+            let mut reference_rng = rng.clone();
+
+            for _ in 0..100 {
+                let picked = tournament(&population, size, &mut rng);
+                let index = tournament_by_fitness(&fitnesses, size, &mut reference_rng);
+                assert!(std::ptr::eq(picked, &population[index]));
+            }
+        }
+
+        let mut rng = StdRng::seed_from_u64(1418);
+        let mut reference_rng = rng.clone();
+        for _ in 0..100 {
+            let picked = roulette(&population, &mut rng);
+            let index = roulette_by_fitness(&fitnesses, &mut reference_rng);
+            assert!(std::ptr::eq(picked, &population[index]));
+        }
+    }
+}
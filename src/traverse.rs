@@ -1,19 +1,177 @@
 use crate::{
     wrapper::{CrossoverWrapper, MutationWrapper},
-    Chromosome, Genome,
+    Chromosome, ContextualChromosome, Genome,
 };
-use rand::Rng;
+use rand::{Rng, RngCore};
+
+/// The maximum number of attempts made by [Mutator::at_least_one].
+const AT_LEAST_ONE_ATTEMPTS: usize = 64;
 
 /// A helper struct for performing the mutation operation on genomes.
+///
+/// Like the functions of this crate, it is generic over its rng, which may also be a trait object:
+/// `Box<dyn RngCore + Send>` implements [Rng], so it can be passed (by mutable reference) wherever an rng is expected,
+/// for instance in code that cannot be generic over the rng.
 pub struct Mutator<R: Rng> {
     rate: f64,
+    magnitude: f64,
     rng: R,
+    mode: MutatorMode,
+    labels: Vec<&'static str>,
+}
+
+/// What a [Mutator] does with the chromosomes it visits.
+///
+/// The diagnostic modes share a single field, so that visiting a chromosome only needs to check it once.
+enum MutatorMode {
+    /// Every chromosome is mutated.
+    Mutate,
+    /// The chromosomes are recorded instead of being mutated, for [crate::dry_run_mutate].
+    Trace(Vec<TraversalStep>),
+    /// Only the selected chromosomes are mutated, for [crate::mutate_n_genes].
+    Select(Selection),
+}
+
+/// The chromosomes selected for mutation by [crate::mutate_n_genes], indexed in traversal order.
+struct Selection {
+    selected: Vec<bool>,
+    passed: usize,
+}
+
+/// A step of the traversal of a genome, as recorded by [crate::dry_run_mutate] and [crate::dry_run_crossover].
+///
+/// The traversals of [Genome::mutate] and [Genome::crossover] should generally yield the same steps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TraversalStep {
+    /// A single chromosome, along with the name of its type.
+    /// Values passed to a wrapper (with [Mutator::with] or [Crossover::with]) are recorded as a single chromosome.
+    Chromosome(&'static str),
+    /// A call to [Mutator::custom] or [Crossover::custom].
+    Custom,
+    /// The start of a [Mutator::group] or [Crossover::group].
+    GroupStart,
+    /// The end of a [Mutator::group] or [Crossover::group].
+    GroupEnd,
+}
+
+fn label_path(labels: &[&'static str]) -> String {
+    if labels.is_empty() {
+        String::from("<root>")
+    } else {
+        labels.join(".")
+    }
+}
+
+/// Returns the name of the type of a value passed by reference to a wrapper.
+fn wrapped_type_name<G>() -> &'static str {
+    std::any::type_name::<G>().trim_start_matches("&mut ")
 }
 
 impl<R: Rng> Mutator<R> {
+    /// Creates a mutator with the given mutation `rate`, which owns `rng`.
+    ///
+    /// Most code should call [crate::mutate] instead; this is meant for code that needs to hold on to a mutator,
+    /// for instance to pass it to [Genome::mutate] from an application that cannot be generic over the rng.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use genomic::prelude::*;
+    /// use rand::{rngs::StdRng, RngCore, SeedableRng};
+    ///
+    /// struct Plugin {
+    ///     mutator: Mutator<Box<dyn RngCore + Send>>,
+    /// }
+    ///
+    /// fn assert_send<T: Send>(_: &T) {}
+    ///
+    /// let mut plugin = Plugin {
+    ///     mutator: Mutator::new(1.0, Box::new(StdRng::seed_from_u64(0))),
+    /// };
+    /// assert_send(&plugin);
+    ///
+    /// let mut values = [0u32; 4];
+    /// values.mutate(&mut plugin.mutator);
+    /// assert!(values.iter().any(|&value| value != 0));
+    ///
+    /// // The functions of the crate accept a boxed rng as well
+    /// let mut rng: Box<dyn RngCore + Send> = Box::new(StdRng::seed_from_u64(1));
+    /// genomic::mutate(&mut values, 0.5, &mut rng);
+    /// ```
+    #[inline(always)]
+    pub fn new(rate: f64, rng: R) -> Self {
+        Self {
+            rate,
+            magnitude: 1.0,
+            rng,
+            mode: MutatorMode::Mutate,
+            labels: Vec::new(),
+        }
+    }
+
+    /// Creates a mutator that records the traversal of genomes instead of mutating them.
+    pub(crate) fn dry_run(rng: R) -> Self {
+        Self {
+            rate: 0.0,
+            magnitude: 1.0,
+            rng,
+            mode: MutatorMode::Trace(Vec::new()),
+            labels: Vec::new(),
+        }
+    }
+
+    pub(crate) fn into_trace(self) -> Vec<TraversalStep> {
+        match self.mode {
+            MutatorMode::Trace(trace) => trace,
+            _ => Vec::new(),
+        }
+    }
+
+    /// Restricts the mutation to the top-level chromosomes, wrapped values, custom operations and groups
+    /// whose index (in traversal order) is `true` in `selected`; the other ones are skipped.
+    pub(crate) fn select(mut self, selected: Vec<bool>) -> Self {
+        self.mode = MutatorMode::Select(Selection {
+            selected,
+            passed: 0,
+        });
+        self
+    }
+
+    /// Visits the next chromosome (or wrapped value, custom operation or group), returning whether it should be mutated.
+    ///
+    /// When tracing, `step` is recorded and nothing should be mutated;
+    /// when only some chromosomes are selected, this moves on to the chromosome after it.
+    #[inline(always)]
+    fn visit(&mut self, step: TraversalStep) -> bool {
+        match &mut self.mode {
+            MutatorMode::Mutate => true,
+            MutatorMode::Trace(trace) => {
+                trace.push(step);
+                false
+            }
+            MutatorMode::Select(selection) => {
+                let selected = selection
+                    .selected
+                    .get(selection.passed)
+                    .copied()
+                    .unwrap_or(false);
+                selection.passed += 1;
+                selected
+            }
+        }
+    }
+
+    /// Runs `callback` without restricting the mutation to the selected chromosomes,
+    /// for the values within a selected wrapped value or group, which are all part of the selected chromosome.
     #[inline(always)]
-    pub(crate) fn new(rate: f64, rng: R) -> Self {
-        Self { rate, rng }
+    fn within_selected(&mut self, callback: impl FnOnce(&mut Self)) {
+        if let MutatorMode::Select(_) = self.mode {
+            let mode = std::mem::replace(&mut self.mode, MutatorMode::Mutate);
+            callback(self);
+            self.mode = mode;
+        } else {
+            callback(self);
+        }
     }
 
     /// Instructs the mutation helper to mutate a single chromosome.
@@ -22,7 +180,29 @@ impl<R: Rng> Mutator<R> {
         &'a mut self,
         chromosome: &mut Ch,
     ) -> &'a mut Self {
-        chromosome.mutate(self.rate, &mut self.rng);
+        if self.visit(TraversalStep::Chromosome(std::any::type_name::<Ch>())) {
+            chromosome.mutate(self.rate, &mut self.rng);
+        }
+
+        self
+    }
+
+    /// Instructs the mutation helper to mutate a single chromosome, passing it the shared context `ctx`.
+    ///
+    /// This counts as one chromosome, like [Mutator::chromosome]; see [ContextualChromosome].
+    #[inline(always)]
+    pub fn chromosome_ctx<'a, Ch, Ctx>(
+        &'a mut self,
+        chromosome: &mut Ch,
+        ctx: &mut Ctx,
+    ) -> &'a mut Self
+    where
+        Ch: ContextualChromosome<Ctx> + ?Sized,
+        Ctx: ?Sized,
+    {
+        if self.visit(TraversalStep::Chromosome(std::any::type_name::<Ch>())) {
+            chromosome.mutate_ctx(self.rate, ctx, &mut self.rng);
+        }
 
         self
     }
@@ -48,7 +228,26 @@ impl<R: Rng> Mutator<R> {
         self
     }
 
+    /// Mutates each element of `items`, with the rate of the `i`-th element multiplied by `rates[i]`.
+    ///
+    /// This is equivalent to wrapping the mutation of each element in a call to [Mutator::multiply_rate],
+    /// so the resulting rates are clamped to `[0, 1]`.
+    /// Elements past the end of `rates` use a multiplier of `1.0`, and extra rates are ignored.
+    #[inline(always)]
+    pub fn iter_rated<'a, G: Genome>(&'a mut self, items: &mut [G], rates: &[f64]) -> &'a mut Self {
+        for (index, item) in items.iter_mut().enumerate() {
+            let rate_multiplier = rates.get(index).copied().unwrap_or(1.0);
+            self.multiply_rate(rate_multiplier, |mutator| item.mutate(mutator));
+        }
+
+        self
+    }
+
     /// Defines a group of chromosomes that have a lower rate of mutation than the other chromosomes.
+    ///
+    /// The mutation rate within `callback` is the current rate multiplied by `rate_multiplier` (clamped to `[0, 1]`).
+    /// Because it is relative to the current rate, this composes with the rate passed to [crate::mutate] or [crate::reproduce],
+    /// as well as with nested calls to `multiply_rate`.
     #[inline(always)]
     pub fn multiply_rate<'a, 'b, F: for<'c> FnOnce(&'c mut Self) + 'b>(
         &'a mut self,
@@ -64,6 +263,96 @@ impl<R: Rng> Mutator<R> {
         self
     }
 
+    /// Performs the mutation of `value` within `callback`, re-running it until `value` changes.
+    ///
+    /// `value` is compared against a snapshot taken before the first attempt,
+    /// so the callback should only mutate `value`, for instance with `|mutator, value| { mutator.genome(value); }`.
+    /// A genome that cannot change would make this loop forever, so at most `64` attempts are made,
+    /// after which `value` is left as-is. This method returns whether `value` changed.
+    ///
+    /// Each attempt mutates the result of the previous one, which is identical to the snapshot,
+    /// so this is equivalent to rejecting the outcomes where nothing changed.
+    pub fn at_least_one<T, F>(&mut self, value: &mut T, mut callback: F) -> bool
+    where
+        T: Clone + PartialEq,
+        F: FnMut(&mut Self, &mut T),
+    {
+        let passed = match &self.mode {
+            MutatorMode::Mutate => None,
+            MutatorMode::Trace(_) => {
+                callback(self, value);
+                return false;
+            }
+            MutatorMode::Select(selection) => Some(selection.passed),
+        };
+
+        let snapshot = value.clone();
+
+        for _ in 0..AT_LEAST_ONE_ATTEMPTS {
+            // Each attempt covers the same chromosomes, so it starts from the same position in the selection
+            if let (MutatorMode::Select(selection), Some(passed)) = (&mut self.mode, passed) {
+                selection.passed = passed;
+            }
+
+            callback(self, value);
+
+            if *value != snapshot {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Tags the operations performed within `callback` with `name`, for diagnostic purposes.
+    ///
+    /// This has no effect on the mutation itself; the names of the enclosing labels can be read with [Mutator::label_path].
+    /// Since [Validate](crate::genome::Validate) is only checked on the whole individual,
+    /// the failures of [checked_mutate](crate::checked_mutate) don't include the label path.
+    #[inline(always)]
+    pub fn labeled<'a, 'b, F: for<'c> FnOnce(&'c mut Self) + 'b>(
+        &'a mut self,
+        name: &'static str,
+        callback: F,
+    ) -> &'a mut Self {
+        self.labels.push(name);
+
+        callback(self);
+
+        self.labels.pop();
+        self
+    }
+
+    /// Returns the names of the enclosing [labels](Mutator::labeled), from the outermost to the innermost,
+    /// separated by dots, or `"<root>"` if there are none.
+    pub fn label_path(&self) -> String {
+        label_path(&self.labels)
+    }
+
+    /// Scales how far the values within `callback` move when mutated, without changing how often they are mutated.
+    ///
+    /// The magnitude within `callback` is the current magnitude multiplied by `scale`,
+    /// and can be read with [Mutator::magnitude]; nested calls thus compose.
+    /// The magnitude is only honored by the wrappers that perform a random walk, which scale their step size by it:
+    /// [UniformCh](crate::wrapper::UniformCh), [WrappingUniformCh](crate::wrapper::WrappingUniformCh),
+    /// [LogUniformCh](crate::wrapper::LogUniformCh) and [DistCh](crate::wrapper::DistCh).
+    /// Bit flips, like the ones of the integer [Chromosome] implementations or [FixedBits](crate::wrapper::FixedBits),
+    /// ignore it.
+    #[inline(always)]
+    pub fn with_magnitude<'a, 'b, F: for<'c> FnOnce(&'c mut Self) + 'b>(
+        &'a mut self,
+        scale: f64,
+        callback: F,
+    ) -> &'a mut Self {
+        let new_magnitude = (self.magnitude * scale).max(0.0);
+        let old_magnitude = std::mem::replace(&mut self.magnitude, new_magnitude);
+
+        callback(self);
+
+        self.magnitude = old_magnitude;
+        self
+    }
+
     /// Wraps a chromosome in one of the wrapped types defined in [chromosome.rs].
     /// This is now superceded with [Mutator::with].
     #[deprecated(note = "use Mutator::with instead")]
@@ -121,7 +410,96 @@ impl<R: Rng> Mutator<R> {
     where
         W: MutationWrapper<G>,
     {
-        wrapper.mutate_with(value, self);
+        if self.visit(TraversalStep::Chromosome(wrapped_type_name::<G>())) {
+            // The wrapper may mutate several values, which are all part of the selected chromosome
+            self.within_selected(|mutator| wrapper.mutate_with(value, mutator));
+        }
+
+        self
+    }
+
+    /// Applies the same mutation wrapper to every value of `values`.
+    ///
+    /// This is the combination of [Mutator::iter] and [Mutator::with],
+    /// and each value should account for one chromosome in [Genome::size_hint].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genomic::prelude::*;
+    /// use genomic::wrapper::UniformCh;
+    ///
+    /// struct Scores {
+    ///     pub values: Vec<i32>,
+    /// }
+    ///
+    /// impl Genome for Scores {
+    ///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+    ///         mutator.iter_with(&mut UniformCh::new(0, 10), &mut self.values);
+    ///     }
+    ///
+    ///     fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+    ///         crossover.iter(&mut self.values, &mut other.values);
+    ///     }
+    ///
+    ///     fn size_hint(&self) -> usize {
+    ///         self.values.len()
+    ///     }
+    /// }
+    /// ```
+    pub fn iter_with<'a, W, G>(
+        &'a mut self,
+        wrapper: &mut W,
+        values: impl IntoIterator<Item = G>,
+    ) -> &'a mut Self
+    where
+        W: MutationWrapper<G>,
+    {
+        values.into_iter().for_each(|value| {
+            self.with(wrapper, value);
+        });
+
+        self
+    }
+
+    /// Mutates arbitrary state with a closure, which receives the mutation rate and the rng.
+    ///
+    /// This is an escape hatch for mutations that don't warrant a dedicated [MutationWrapper].
+    /// A call to this method should account for `1` chromosome in [Genome::size_hint];
+    /// keeping `size_hint` consistent with what the closure does is up to you.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genomic::prelude::*;
+    /// use genomic::Rng;
+    ///
+    /// struct Label {
+    ///     pub name: String,
+    /// }
+    ///
+    /// impl Genome for Label {
+    ///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+    ///         mutator.custom(|rate, rng| {
+    ///             if rng.gen_bool(rate) {
+    ///                 self.name.push('!');
+    ///             }
+    ///         });
+    ///     }
+    ///
+    ///     fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+    ///         crossover.chromosome(&mut self.name, &mut other.name);
+    ///     }
+    ///
+    ///     fn size_hint(&self) -> usize {
+    ///         1
+    ///     }
+    /// }
+    /// ```
+    pub fn custom<F: FnOnce(f64, &mut R)>(&mut self, callback: F) -> &mut Self {
+        if self.visit(TraversalStep::Custom) {
+            callback(self.rate, &mut self.rng);
+        }
 
         self
     }
@@ -136,7 +514,15 @@ impl<R: Rng> Mutator<R> {
         &'a mut self,
         callback: F,
     ) -> &'a mut Self {
-        callback(self);
+        if let MutatorMode::Trace(trace) = &mut self.mode {
+            trace.push(TraversalStep::GroupStart);
+            callback(self);
+            if let MutatorMode::Trace(trace) = &mut self.mode {
+                trace.push(TraversalStep::GroupEnd);
+            }
+        } else if self.visit(TraversalStep::GroupStart) {
+            self.within_selected(callback);
+        }
 
         self
     }
@@ -146,21 +532,56 @@ impl<R: Rng> Mutator<R> {
         self.rate
     }
 
+    /// Returns the mutation magnitude, which defaults to `1.0` and can be changed with [Mutator::with_magnitude].
+    ///
+    /// Wrappers performing a random walk should scale their step size by this value.
+    pub fn magnitude(&self) -> f64 {
+        self.magnitude
+    }
+
     /// Returns the rng of the mutator. For most genomes, you won't need to use this method.
     pub fn get_rng(&mut self) -> &mut R {
         &mut self.rng
     }
+
+    /// Runs `callback` with a mutator sharing the state of this one, but whose rng is a trait object,
+    /// for calling the object-safe [DynMutationWrapper](crate::wrapper::DynMutationWrapper).
+    pub(crate) fn erase_rng<T>(
+        &mut self,
+        callback: impl FnOnce(&mut Mutator<&mut dyn RngCore>) -> T,
+    ) -> T {
+        let mut erased = Mutator {
+            rate: self.rate,
+            magnitude: self.magnitude,
+            rng: &mut self.rng as &mut dyn RngCore,
+            mode: std::mem::replace(&mut self.mode, MutatorMode::Mutate),
+            labels: std::mem::take(&mut self.labels),
+        };
+
+        let result = callback(&mut erased);
+
+        let Mutator { mode, labels, .. } = erased;
+        self.mode = mode;
+        self.labels = labels;
+
+        result
+    }
 }
 
 /// A helper struct for performing the crossover operation on genomes.
 pub struct Crossover<R: Rng> {
     rng: R,
     method: CrossoverState,
+    trace: Option<Vec<TraversalStep>>,
+    effective_swaps: u64,
+    labels: Vec<&'static str>,
+    mask: Option<Vec<bool>>,
 }
 
 /// The crossover type, used for the [crate::crossover] function.
 /// This determines how chromosomes of two individuals will be mixed.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CrossoverMethod {
     /// Rolls a random number for each chromosome to determine whether it should be swapped or not.
     /// The `f64` determines the rate at which chromosomes will be swapped.
@@ -170,6 +591,30 @@ pub enum CrossoverMethod {
     /// Splits the genome in `k` points (with `k` being passed to this enum variant),
     /// and swaps all of the chromosomes in the even segments, leaving the odd segments as-is.
     KPoint(u64),
+
+    /// Splits the genome at the given positions, and swaps all of the chromosomes in the even segments,
+    /// leaving the odd segments as-is.
+    ///
+    /// A position of `n` means that the `n`-th chromosome (starting from zero) is the first chromosome of a new segment.
+    /// The positions do not need to be sorted, duplicate positions are ignored,
+    /// and so are positions greater than or equal to [Genome::size_hint].
+    FixedPoints(Vec<u64>),
+
+    /// Splits the genome in `k` points like [CrossoverMethod::KPoint], but with the positions of the points
+    /// being sampled proportionally to `weights`.
+    ///
+    /// `weights[n]` is the weight of splitting the genome right before the `n`-th chromosome (starting from zero).
+    /// If all of the weights are zero, then the points are chosen uniformly instead.
+    /// If `k` is greater than the number of positions, then every position is chosen.
+    WeightedKPoint { k: u64, weights: Vec<f64> },
+
+    /// Splits the genome in `k` points placed at evenly spaced positions, yielding segments of equal sizes
+    /// (up to rounding), and swaps all of the chromosomes in the even segments, leaving the odd segments as-is.
+    ///
+    /// The `i`-th point (starting from zero) is placed right before the chromosome at `length * (i + 1) / (k + 1)`,
+    /// where `length` is [Genome::size_hint], so the result is deterministic.
+    /// If `k` is greater than or equal to `length`, then the genome is split between every chromosome.
+    EvenKPoint(u64),
     // TODO: add more crossover operators
 }
 
@@ -182,15 +627,108 @@ pub(crate) enum CrossoverState {
         desired: u64,
     },
     Fixed(bool),
+    FixedPoints {
+        count: u64,
+        points: Vec<u64>,
+        passed: usize,
+        kind: CrossoverMethodKind,
+    },
+}
+
+/// The kind of crossover method currently used by a [Crossover], as returned by [Crossover::method].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CrossoverMethodKind {
+    /// See [CrossoverMethod::Uniform].
+    Uniform,
+    /// See [CrossoverMethod::KPoint].
+    KPoint,
+    /// See [CrossoverMethod::FixedPoints].
+    FixedPoints,
+    /// See [CrossoverMethod::WeightedKPoint].
+    WeightedKPoint,
+    /// See [CrossoverMethod::EvenKPoint].
+    EvenKPoint,
+    /// All of the chromosomes are either swapped or kept, as is the case within [Crossover::group].
+    Fixed,
 }
 
 impl<R: Rng> Crossover<R> {
     #[inline(always)]
     pub(crate) fn new(rng: R, method: CrossoverState) -> Self {
-        Self { rng, method }
+        Self {
+            rng,
+            method,
+            trace: None,
+            effective_swaps: 0,
+            labels: Vec::new(),
+            mask: None,
+        }
+    }
+
+    /// Creates a crossover helper using `method`, which owns `rng`.
+    ///
+    /// `size` should be the [Genome::size_hint] of the individuals that will be crossed over.
+    /// Most code should call [crate::crossover] instead; this is meant for code that needs to hold on to a crossover helper,
+    /// for instance to pass it to [Genome::crossover] from an application that cannot be generic over the rng.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use genomic::prelude::*;
+    /// use rand::{rngs::StdRng, RngCore, SeedableRng};
+    ///
+    /// let mut left = [0u8; 4];
+    /// let mut right = [1u8; 4];
+    ///
+    /// let rng: Box<dyn RngCore + Send> = Box::new(StdRng::seed_from_u64(0));
+    /// let mut crossover = Crossover::from_method(rng, CrossoverMethod::FixedPoints(vec![2]), left.size_hint());
+    /// left.crossover(&mut right, &mut crossover);
+    ///
+    /// assert_eq!(left, [0, 0, 1, 1]);
+    /// ```
+    pub fn from_method(mut rng: R, method: CrossoverMethod, size: usize) -> Self {
+        let method = crate::crossover_state(method, size, &mut rng);
+        Self::new(rng, method)
+    }
+
+    /// Creates a crossover helper that records the traversal of genomes instead of performing the crossover.
+    pub(crate) fn dry_run(rng: R) -> Self {
+        Self {
+            rng,
+            method: CrossoverState::Fixed(false),
+            trace: Some(Vec::new()),
+            effective_swaps: 0,
+            labels: Vec::new(),
+            mask: None,
+        }
+    }
+
+    pub(crate) fn into_trace(self) -> Vec<TraversalStep> {
+        self.trace.unwrap_or_default()
+    }
+
+    /// Makes the crossover helper record each of its swap decisions, which can then be retrieved with [Crossover::into_mask].
+    pub(crate) fn record_mask(mut self) -> Self {
+        self.mask = Some(Vec::new());
+        self
+    }
+
+    pub(crate) fn into_mask(self) -> Vec<bool> {
+        self.mask.unwrap_or_default()
     }
 
     fn should_flip(&mut self) -> bool {
+        let should_flip = self.decide();
+
+        if let Some(mask) = &mut self.mask {
+            mask.push(should_flip);
+        }
+
+        should_flip
+    }
+
+    fn decide(&mut self) -> bool {
         match self.method {
             CrossoverState::Uniform(rate) => self.rng.gen_bool(rate / 2.0),
             CrossoverState::KPoint {
@@ -199,16 +737,43 @@ impl<R: Rng> Crossover<R> {
                 ref mut swapped,
                 desired,
             } => {
-                // NOTE: I am unsure whether this method is enough to guarantee that there will be `k` splits
-                let rate = (desired - *swapped) as f64 / (length - *count) as f64;
+                // Each of the remaining chromosomes is equally likely to hold one of the remaining splits,
+                // so exactly `min(k, length)` splits happen over the `length` chromosomes of the genome
+                if *count < length {
+                    let rate = (desired - *swapped) as f64 / (length - *count) as f64;
 
-                if *count < length && self.rng.gen_bool(rate.min(1.0)) {
-                    *swapped += 1;
+                    if self.rng.gen_bool(rate.min(1.0)) {
+                        *swapped += 1;
+                    }
                 }
+                *count += 1;
 
                 *swapped % 2 == 1
             }
             CrossoverState::Fixed(res) => res,
+            CrossoverState::FixedPoints {
+                ref mut count,
+                ref points,
+                ref mut passed,
+                ..
+            } => {
+                while *passed < points.len() && points[*passed] <= *count {
+                    *passed += 1;
+                }
+                *count += 1;
+
+                *passed % 2 == 1
+            }
+        }
+    }
+
+    /// Returns the kind of crossover method in use, which crossover wrappers may use to adapt their behavior.
+    pub fn method(&self) -> CrossoverMethodKind {
+        match self.method {
+            CrossoverState::Uniform(_) => CrossoverMethodKind::Uniform,
+            CrossoverState::KPoint { .. } => CrossoverMethodKind::KPoint,
+            CrossoverState::Fixed(_) => CrossoverMethodKind::Fixed,
+            CrossoverState::FixedPoints { kind, .. } => kind,
         }
     }
 
@@ -216,57 +781,384 @@ impl<R: Rng> Crossover<R> {
     /// This is the direct equivalent of [Mutator::chromosome].
     #[inline(always)]
     pub fn chromosome<'a, Ch>(&'a mut self, ch_left: &mut Ch, ch_right: &mut Ch) -> &'a mut Self {
-        if self.should_flip() {
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraversalStep::Chromosome(std::any::type_name::<Ch>()));
+        } else if self.should_flip() {
             std::mem::swap(ch_left, ch_right);
         }
 
         self
     }
 
-    /// Instructs the helper to perform the crossover operation on a sub-genome.
+    /// Like [Crossover::chromosome], but copies `donor` into `dest` instead of swapping them.
     ///
-    /// This is the direct equivalent of [Mutator::genome].
+    /// This requires `Ch` to implement [Clone], and leaves `donor` unchanged:
+    /// it is meant for operators that only keep one child, where the swap would needlessly modify the other parent.
+    /// The copy decision is made exactly as with [Crossover::chromosome], so `dest` ends up with the same value
+    /// as the left individual after a regular crossover.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genomic::prelude::*;
+    ///
+    /// struct Child(Vec<u8>);
+    ///
+    /// impl Child {
+    ///     fn inherit(&mut self, donor: &Child, crossover: &mut Crossover<impl rand::Rng>) {
+    ///         for (dest, donor) in self.0.iter_mut().zip(donor.0.iter()) {
+    ///             crossover.chromosome_copy(dest, donor);
+    ///         }
+    ///     }
+    /// }
+    /// ```
     #[inline(always)]
-    pub fn genome<'a, G: Genome + ?Sized>(
-        &'a mut self,
-        genome_left: &mut G,
-        genome_right: &mut G,
-    ) -> &'a mut Self {
-        genome_left.crossover(genome_right, self);
+    pub fn chromosome_copy<'a, Ch: Clone>(&'a mut self, dest: &mut Ch, donor: &Ch) -> &'a mut Self {
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraversalStep::Chromosome(std::any::type_name::<Ch>()));
+        } else if self.should_flip() {
+            dest.clone_from(donor);
+        }
 
         self
     }
 
+    /// Like [Crossover::chromosome], but skips the swap if `ch_left` and `ch_right` are equal.
+    ///
+    /// The swap decision is made exactly as with [Crossover::chromosome], so both methods can be used interchangeably,
+    /// but only the swaps between differing chromosomes are performed and counted in [Crossover::effective_swaps].
+    /// This is useful for operators like HUX, which only consider the differing chromosomes of both parents.
     #[inline(always)]
-    pub fn with<'a, 'b, W, G: 'b>(
+    pub fn chromosome_if_different<'a, Ch: PartialEq>(
         &'a mut self,
-        wrapper: &mut W,
-        genome_left: G,
-        genome_right: G,
-    ) -> &'a mut Self
-    where
-        W: CrossoverWrapper<G> + 'b,
-    {
-        wrapper.crossover_with(genome_left, genome_right, self);
+        ch_left: &mut Ch,
+        ch_right: &mut Ch,
+    ) -> &'a mut Self {
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraversalStep::Chromosome(std::any::type_name::<Ch>()));
+        } else if self.should_flip() && ch_left != ch_right {
+            std::mem::swap(ch_left, ch_right);
+            self.effective_swaps += 1;
+        }
 
         self
     }
 
-    /// Instructs the helper to perform the crossover operation on a list of sub-genomes.
+    /// Tags the operations performed within `callback` with `name`, for diagnostic purposes.
     ///
-    /// This is the direct equivalent of [Mutator::iter].
+    /// This is the direct equivalent of [Mutator::labeled].
+    /// The label path (see [Crossover::label_path]) is included in the debug assertions of this helper,
+    /// like the one of [Crossover::iter_with] about values of different lengths.
+    ///
+    /// It is not included in the [size_hint](Genome::size_hint) checks of [crossover](crate::crossover)
+    /// and [reproduce](crate::reproduce): these compare the whole individuals before the traversal starts, outside of any label.
+    /// Sub-genomes aren't checked individually, since the variants of an enum may cross over with different sizes
+    /// (see [impl_genome_enum](crate::impl_genome_enum)).
+    /// Likewise, [Validate](crate::genome::Validate) is only checked on the whole individuals,
+    /// so the failures of [checked_crossover](crate::checked_crossover) don't include the label path.
     #[inline(always)]
-    pub fn iter<'a, 'b, G: Genome + ?Sized + 'b>(
+    pub fn labeled<'a, 'b, F: for<'c> FnOnce(&'c mut Self) + 'b>(
         &'a mut self,
-        genomes_left: impl IntoIterator<Item = &'b mut G>,
-        genomes_right: impl IntoIterator<Item = &'b mut G>,
+        name: &'static str,
+        callback: F,
     ) -> &'a mut Self {
-        genomes_left
-            .into_iter()
-            .zip(genomes_right.into_iter())
-            .for_each(|(item_left, item_right)| item_left.crossover(item_right, self));
+        self.labels.push(name);
 
-        self
+        callback(self);
+
+        self.labels.pop();
+        self
+    }
+
+    /// Returns the names of the enclosing [labels](Crossover::labeled), from the outermost to the innermost,
+    /// separated by dots, or `"<root>"` if there are none.
+    pub fn label_path(&self) -> String {
+        label_path(&self.labels)
+    }
+
+    /// Performs the crossover operations within `callback` using `method`, instead of the method of this helper.
+    ///
+    /// `size` should be the number of chromosomes crossed over within `callback` (the sum of their [Genome::size_hint]),
+    /// which methods like [CrossoverMethod::KPoint] need to place their points.
+    /// The state of the current method (for instance, the segment that [CrossoverMethod::KPoint] is in)
+    /// is set aside and restored once `callback` returns, so the chromosomes after this call continue where they left off.
+    /// The `size` chromosomes are still counted by the current method, so that the positions of
+    /// [CrossoverMethod::FixedPoints] keep referring to the whole genome, and so that [CrossoverMethod::KPoint]
+    /// places its points within the other chromosomes.
+    /// Within a [Crossover::group], `method` is ignored: the group's decision to swap all of its chromosomes is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genomic::prelude::*;
+    ///
+    /// struct Agent {
+    ///     pub genes: Vec<u8>,
+    ///     pub layout: Vec<u8>,
+    /// }
+    ///
+    /// impl Genome for Agent {
+    ///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+    ///         mutator.iter(&mut self.genes).iter(&mut self.layout);
+    ///     }
+    ///
+    ///     fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+    ///         // The genes are crossed over with the method passed to `genomic::crossover`,
+    ///         // while the layout keeps contiguous segments
+    ///         crossover
+    ///             .iter(&mut self.genes, &mut other.genes)
+    ///             .with_method(CrossoverMethod::KPoint(2), self.layout.len(), |crossover| {
+    ///                 crossover.iter(&mut self.layout, &mut other.layout);
+    ///             });
+    ///     }
+    ///
+    ///     fn size_hint(&self) -> usize {
+    ///         self.genes.len() + self.layout.len()
+    ///     }
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn with_method<'a, 'b, F: for<'c> FnOnce(&'c mut Self) + 'b>(
+        &'a mut self,
+        method: CrossoverMethod,
+        size: usize,
+        callback: F,
+    ) -> &'a mut Self {
+        if let CrossoverState::Fixed(_) = self.method {
+            callback(self);
+            return self;
+        }
+
+        let state = crate::crossover_state(method, size, &mut self.rng);
+        let old_state = std::mem::replace(&mut self.method, state);
+        callback(self);
+        self.method = old_state;
+
+        self.advance(size);
+
+        self
+    }
+
+    /// Returns the number of swaps between differing chromosomes performed so far by [Crossover::chromosome_if_different],
+    /// including within groups.
+    ///
+    /// The total count for a crossover can be obtained with [crate::crossover_counting_swaps].
+    pub fn effective_swaps(&self) -> u64 {
+        self.effective_swaps
+    }
+
+    /// Returns the rng of the crossover helper. For most genomes, you won't need to use this method.
+    ///
+    /// The decisions of the crossover method stay statistically independent of the values drawn from it.
+    /// Methods like [CrossoverMethod::Uniform] draw their decisions from this same rng, though,
+    /// so drawing from it changes the sequence of decisions of a seeded crossover.
+    pub fn get_rng(&mut self) -> &mut R {
+        &mut self.rng
+    }
+
+    /// Instructs the helper to perform the crossover operation on a sub-genome.
+    ///
+    /// This is the direct equivalent of [Mutator::genome].
+    #[inline(always)]
+    pub fn genome<'a, G: Genome + ?Sized>(
+        &'a mut self,
+        genome_left: &mut G,
+        genome_right: &mut G,
+    ) -> &'a mut Self {
+        genome_left.crossover(genome_right, self);
+
+        self
+    }
+
+    /// Performs the crossover operation on a sub-genome like [Crossover::genome], but only if `condition` is `true`.
+    ///
+    /// This is meant for sub-genomes that can only be recombined when both parents agree on their structure.
+    /// Otherwise, both sub-genomes are left untouched, but they still count as [Genome::size_hint] chromosomes
+    /// towards the crossover method: the chromosomes after this call are crossed over as if the sub-genome had been traversed,
+    /// so the positions of [CrossoverMethod::FixedPoints] keep referring to the whole genome,
+    /// and the skipped chromosomes are recorded as kept in the crossover mask.
+    /// The sub-genome is traversed regardless of `condition` in [dry_run_crossover](crate::dry_run_crossover).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use genomic::prelude::*;
+    ///
+    /// struct Network {
+    ///     pub biases: Vec<u8>,
+    ///     pub layers: Vec<u8>,
+    /// }
+    ///
+    /// impl Genome for Network {
+    ///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+    ///         mutator.iter(&mut self.biases).iter(&mut self.layers);
+    ///     }
+    ///
+    ///     fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+    ///         // Layers of networks with different depths can't be exchanged
+    ///         let compatible = self.layers.len() == other.layers.len();
+    ///
+    ///         crossover
+    ///             .iter(&mut self.biases, &mut other.biases)
+    ///             .genome_if(compatible, &mut self.layers, &mut other.layers);
+    ///     }
+    ///
+    ///     fn size_hint(&self) -> usize {
+    ///         self.biases.len() + self.layers.len()
+    ///     }
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn genome_if<'a, G: Genome + ?Sized>(
+        &'a mut self,
+        condition: bool,
+        genome_left: &mut G,
+        genome_right: &mut G,
+    ) -> &'a mut Self {
+        if condition || self.trace.is_some() {
+            genome_left.crossover(genome_right, self);
+        } else {
+            self.skip(genome_left.size_hint());
+        }
+
+        self
+    }
+
+    /// Advances the state of the crossover method past `size` chromosomes, without drawing any decision.
+    fn advance(&mut self, size: usize) {
+        match &mut self.method {
+            CrossoverState::KPoint { count, .. } | CrossoverState::FixedPoints { count, .. } => {
+                *count += size as u64;
+            }
+            CrossoverState::Uniform(_) | CrossoverState::Fixed(_) => {}
+        }
+    }
+
+    /// Advances the crossover method past `size` chromosomes, without swapping them.
+    fn skip(&mut self, size: usize) {
+        self.advance(size);
+
+        if let Some(mask) = &mut self.mask {
+            mask.resize(mask.len() + size, false);
+        }
+    }
+
+    #[inline(always)]
+    pub fn with<'a, 'b, W, G: 'b>(
+        &'a mut self,
+        wrapper: &mut W,
+        genome_left: G,
+        genome_right: G,
+    ) -> &'a mut Self
+    where
+        W: CrossoverWrapper<G> + 'b,
+    {
+        match &mut self.trace {
+            Some(trace) => trace.push(TraversalStep::Chromosome(wrapped_type_name::<G>())),
+            None => wrapper.crossover_with(genome_left, genome_right, self),
+        }
+
+        self
+    }
+
+    /// Applies the same crossover wrapper to every pair of values from `values_left` and `values_right`.
+    ///
+    /// This is the direct equivalent of [Mutator::iter_with].
+    /// Both iterators should have the same length; if not, the longest one is truncated
+    /// (and a debug assertion is raised).
+    pub fn iter_with<'a, W, G>(
+        &'a mut self,
+        wrapper: &mut W,
+        values_left: impl IntoIterator<Item = G>,
+        values_right: impl IntoIterator<Item = G>,
+    ) -> &'a mut Self
+    where
+        W: CrossoverWrapper<G>,
+    {
+        let mut values_left = values_left.into_iter();
+        let mut values_right = values_right.into_iter();
+
+        loop {
+            match (values_left.next(), values_right.next()) {
+                (Some(value_left), Some(value_right)) => {
+                    self.with(wrapper, value_left, value_right);
+                }
+                (left, right) => {
+                    debug_assert!(
+                        left.is_none() && right.is_none(),
+                        "Crossover::iter_with called with iterators of different lengths (in {})",
+                        self.label_path()
+                    );
+                    break;
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Performs the crossover operation on two vectors of possibly different lengths, up to `max_length` elements,
+    /// treating each element as a single chromosome.
+    ///
+    /// Both vectors are virtually padded up to `max_length` with elements returned by `neutral`,
+    /// so that they are aligned, and truncated back to their original lengths afterwards.
+    /// This means that neither vector changes length, and that the elements of the longest vector that would have
+    /// been swapped into the padding are replaced by neutral elements.
+    /// `neutral` should thus return an element that has no effect on the individual.
+    ///
+    /// Both vectors should account for `max_length` chromosomes in [Genome::size_hint], whatever their length,
+    /// so that parents of different lengths report the same size.
+    /// Neither vector should be longer than `max_length`: the elements past it are left untouched
+    /// (and a debug assertion is raised).
+    pub fn iter_padded<'a, T>(
+        &'a mut self,
+        values_left: &mut Vec<T>,
+        values_right: &mut Vec<T>,
+        max_length: usize,
+        mut neutral: impl FnMut() -> T,
+    ) -> &'a mut Self {
+        let (length_left, length_right) = (values_left.len(), values_right.len());
+        debug_assert!(
+            length_left <= max_length && length_right <= max_length,
+            "Crossover::iter_padded called with vectors longer than max_length (in {})",
+            self.label_path()
+        );
+
+        for values in [&mut *values_left, &mut *values_right] {
+            if values.len() < max_length {
+                values.resize_with(max_length, &mut neutral);
+            }
+        }
+
+        for (value_left, value_right) in values_left
+            .iter_mut()
+            .zip(values_right.iter_mut())
+            .take(max_length)
+        {
+            self.chromosome(value_left, value_right);
+        }
+
+        values_left.truncate(length_left);
+        values_right.truncate(length_right);
+
+        self
+    }
+
+    /// Instructs the helper to perform the crossover operation on a list of sub-genomes.
+    ///
+    /// This is the direct equivalent of [Mutator::iter].
+    #[inline(always)]
+    pub fn iter<'a, 'b, G: Genome + ?Sized + 'b>(
+        &'a mut self,
+        genomes_left: impl IntoIterator<Item = &'b mut G>,
+        genomes_right: impl IntoIterator<Item = &'b mut G>,
+    ) -> &'a mut Self {
+        genomes_left
+            .into_iter()
+            .zip(genomes_right)
+            .for_each(|(item_left, item_right)| item_left.crossover(item_right, self));
+
+        self
     }
 
     /// Groups together multiple operations as if it was a single chromosome.
@@ -276,19 +1168,117 @@ impl<R: Rng> Crossover<R> {
     ///
     /// As with [Mutator::group], a call to this method should account for exactly `1` chromosome in [Genome::size_hint],
     /// no matter how many operations are performed in the callback.
-    pub fn group<'a, 'b, F: for<'c> FnOnce(&'c mut Crossover<&'c mut R>) + 'b>(
+    pub fn group<'a, 'b, F: for<'c, 'd> FnOnce(&'c mut Crossover<&'d mut R>) + 'b>(
         &'a mut self,
         callback: F,
     ) -> &'a mut Self {
-        let should_flip = self.should_flip();
+        let should_flip = self.trace.is_none() && self.should_flip();
 
         let mut fixed = Crossover {
             rng: &mut self.rng,
             method: CrossoverState::Fixed(should_flip),
+            trace: self.trace.take(),
+            effective_swaps: 0,
+            labels: std::mem::take(&mut self.labels),
+            mask: None,
         };
 
+        if let Some(trace) = &mut fixed.trace {
+            trace.push(TraversalStep::GroupStart);
+        }
+
         callback(&mut fixed);
 
+        self.trace = fixed.trace.take();
+        self.labels = std::mem::take(&mut fixed.labels);
+        self.effective_swaps += fixed.effective_swaps;
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraversalStep::GroupEnd);
+        }
+
+        self
+    }
+
+    /// Runs `callback` with a crossover helper sharing the state of this one, but whose rng is a trait object,
+    /// for calling the object-safe [DynCrossoverWrapper](crate::wrapper::DynCrossoverWrapper).
+    pub(crate) fn erase_rng<T>(
+        &mut self,
+        callback: impl FnOnce(&mut Crossover<&mut dyn RngCore>) -> T,
+    ) -> T {
+        let mut erased = Crossover {
+            rng: &mut self.rng as &mut dyn RngCore,
+            method: std::mem::replace(&mut self.method, CrossoverState::Fixed(false)),
+            trace: self.trace.take(),
+            effective_swaps: 0,
+            labels: std::mem::take(&mut self.labels),
+            mask: self.mask.take(),
+        };
+
+        let result = callback(&mut erased);
+
+        let Crossover {
+            method,
+            trace,
+            effective_swaps,
+            labels,
+            mask,
+            ..
+        } = erased;
+        self.method = method;
+        self.trace = trace;
+        self.effective_swaps += effective_swaps;
+        self.labels = labels;
+        self.mask = mask;
+
+        result
+    }
+
+    /// Performs an arbitrary crossover operation with a closure, which receives the swap decision and the rng.
+    ///
+    /// The swap decision is made once, according to the current crossover method,
+    /// exactly as if [Crossover::chromosome] had been called.
+    /// This is an escape hatch for crossover operations that don't warrant a dedicated [CrossoverWrapper],
+    /// like value-producing operators (averaging, blending, etc.).
+    ///
+    /// A call to this method should account for `1` chromosome in [Genome::size_hint].
+    pub fn custom<F: FnOnce(bool, &mut R)>(&mut self, callback: F) -> &mut Self {
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraversalStep::Custom);
+            return self;
+        }
+
+        let should_flip = self.should_flip();
+
+        callback(should_flip, &mut self.rng);
+
+        self
+    }
+
+    /// Lets a group of operations take part in the crossover only some of the time.
+    ///
+    /// A single random roll decides whether the callback is called: with a probability of `fraction`,
+    /// the operations within the callback are performed as usual, and otherwise they are skipped entirely,
+    /// leaving the enclosed chromosomes of both individuals intact.
+    /// This differs from [Crossover::group], which always performs the enclosed operations with a shared swap decision.
+    ///
+    /// `size` should be the number of chromosomes crossed over within `callback`, which should also be counted
+    /// in [Genome::size_hint] as usual.
+    /// When the group is skipped, its `size` chromosomes still count towards the crossover method, as with [Crossover::genome_if]:
+    /// the positions of [CrossoverMethod::FixedPoints] keep referring to the whole genome,
+    /// and [CrossoverMethod::KPoint] places its points within the other chromosomes.
+    /// During a dry run, the callback is always called.
+    pub fn subset<'a, 'b, F: for<'c> FnOnce(&'c mut Self) + 'b>(
+        &'a mut self,
+        fraction: f64,
+        size: usize,
+        callback: F,
+    ) -> &'a mut Self {
+        if self.trace.is_some() || self.rng.gen_bool(fraction.clamp(0.0, 1.0)) {
+            callback(self);
+        } else {
+            self.skip(size);
+        }
+
         self
     }
 }
@@ -297,6 +1287,8 @@ impl<R: Rng> Crossover<R> {
 mod test {
     use std::collections::HashSet;
 
+    use rand::{rngs::StdRng, SeedableRng};
+
     use crate::wrapper::UniformCh;
 
     use super::*;
@@ -321,7 +1313,7 @@ mod test {
 
         let mut instance = MyStruct(0);
 
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::test_rng();
         let mut values = HashSet::new();
         for _ in 0..100 {
             crate::mutate(&mut instance, 1.0, &mut rng);
@@ -333,54 +1325,1012 @@ mod test {
     }
 
     #[test]
-    fn test_group() {
-        struct MyStruct(Vec<i32>);
+    fn test_iter_with() {
+        struct Scores(Vec<i32>);
 
-        impl Genome for MyStruct {
+        impl Genome for Scores {
             fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
-                mutator
-                    .group(|m| {
-                        m.genome(&mut self.0[0..2]);
-                    })
-                    .group(|m| {
-                        m.genome(&mut self.0[2..4]);
-                    });
+                mutator.iter_with(&mut UniformCh::new(0, 10), &mut self.0);
             }
 
             fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
-                crossover
-                    .group(|c| {
-                        c.genome(&mut self.0[0..2], &mut other.0[0..2]);
-                    })
-                    .group(|c| {
-                        c.genome(&mut self.0[2..4], &mut other.0[2..4]);
-                    });
+                crossover.iter(&mut self.0, &mut other.0);
             }
 
             fn size_hint(&self) -> usize {
-                2
+                self.0.len()
             }
         }
 
-        fn test_with_method(method: CrossoverMethod) {
-            let mut rng = rand::thread_rng();
+        let mut instance = Scores(vec![-5, 0, 5, 100]);
+
+        let mut rng = crate::test_rng();
+        let mut values = HashSet::new();
+        for _ in 0..100 {
+            crate::mutate(&mut instance, 1.0, &mut rng);
+            for &value in instance.0.iter() {
+                assert!((0..=10).contains(&value));
+                values.insert(value);
+            }
+        }
+        assert_eq!(values.len(), 11);
+    }
+
+    #[test]
+    fn test_crossover_iter_with() {
+        struct Intermediate;
+
+        impl CrossoverWrapper<&mut f64> for Intermediate {
+            fn crossover_with(
+                &mut self,
+                value_left: &mut f64,
+                value_right: &mut f64,
+                _crossover: &mut Crossover<impl Rng>,
+            ) {
+                let mean = (*value_left + *value_right) / 2.0;
+                *value_left = mean;
+                *value_right = mean;
+            }
+        }
+
+        struct Weights(Vec<f64>);
+
+        impl Genome for Weights {
+            fn mutate(&mut self, _mutator: &mut Mutator<impl Rng>) {
+                unimplemented!()
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                crossover.iter_with(&mut Intermediate, &mut self.0, &mut other.0);
+            }
+
+            fn size_hint(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        let mut instance_a = Weights(vec![0.0, 1.0, 2.0]);
+        let mut instance_b = Weights(vec![2.0, 3.0, -2.0]);
+
+        crate::crossover(
+            &mut instance_a,
+            &mut instance_b,
+            CrossoverMethod::Uniform(0.5),
+            &mut crate::test_rng(),
+        );
+
+        assert_eq!(instance_a.0, vec![1.0, 2.0, 0.0]);
+        assert_eq!(instance_b.0, vec![1.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_iter_padded() {
+        const MAX_LENGTH: usize = 8;
+
+        struct Growing(Vec<f64>);
+
+        impl Genome for Growing {
+            fn mutate(&mut self, _mutator: &mut Mutator<impl Rng>) {
+                unimplemented!()
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                crossover.iter_padded(&mut self.0, &mut other.0, MAX_LENGTH, || 0.0);
+            }
+
+            fn size_hint(&self) -> usize {
+                MAX_LENGTH
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        for method in [CrossoverMethod::Uniform(1.0), CrossoverMethod::KPoint(2)] {
             for _ in 0..100 {
-                let mut instance_a = MyStruct(vec![0, 1, 2, 3]);
-                let mut instance_b = MyStruct(vec![4, 5, 6, 7]);
+                let mut instance_a = Growing(vec![1.0, 2.0, 3.0]);
+                let mut instance_b = Growing(vec![-1.0, -2.0, -3.0, -4.0, -5.0]);
 
-                crate::crossover(&mut instance_a, &mut instance_b, method, &mut rng);
+                crate::crossover(&mut instance_a, &mut instance_b, method.clone(), &mut rng);
 
-                assert_eq!(instance_a.0[1], instance_a.0[0] + 1);
-                assert_eq!(instance_b.0[1], instance_b.0[0] + 1);
-                assert_eq!(instance_a.0[3], instance_a.0[2] + 1);
-                assert_eq!(instance_b.0[3], instance_b.0[2] + 1);
-                for i in 0..4 {
-                    assert_ne!(instance_a.0[i], instance_b.0[i]);
+                assert_eq!(instance_a.0.len(), 3);
+                assert_eq!(instance_b.0.len(), 5);
+                for i in 0..3 {
+                    assert_eq!(instance_a.0[i].abs(), (i + 1) as f64);
+                    assert_eq!(instance_b.0[i].abs(), (i + 1) as f64);
+                }
+                for i in 3..5 {
+                    assert!(instance_b.0[i] == 0.0 || instance_b.0[i] == -((i + 1) as f64));
                 }
             }
         }
 
-        test_with_method(CrossoverMethod::Uniform(0.5));
+        // Every padded element takes a decision, so the positions of the points refer to `MAX_LENGTH` elements
+        let mut instance_a = Growing(vec![1.0, 2.0, 3.0]);
+        let mut instance_b = Growing(vec![-1.0, -2.0, -3.0, -4.0, -5.0]);
+        let mask = crate::crossover_with_mask(
+            &mut instance_a,
+            &mut instance_b,
+            CrossoverMethod::FixedPoints(vec![2, 4]),
+            &mut rng,
+        );
+        assert_eq!(mask.len(), MAX_LENGTH);
+        assert_eq!(instance_a.0, [1.0, 2.0, -3.0]);
+        assert_eq!(instance_b.0, [-1.0, -2.0, 3.0, 0.0, -5.0]);
+    }
+
+    #[test]
+    fn test_method() {
+        struct Kinds(Vec<CrossoverMethodKind>);
+
+        impl Genome for Kinds {
+            fn mutate(&mut self, _mutator: &mut Mutator<impl Rng>) {
+                unimplemented!()
+            }
+
+            fn crossover(&mut self, _other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                self.0.push(crossover.method());
+                crossover.group(|c| self.0.push(c.method()));
+            }
+
+            fn size_hint(&self) -> usize {
+                1
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        for (method, kind) in [
+            (CrossoverMethod::Uniform(0.5), CrossoverMethodKind::Uniform),
+            (CrossoverMethod::KPoint(1), CrossoverMethodKind::KPoint),
+            (
+                CrossoverMethod::FixedPoints(vec![0]),
+                CrossoverMethodKind::FixedPoints,
+            ),
+            (
+                CrossoverMethod::WeightedKPoint {
+                    k: 1,
+                    weights: vec![1.0],
+                },
+                CrossoverMethodKind::WeightedKPoint,
+            ),
+        ] {
+            let mut instance_a = Kinds(Vec::new());
+            let mut instance_b = Kinds(Vec::new());
+
+            crate::crossover(&mut instance_a, &mut instance_b, method, &mut rng);
+
+            assert_eq!(instance_a.0, vec![kind, CrossoverMethodKind::Fixed]);
+        }
+    }
+
+    #[test]
+    fn test_chromosome_ctx() {
+        struct StepSize {
+            step: f64,
+            uses: usize,
+        }
+
+        impl ContextualChromosome<StepSize> for f64 {
+            fn mutate_ctx(&mut self, rate: f64, ctx: &mut StepSize, rng: &mut impl Rng) {
+                ctx.uses += 1;
+                *self += rate * ctx.step * rng.gen_range(-1.0..1.0);
+            }
+        }
+
+        struct Point {
+            coordinates: [f64; 4],
+            step: StepSize,
+        }
+
+        impl Genome for Point {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                for coordinate in self.coordinates.iter_mut() {
+                    mutator.chromosome_ctx(coordinate, &mut self.step);
+                }
+            }
+
+            fn crossover(&mut self, _other: &mut Self, _crossover: &mut Crossover<impl Rng>) {
+                unimplemented!()
+            }
+
+            fn size_hint(&self) -> usize {
+                self.coordinates.len()
+            }
+        }
+
+        // With the same randomness, every gene moves three times further with a step three times larger
+        let mut deltas = Vec::new();
+        for step in [1.0, 3.0] {
+            let mut point = Point {
+                coordinates: [0.0; 4],
+                step: StepSize { step, uses: 0 },
+            };
+            crate::mutate(&mut point, 0.5, &mut StdRng::seed_from_u64(1450));
+
+            assert_eq!(point.step.uses, 4);
+            assert!(point.coordinates.iter().all(|&x| x != 0.0));
+            deltas.push(point.coordinates);
+        }
+        for (small, large) in deltas[0].iter().zip(deltas[1]) {
+            assert!((large - 3.0 * small).abs() < 1e-12);
+        }
+
+        let mut point = Point {
+            coordinates: [0.0; 4],
+            step: StepSize { step: 1.0, uses: 0 },
+        };
+        assert_eq!(crate::dry_run_mutate(&mut point).len(), 4);
+        crate::mutate_n_genes(&mut point, 1, &mut crate::test_rng());
+        assert_eq!(point.step.uses, 1);
+    }
+
+    #[test]
+    fn test_custom() {
+        struct Counter {
+            value: u32,
+            rates: Vec<f64>,
+        }
+
+        impl Genome for Counter {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                mutator.custom(|rate, rng| {
+                    self.rates.push(rate);
+                    self.value += rng.gen_range(1..=3);
+                });
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                crossover.chromosome(&mut self.value, &mut other.value);
+            }
+
+            fn size_hint(&self) -> usize {
+                1
+            }
+        }
+
+        let mut instance = Counter {
+            value: 0,
+            rates: Vec::new(),
+        };
+
+        let mut rng = crate::test_rng();
+        for _ in 0..10 {
+            crate::mutate(&mut instance, 0.25, &mut rng);
+        }
+
+        assert!((10..=30).contains(&instance.value));
+        assert_eq!(instance.rates, vec![0.25; 10]);
+    }
+
+    #[test]
+    fn test_crossover_custom() {
+        struct Average {
+            value: f64,
+            flips: Vec<bool>,
+        }
+
+        impl Genome for Average {
+            fn mutate(&mut self, _mutator: &mut Mutator<impl Rng>) {
+                unimplemented!()
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                crossover.custom(|should_flip, _rng| {
+                    let mean = (self.value + other.value) / 2.0;
+                    self.value = mean;
+                    other.value = mean;
+                    self.flips.push(should_flip);
+                });
+            }
+
+            fn size_hint(&self) -> usize {
+                1
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        for (method, flip) in [
+            (CrossoverMethod::FixedPoints(vec![0]), true),
+            (CrossoverMethod::FixedPoints(vec![]), false),
+        ] {
+            let mut instance_a = Average {
+                value: 0.0,
+                flips: Vec::new(),
+            };
+            let mut instance_b = Average {
+                value: 3.0,
+                flips: Vec::new(),
+            };
+
+            crate::crossover(&mut instance_a, &mut instance_b, method, &mut rng);
+
+            assert_eq!(instance_a.value, 1.5);
+            assert_eq!(instance_b.value, 1.5);
+            assert_eq!(instance_a.flips, vec![flip]);
+        }
+    }
+
+    #[test]
+    fn test_group() {
+        struct MyStruct(Vec<i32>);
+
+        impl Genome for MyStruct {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                mutator
+                    .group(|m| {
+                        m.genome(&mut self.0[0..2]);
+                    })
+                    .group(|m| {
+                        m.genome(&mut self.0[2..4]);
+                    });
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                crossover
+                    .group(|c| {
+                        c.genome(&mut self.0[0..2], &mut other.0[0..2]);
+                    })
+                    .group(|c| {
+                        c.genome(&mut self.0[2..4], &mut other.0[2..4]);
+                    });
+            }
+
+            fn size_hint(&self) -> usize {
+                2
+            }
+        }
+
+        fn test_with_method(method: CrossoverMethod) {
+            let mut rng = crate::test_rng();
+            for _ in 0..100 {
+                let mut instance_a = MyStruct(vec![0, 1, 2, 3]);
+                let mut instance_b = MyStruct(vec![4, 5, 6, 7]);
+
+                crate::crossover(&mut instance_a, &mut instance_b, method.clone(), &mut rng);
+
+                assert_eq!(instance_a.0[1], instance_a.0[0] + 1);
+                assert_eq!(instance_b.0[1], instance_b.0[0] + 1);
+                assert_eq!(instance_a.0[3], instance_a.0[2] + 1);
+                assert_eq!(instance_b.0[3], instance_b.0[2] + 1);
+                for i in 0..4 {
+                    assert_ne!(instance_a.0[i], instance_b.0[i]);
+                }
+            }
+        }
+
+        test_with_method(CrossoverMethod::Uniform(0.5));
         test_with_method(CrossoverMethod::KPoint(1));
+        test_with_method(CrossoverMethod::FixedPoints(vec![1]));
+    }
+
+    #[test]
+    fn test_fixed_points() {
+        let mut rng = crate::test_rng();
+        for _ in 0..10 {
+            let mut instance_a = vec![0u32; 8];
+            let mut instance_b = vec![1u32; 8];
+
+            crate::crossover(
+                &mut instance_a,
+                &mut instance_b,
+                CrossoverMethod::FixedPoints(vec![6, 2, 4, 2, 20]),
+                &mut rng,
+            );
+
+            assert_eq!(instance_a, vec![0, 0, 1, 1, 0, 0, 1, 1]);
+            assert_eq!(instance_b, vec![1, 1, 0, 0, 1, 1, 0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_subset() {
+        struct Modules {
+            parts: Vec<u32>,
+            recombined: usize,
+        }
+
+        impl Genome for Modules {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                mutator.iter(&mut self.parts);
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                for (left, right) in self.parts.iter_mut().zip(other.parts.iter_mut()) {
+                    crossover.subset(0.25, 1, |c| {
+                        self.recombined += 1;
+                        c.chromosome(left, right);
+                    });
+                }
+            }
+
+            fn size_hint(&self) -> usize {
+                self.parts.len()
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        let mut recombined = 0;
+        let mut swapped = 0;
+        for _ in 0..100 {
+            let mut instance_a = Modules {
+                parts: vec![0; 100],
+                recombined: 0,
+            };
+            let mut instance_b = Modules {
+                parts: vec![1; 100],
+                recombined: 0,
+            };
+
+            crate::crossover(
+                &mut instance_a,
+                &mut instance_b,
+                CrossoverMethod::FixedPoints(vec![0]),
+                &mut rng,
+            );
+
+            recombined += instance_a.recombined;
+            swapped += instance_a.parts.iter().filter(|&&part| part == 1).count();
+        }
+
+        assert!((2000..3000).contains(&recombined));
+        assert_eq!(swapped, recombined);
+
+        // A skipped subset still counts towards the crossover method
+        struct Skipped([u8; 12]);
+
+        impl Genome for Skipped {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                mutator.genome(&mut self.0);
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                let (left_head, left_rest) = self.0.split_at_mut(4);
+                let (left_middle, left_tail) = left_rest.split_at_mut(4);
+                let (right_head, right_rest) = other.0.split_at_mut(4);
+                let (right_middle, right_tail) = right_rest.split_at_mut(4);
+
+                crossover
+                    .genome(left_head, right_head)
+                    .subset(0.0, 4, |c| {
+                        c.genome(left_middle, right_middle);
+                    })
+                    .genome(left_tail, right_tail);
+            }
+
+            fn size_hint(&self) -> usize {
+                12
+            }
+        }
+
+        let mask = crate::crossover_with_mask(
+            &mut Skipped([0; 12]),
+            &mut Skipped([1; 12]),
+            CrossoverMethod::FixedPoints(vec![2, 10]),
+            &mut rng,
+        );
+        assert_eq!(
+            mask,
+            [false, false, true, true, false, false, false, false, true, true, false, false]
+        );
+
+        for _ in 0..20 {
+            let mask = crate::crossover_with_mask(
+                &mut Skipped([0; 12]),
+                &mut Skipped([1; 12]),
+                CrossoverMethod::KPoint(3),
+                &mut rng,
+            );
+            assert_eq!(mask.len(), 12);
+            assert!(mask[4..8].iter().all(|&swapped| !swapped));
+
+            let decided = mask[..4].iter().chain(&mask[8..]).collect::<Vec<_>>();
+            let switches = std::iter::once(&&false)
+                .chain(&decided)
+                .zip(&decided)
+                .filter(|(previous, current)| previous != current)
+                .count();
+            assert_eq!(switches, 3, "{mask:?}");
+        }
+    }
+
+    #[test]
+    fn test_chromosome_if_different() {
+        struct Bits(Vec<bool>);
+
+        impl Genome for Bits {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                mutator.iter(&mut self.0);
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                for (left, right) in self.0.iter_mut().zip(other.0.iter_mut()) {
+                    crossover.chromosome_if_different(left, right);
+                }
+            }
+
+            fn size_hint(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        let mut left = Bits(vec![true, false, true, false]);
+        let mut right = Bits(vec![true, false, true, false]);
+        for _ in 0..10 {
+            let swaps = crate::crossover_counting_swaps(
+                &mut left,
+                &mut right,
+                CrossoverMethod::FixedPoints(vec![0]),
+                &mut rng,
+            );
+            assert_eq!(swaps, 0);
+            assert_eq!(left.0, vec![true, false, true, false]);
+            assert_eq!(right.0, vec![true, false, true, false]);
+        }
+
+        let mut left = Bits(vec![true, true, false, false]);
+        let mut right = Bits(vec![true, false, false, true]);
+        let swaps = crate::crossover_counting_swaps(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![1, 3]),
+            &mut rng,
+        );
+        assert_eq!(swaps, 1);
+        assert_eq!(left.0, vec![true, false, false, false]);
+        assert_eq!(right.0, vec![true, true, false, true]);
+
+        // Behaves like `chromosome` given the same seed
+        let (mut seeded, mut reference) =
+            (StdRng::seed_from_u64(1409), StdRng::seed_from_u64(1409));
+        let mut left = Bits((0..32).map(|i| i % 3 == 0).collect());
+        let mut right = Bits((0..32).map(|i| i % 5 == 0).collect());
+        let (mut expected_left, mut expected_right) = (left.0.clone(), right.0.clone());
+
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::Uniform(1.0),
+            &mut seeded,
+        );
+        crate::crossover(
+            &mut expected_left,
+            &mut expected_right,
+            CrossoverMethod::Uniform(1.0),
+            &mut reference,
+        );
+        assert_eq!(left.0, expected_left);
+        assert_eq!(right.0, expected_right);
+    }
+
+    #[test]
+    fn test_with_magnitude() {
+        let mut rng = StdRng::seed_from_u64(1414);
+        let mut wrapper = UniformCh::new(-100.0f64, 100.0);
+
+        let mut steps = [0.0, 0.0];
+        let mut flips = [0, 0];
+        for (index, scale) in [1.0, 2.0].into_iter().enumerate() {
+            for _ in 0..10000 {
+                let mut value = 0.0f64;
+                let mut bits = 0u32;
+                Mutator::new(0.1, &mut rng).with_magnitude(scale, |mutator| {
+                    assert_eq!(mutator.magnitude(), scale);
+                    mutator.with(&mut wrapper, &mut value).chromosome(&mut bits);
+                });
+                steps[index] += value.abs();
+                flips[index] += bits.count_ones();
+            }
+        }
+
+        let step_ratio = steps[1] / steps[0];
+        assert!((1.8..2.2).contains(&step_ratio), "{step_ratio}");
+        let flip_ratio = flips[1] as f64 / flips[0] as f64;
+        assert!((0.9..1.1).contains(&flip_ratio), "{flip_ratio}");
+    }
+
+    #[test]
+    fn test_iter_rated() {
+        let mut rng = crate::test_rng();
+        let mut changed = [false; 4];
+
+        for _ in 0..100 {
+            let mut items = [0u32; 4];
+            // The last element has no rate, and uses a multiplier of `1.0`
+            Mutator::new(0.5, &mut rng).iter_rated(&mut items, &[0.0, 1.0, 4.0]);
+
+            assert_eq!(items[0], 0);
+            for (changed, item) in changed.iter_mut().zip(items) {
+                *changed |= item != 0;
+            }
+        }
+
+        assert_eq!(changed, [false, true, true, true]);
+    }
+
+    #[test]
+    fn test_chromosome_copy() {
+        let mut rng = crate::test_rng();
+
+        let donor = vec![1u8, 2, 3];
+        let mut dest = vec![4u8, 5];
+        Crossover::new(&mut rng, CrossoverState::Fixed(false)).chromosome_copy(&mut dest, &donor);
+        assert_eq!(dest, vec![4, 5]);
+
+        Crossover::new(&mut rng, CrossoverState::Fixed(true)).chromosome_copy(&mut dest, &donor);
+        assert_eq!(dest, vec![1, 2, 3]);
+        assert_eq!(donor, vec![1, 2, 3]);
+
+        // Picks the same chromosomes as `chromosome` given the same seed
+        let (mut seeded, mut reference) =
+            (StdRng::seed_from_u64(1458), StdRng::seed_from_u64(1458));
+        let donor = [1u8; 32];
+        let mut dest = [0u8; 32];
+        let (mut expected, mut other) = ([0u8; 32], [1u8; 32]);
+
+        let mut crossover = Crossover::new(&mut seeded, CrossoverState::Uniform(1.0));
+        for (dest, donor) in dest.iter_mut().zip(donor.iter()) {
+            crossover.chromosome_copy(dest, donor);
+        }
+        let mut crossover = Crossover::new(&mut reference, CrossoverState::Uniform(1.0));
+        for (left, right) in expected.iter_mut().zip(other.iter_mut()) {
+            crossover.chromosome(left, right);
+        }
+
+        assert_eq!(dest, expected);
+        assert_ne!(dest, donor);
+        assert_eq!(donor, [1u8; 32]);
+    }
+
+    #[test]
+    fn test_with_method() {
+        struct Split {
+            uniform: Vec<u8>,
+            segments: Vec<u8>,
+            after: Vec<u8>,
+        }
+
+        impl Genome for Split {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                mutator
+                    .iter(&mut self.uniform)
+                    .iter(&mut self.segments)
+                    .iter(&mut self.after);
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                crossover
+                    .iter(&mut self.uniform, &mut other.uniform)
+                    .with_method(
+                        CrossoverMethod::KPoint(1),
+                        self.segments.len(),
+                        |crossover| {
+                            crossover.iter(&mut self.segments, &mut other.segments);
+                        },
+                    )
+                    .iter(&mut self.after, &mut other.after);
+            }
+
+            fn size_hint(&self) -> usize {
+                self.uniform.len() + self.segments.len() + self.after.len()
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        let new = || Split {
+            uniform: vec![0; 32],
+            segments: vec![0; 32],
+            after: vec![0; 32],
+        };
+
+        let mut segment_splits = 0;
+        for _ in 0..20 {
+            let mask = crate::crossover_with_mask(
+                &mut new(),
+                &mut new(),
+                CrossoverMethod::Uniform(1.0),
+                &mut rng,
+            );
+            let (uniform, rest) = mask.split_at(32);
+            let (segments, after) = rest.split_at(32);
+
+            // A single point yields at most one change between swapped and kept chromosomes
+            let changes = |mask: &[bool]| mask.windows(2).filter(|pair| pair[0] != pair[1]).count();
+            assert!(changes(segments) <= 1, "{segments:?}");
+            segment_splits += changes(segments);
+
+            // The uniform crossover resumes after the call
+            assert!(changes(uniform) > 4, "{uniform:?}");
+            assert!(changes(after) > 4, "{after:?}");
+        }
+        assert!(segment_splits > 0);
+
+        // The state of the enclosing crossover is restored, and still counts the chromosomes within the call
+        let mask = crate::crossover_with_mask(
+            &mut new(),
+            &mut new(),
+            CrossoverMethod::FixedPoints(vec![16, 80]),
+            &mut rng,
+        );
+        assert!(mask[..16].iter().all(|&swapped| !swapped));
+        assert!(mask[16..32].iter().all(|&swapped| swapped));
+        assert!(mask[64..80].iter().all(|&swapped| swapped));
+        assert!(mask[80..].iter().all(|&swapped| !swapped));
+
+        // KPoint keeps placing its points in the chromosomes outside of the call
+        for _ in 0..20 {
+            let mask = crate::crossover_with_mask(
+                &mut new(),
+                &mut new(),
+                CrossoverMethod::KPoint(2),
+                &mut rng,
+            );
+            let outside = mask[..32].iter().chain(&mask[64..]).collect::<Vec<_>>();
+            let switches = std::iter::once(&&false)
+                .chain(&outside)
+                .zip(&outside)
+                .filter(|(previous, current)| previous != current)
+                .count();
+            assert_eq!(switches, 2, "{mask:?}");
+        }
+
+        // Within a group, the group's decision is kept
+        struct Grouped(Split);
+
+        impl Genome for Grouped {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                mutator.group(|mutator| {
+                    mutator.genome(&mut self.0);
+                });
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                crossover.group(|crossover| {
+                    crossover.genome(&mut self.0, &mut other.0);
+                });
+            }
+
+            fn size_hint(&self) -> usize {
+                1
+            }
+        }
+
+        let mut swaps = 0;
+        for _ in 0..40 {
+            let (mut left, mut right) = (Grouped(new()), Grouped(new()));
+            right.0.segments = vec![1; 32];
+
+            crate::crossover(
+                &mut left,
+                &mut right,
+                CrossoverMethod::Uniform(1.0),
+                &mut rng,
+            );
+            assert!(left.0.segments == [0; 32] || left.0.segments == [1; 32]);
+            swaps += usize::from(left.0.segments[0] == 1);
+        }
+        assert!(swaps > 0 && swaps < 40);
+    }
+
+    #[test]
+    fn test_genome_if() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Optional {
+            head: [u8; 4],
+            optional: [u8; 4],
+            tail: [u8; 4],
+            enabled: bool,
+        }
+
+        impl Genome for Optional {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                mutator
+                    .genome(&mut self.head)
+                    .genome(&mut self.optional)
+                    .genome(&mut self.tail);
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                crossover
+                    .genome(&mut self.head, &mut other.head)
+                    .genome_if(
+                        self.enabled && other.enabled,
+                        &mut self.optional,
+                        &mut other.optional,
+                    )
+                    .genome(&mut self.tail, &mut other.tail);
+            }
+
+            fn size_hint(&self) -> usize {
+                12
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        let new = |value: u8, enabled: bool| Optional {
+            head: [value; 4],
+            optional: [value; 4],
+            tail: [value; 4],
+            enabled,
+        };
+
+        // Every chromosome is swapped, except for the disabled sub-genome
+        let (mut left, mut right) = (new(0, true), new(1, false));
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![0]),
+            &mut rng,
+        );
+        assert_eq!((left.head, right.head), ([1; 4], [0; 4]));
+        assert_eq!((left.optional, right.optional), ([0; 4], [1; 4]));
+        assert_eq!((left.tail, right.tail), ([1; 4], [0; 4]));
+
+        let (mut left, mut right) = (new(0, true), new(1, true));
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![0]),
+            &mut rng,
+        );
+        assert_eq!((left.optional, right.optional), ([1; 4], [0; 4]));
+
+        // The skipped chromosomes still count towards the positions of the points
+        let (mut left, mut right) = (new(0, false), new(1, false));
+        let mask = crate::crossover_with_mask(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![2, 10]),
+            &mut rng,
+        );
+        assert_eq!(mask.len(), 12);
+        assert_eq!(
+            mask[..8],
+            [false, false, true, true, false, false, false, false]
+        );
+        assert_eq!(mask[8..], [true, true, false, false]);
+        assert_eq!(left.tail, [1, 1, 0, 0]);
+        assert_eq!(left.optional, [0; 4]);
+
+        // KPoint places all of its points within the chromosomes that aren't skipped
+        for _ in 0..20 {
+            let (mut left, mut right) = (new(0, false), new(1, false));
+            let mask = crate::crossover_with_mask(
+                &mut left,
+                &mut right,
+                CrossoverMethod::KPoint(3),
+                &mut rng,
+            );
+            assert!(mask[4..8].iter().all(|&swapped| !swapped));
+
+            let decided = mask[..4].iter().chain(&mask[8..]).collect::<Vec<_>>();
+            let switches = std::iter::once(&&false)
+                .chain(&decided)
+                .zip(&decided)
+                .filter(|(previous, current)| previous != current)
+                .count();
+            assert_eq!(switches, 3, "{mask:?}");
+        }
+
+        // The dry run traverses the sub-genome anyway
+        let (mut left, mut right) = (new(0, false), new(1, false));
+        assert_eq!(
+            crate::dry_run_crossover(&mut left, &mut right),
+            crate::dry_run_mutate(&mut left)
+        );
+    }
+
+    #[test]
+    fn test_labeled() {
+        let mut rng = crate::test_rng();
+
+        let mut mutator = Mutator::new(0.5, &mut rng);
+        assert_eq!(mutator.label_path(), "<root>");
+        mutator.labeled("brain", |mutator| {
+            mutator.labeled("weights", |mutator| {
+                assert_eq!(mutator.label_path(), "brain.weights");
+            });
+            assert_eq!(mutator.label_path(), "brain");
+        });
+        assert_eq!(mutator.label_path(), "<root>");
+
+        let mut crossover = Crossover::new(&mut rng, CrossoverState::Uniform(1.0));
+        crossover.labeled("brain", |crossover| {
+            crossover.group(|crossover| assert_eq!(crossover.label_path(), "brain"));
+        });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "(in brain.weights)")]
+    fn test_labeled_diagnostic() {
+        struct Intermediate;
+
+        impl CrossoverWrapper<&mut f64> for Intermediate {
+            fn crossover_with(
+                &mut self,
+                _left: &mut f64,
+                _right: &mut f64,
+                _crossover: &mut Crossover<impl Rng>,
+            ) {
+            }
+        }
+
+        let mut rng = crate::test_rng();
+        let (mut left, mut right) = (vec![0.0; 3], vec![0.0; 2]);
+
+        Crossover::new(&mut rng, CrossoverState::Uniform(1.0)).labeled("brain", |crossover| {
+            crossover.labeled("weights", |crossover| {
+                crossover.iter_with(&mut Intermediate, &mut left, &mut right);
+            });
+        });
+    }
+
+    #[test]
+    fn test_at_least_one() {
+        let mut rng = crate::test_rng();
+
+        for _ in 0..1000 {
+            let mut pair = (0u8, false);
+            let changed = Mutator::new(0.2, &mut rng).at_least_one(&mut pair, |mutator, pair| {
+                mutator.genome(pair);
+            });
+
+            assert!(changed);
+            assert_ne!(pair, (0, false));
+        }
+
+        // A genome that can't change gives up after a bounded number of attempts
+        let mut attempts = 0;
+        let mut unit = ();
+        let changed = Mutator::new(1.0, &mut rng).at_least_one(&mut unit, |mutator, unit| {
+            attempts += 1;
+            mutator.chromosome(unit);
+        });
+        assert!(!changed);
+        assert_eq!(attempts, AT_LEAST_ONE_ATTEMPTS);
+    }
+
+    #[test]
+    fn test_at_least_one_selection() {
+        struct Parts([[u64; 2]; 4]);
+
+        impl Genome for Parts {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                for part in self.0.iter_mut() {
+                    mutator.at_least_one(part, |mutator, part| {
+                        mutator.genome(part);
+                    });
+                }
+            }
+
+            fn crossover(&mut self, _other: &mut Self, _crossover: &mut Crossover<impl Rng>) {
+                unimplemented!()
+            }
+
+            fn size_hint(&self) -> usize {
+                8
+            }
+        }
+
+        // The attempts on the unselected parts don't use up the selection of the following parts
+        let mut rng = crate::test_rng();
+        let mut selected = vec![false; 8];
+        selected[2] = true;
+        selected[7] = true;
+
+        let mut parts = Parts([[0; 2]; 4]);
+        parts.mutate(&mut Mutator::new(1.0, &mut rng).select(selected));
+        let mutated = parts.0.map(|part| part.map(|gene| gene != 0));
+        assert_eq!(
+            mutated,
+            [[false, false], [true, false], [false, false], [false, true]]
+        );
+
+        for n in 0..=8 {
+            let mut parts = Parts([[0; 2]; 4]);
+            crate::mutate_n_genes(&mut parts, n, &mut rng);
+
+            let mutated = parts.0.iter().flatten().filter(|&&gene| gene != 0).count();
+            assert_eq!(mutated, n);
+        }
     }
 }
@@ -0,0 +1,167 @@
+use std::ops::RangeInclusive;
+
+use super::*;
+
+/// The number of times [BoundedCh] re-rolls an out-of-range mutation before falling back to a uniform draw.
+const MAX_RETRIES: usize = 16;
+
+/// Keeps the usual bit-flip mutation of integers, but constrains the mutated value within `min` and `max`.
+///
+/// Mutations yielding a value outside of the range are rejected and re-rolled.
+/// If no valid value was found after a few retries (which can happen for tiny ranges),
+/// then the value is instead drawn uniformly between `min` and `max`.
+///
+/// # Panics
+///
+/// Mutating a value panics if `max` is smaller than `min`;
+/// [BoundedCh::new] already checks this, but setting the fields directly, converting a range or deserializing the wrapper doesn't.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::BoundedCh;
+///
+/// struct Percentage {
+///     value: u8,
+/// }
+///
+/// impl Genome for Percentage {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.with(&mut BoundedCh::new(0, 100), &mut self.value);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundedCh<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T> BoundedCh<T> {
+    /// Creates a new wrapper, where the integer value will be kept between `min` and `max` (inclusive).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is smaller than `min`.
+    pub fn new(min: T, max: T) -> Self
+    where
+        T: PartialOrd,
+    {
+        assert!(max >= min, "`max` should not be smaller than `min`");
+
+        Self { min, max }
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for BoundedCh<T> {
+    fn from(value: RangeInclusive<T>) -> Self {
+        let (min, max) = value.into_inner();
+
+        Self { min, max }
+    }
+}
+
+impl<T> From<BoundedCh<T>> for RangeInclusive<T> {
+    fn from(value: BoundedCh<T>) -> Self {
+        (value.min)..=(value.max)
+    }
+}
+
+macro_rules! impl_bounded_int {
+    ( $type:ty ) => {
+        impl MutationWrapper<&mut $type> for BoundedCh<$type> {
+            fn mutate_with(&mut self, value: &mut $type, mutator: &mut Mutator<impl Rng>) {
+                assert!(
+                    self.max >= self.min,
+                    "`max` should not be smaller than `min`"
+                );
+
+                let rate = mutator.get_rate();
+                let rng = mutator.get_rng();
+
+                let original = (*value).clamp(self.min, self.max);
+
+                for _ in 0..MAX_RETRIES {
+                    let mut candidate = original;
+                    Chromosome::mutate(&mut candidate, rate, &mut *rng);
+
+                    if (self.min..=self.max).contains(&candidate) {
+                        *value = candidate;
+                        return;
+                    }
+                }
+
+                *value = rng.gen_range(self.min..=self.max);
+            }
+        }
+    };
+}
+
+impl_bounded_int!(u8);
+impl_bounded_int!(u16);
+impl_bounded_int!(u32);
+impl_bounded_int!(u64);
+impl_bounded_int!(u128);
+impl_bounded_int!(usize);
+impl_bounded_int!(i8);
+impl_bounded_int!(i16);
+impl_bounded_int!(i32);
+impl_bounded_int!(i64);
+impl_bounded_int!(i128);
+impl_bounded_int!(isize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bounded_stays_in_range() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = BoundedCh::new(0u8, 100);
+
+        for rate in [0.1, 0.5, 1.0] {
+            let mut value = 50u8;
+            for _ in 0..1000 {
+                Mutator::new(rate, &mut rng).with(&mut wrapper, &mut value);
+                assert!(value <= 100);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bounded_tiny_range() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = BoundedCh::new(-3i32, -2);
+
+        let mut value = 0;
+        for _ in 0..100 {
+            Mutator::new(1.0, &mut rng).with(&mut wrapper, &mut value);
+            assert!((-3..=-2).contains(&value));
+        }
+
+        let mut wrapper = BoundedCh::new(10usize, 12);
+        let mut value = 0;
+        for _ in 0..100 {
+            Mutator::new(1.0, &mut rng).with(&mut wrapper, &mut value);
+            assert!((10..=12).contains(&value));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "`max` should not be smaller than `min`")]
+    fn test_bounded_invalid_range() {
+        let mut wrapper = BoundedCh { min: 5u8, max: 2 };
+        Mutator::new(1.0, crate::test_rng()).with(&mut wrapper, &mut 3u8);
+    }
+}
@@ -0,0 +1,117 @@
+use super::*;
+
+/// Applies two mutation wrappers one after the other on the same value,
+/// as if they were a single wrapper.
+///
+/// The value is first mutated by `.0`, then by `.1`.
+/// Since both wrappers act on the same value, a `Chain` should only account for `1` in [Genome::size_hint].
+/// Chains can be nested to combine more than two wrappers, for instance `Chain(a, Chain(b, c))`.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::{Chain, FixedBits, UniformCh};
+///
+/// struct Color {
+///     hue: u8,
+/// }
+///
+/// impl Genome for Color {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.with(
+///             &mut Chain(UniformCh::new(0, 127), FixedBits::new(4)),
+///             &mut self.hue,
+///         );
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chain<A, B>(pub A, pub B);
+
+impl<T: ?Sized, A, B> MutationWrapper<&mut T> for Chain<A, B>
+where
+    A: for<'a> MutationWrapper<&'a mut T>,
+    B: for<'a> MutationWrapper<&'a mut T>,
+{
+    fn mutate_with(&mut self, value: &mut T, mutator: &mut Mutator<impl Rng>) {
+        self.0.mutate_with(&mut *value, mutator);
+        self.1.mutate_with(value, mutator);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::Rng;
+
+    use super::*;
+    use crate::wrapper::UniformCh;
+
+    struct AddOne;
+
+    impl MutationWrapper<&mut u32> for AddOne {
+        fn mutate_with(&mut self, value: &mut u32, _mutator: &mut Mutator<impl Rng>) {
+            *value += 1;
+        }
+    }
+
+    struct Double;
+
+    impl MutationWrapper<&mut u32> for Double {
+        fn mutate_with(&mut self, value: &mut u32, _mutator: &mut Mutator<impl Rng>) {
+            *value *= 2;
+        }
+    }
+
+    struct Single(u32);
+
+    impl Genome for Single {
+        fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+            mutator.with(&mut Chain(AddOne, Double), &mut self.0);
+        }
+
+        fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+            crossover.chromosome(&mut self.0, &mut other.0);
+        }
+
+        fn size_hint(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn test_chain_order() {
+        let mut instance = Single(3);
+
+        crate::mutate(&mut instance, 0.5, &mut crate::test_rng());
+
+        assert_eq!(instance.0, 8);
+
+        // Both wrappers are applied to the value within a single traversal step
+        assert_eq!(crate::dry_run_mutate(&mut instance).len(), 1);
+    }
+
+    #[test]
+    fn test_chain_both_applied() {
+        let mut rng = crate::test_rng();
+        let mut value = 0u32;
+        let mut chain = Chain(UniformCh::new(10, 20), AddOne);
+
+        for _ in 0..100 {
+            let mut mutator = Mutator::new(1.0, &mut rng);
+            mutator.with(&mut chain, &mut value);
+
+            assert!((11..=21).contains(&value));
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::*;
+
+/// Wraps an integer whose `data_bits` least significant bits hold data, while the remaining bits are derived from them
+/// (like parity or checksum bits).
+///
+/// Only the data bits are mutated, as with [FixedBits], and `recompute` is then called once on the mutated value,
+/// so that it can update the derived bits and keep the value internally consistent.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::ChecksumCh;
+///
+/// /// Seven bits of data, followed by an even parity bit
+/// struct Byte(u8);
+///
+/// fn set_parity(value: &mut u8) {
+///     let parity = (*value & 0x7F).count_ones() as u8 % 2;
+///     *value = (*value & 0x7F) | (parity << 7);
+/// }
+///
+/// impl Genome for Byte {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.with(&mut ChecksumCh::new(7, set_parity), &mut self.0);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChecksumCh<F> {
+    pub data_bits: u8,
+    pub recompute: F,
+}
+
+impl<F> ChecksumCh<F> {
+    /// Creates a new wrapper, mutating the `data_bits` least significant bits and then calling `recompute`.
+    pub fn new(data_bits: u8, recompute: F) -> Self {
+        Self {
+            data_bits,
+            recompute,
+        }
+    }
+}
+
+impl<T, F> MutationWrapper<&mut T> for ChecksumCh<F>
+where
+    FixedBits: for<'a> MutationWrapper<&'a mut T>,
+    F: FnMut(&mut T),
+{
+    fn mutate_with(&mut self, value: &mut T, mutator: &mut Mutator<impl Rng>) {
+        FixedBits::new(self.data_bits).mutate_with(&mut *value, mutator);
+
+        (self.recompute)(value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn has_even_parity(value: u16) -> bool {
+        value.count_ones().is_multiple_of(2)
+    }
+
+    #[test]
+    fn test_checksum_parity() {
+        let mut rng = crate::test_rng();
+        let mut calls = 0;
+        let mut changed = 0;
+
+        {
+            let mut wrapper = ChecksumCh::new(12, |value: &mut u16| {
+                calls += 1;
+                let parity = (*value & 0x0FFF).count_ones() as u16 % 2;
+                *value = (*value & 0x0FFF) | (parity << 15);
+            });
+
+            let mut value = 0u16;
+            for _ in 0..1000 {
+                let previous = value;
+                Mutator::new(0.5, &mut rng).with(&mut wrapper, &mut value);
+
+                assert!(has_even_parity(value));
+                assert_eq!(value & 0x7000, 0);
+                if value != previous {
+                    changed += 1;
+                }
+            }
+        }
+
+        // The closure is called once per mutation, not once per bit
+        assert_eq!(calls, 1000);
+        assert!(changed > 900);
+    }
+}
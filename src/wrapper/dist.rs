@@ -0,0 +1,169 @@
+use rand::distributions::Distribution;
+
+use super::*;
+
+/// A wrapper around floating-point values, generalizing [UniformCh] to arbitrary distributions.
+///
+/// As with [UniformCh], the mutation happens within a window of width `rate * (max - min)`,
+/// centered on the current value, and shifted to stay between `min` and `max`.
+/// Instead of picking a uniform value within that window, a sample `t` is drawn from `distribution`,
+/// and the new value is `low + t * (high - low)`, where `low..=high` is the window.
+///
+/// `distribution` should thus yield values between `0.0` and `1.0`; values outside of this interval are clamped.
+/// For instance, a triangular distribution with its mode at `0.5` favors small steps around the current value,
+/// while a uniform distribution over `0.0..1.0` behaves like [UniformCh].
+///
+/// The `rate` only affects the spread of the mutation, by scaling the window:
+/// a rate of `1.0` lets the value reach any number between `min` and `max` (with the shape of `distribution`
+/// stretched over the whole interval), and a rate of `0.0` leaves the value untouched.
+/// The window is also scaled by the [magnitude](Mutator::magnitude) of the mutator.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::DistCh;
+///
+/// struct Weight(f64);
+///
+/// impl Genome for Weight {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         // A Beta or Triangular distribution from `rand_distr` can be used here
+///         let distribution = rand::distributions::Uniform::new_inclusive(0.0, 1.0);
+///         mutator.with(&mut DistCh::new(-1.0, 1.0, distribution), &mut self.0);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistCh<T, D> {
+    pub min: T,
+    pub max: T,
+    pub distribution: D,
+}
+
+impl<T, D> DistCh<T, D> {
+    /// Creates a new wrapper, where the value will be mutated between `min` and `max`,
+    /// following `distribution` within the mutation window.
+    pub fn new(min: T, max: T, distribution: D) -> Self {
+        Self {
+            min,
+            max,
+            distribution,
+        }
+    }
+}
+
+macro_rules! impl_dist_float {
+    ( $type:ty ) => {
+        impl<D: Distribution<f64>> MutationWrapper<&mut $type> for DistCh<$type, D> {
+            fn mutate_with(&mut self, value: &mut $type, mutator: &mut Mutator<impl Rng>) {
+                let spread = (mutator.get_rate() * mutator.magnitude()).clamp(0.0, 1.0);
+                let range = (self.max - self.min) * spread as $type;
+                let half_range = range / 2.0;
+
+                *value = (*value).clamp(self.min, self.max);
+
+                let low = if *value - self.min < half_range {
+                    self.min
+                } else if self.max - *value < half_range {
+                    self.max - range
+                } else {
+                    *value - half_range
+                };
+
+                let t = self.distribution.sample(mutator.get_rng()).clamp(0.0, 1.0);
+                *value = (low + t as $type * range).clamp(self.min, self.max);
+            }
+        }
+    };
+}
+
+impl_dist_float!(f32);
+impl_dist_float!(f64);
+
+/// As with the `f16` implementation of [UniformCh], the mutation is performed on `f32`s,
+/// then rounded back to the nearest `f16`.
+///
+/// Only available with the `half` feature flag.
+#[cfg(feature = "half")]
+impl<D: Distribution<f64>> MutationWrapper<&mut half::f16> for DistCh<half::f16, D> {
+    fn mutate_with(&mut self, value: &mut half::f16, mutator: &mut Mutator<impl Rng>) {
+        let mut value_f32 = value.to_f32();
+        DistCh::new(self.min.to_f32(), self.max.to_f32(), &self.distribution)
+            .mutate_with(&mut value_f32, mutator);
+
+        *value = half::f16::from_f32(value_f32).clamp(self.min, self.max);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand_distr::Triangular;
+
+    use super::*;
+
+    #[test]
+    fn test_dist_triangular() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = DistCh::new(-2.0f64, 2.0, Triangular::new(0.0, 1.0, 0.5).unwrap());
+
+        for rate in [0.0, 0.1, 0.5, 1.0] {
+            let mut value = 1.5;
+            for _ in 0..1000 {
+                let previous = value;
+                Mutator::new(rate, &mut rng).with(&mut wrapper, &mut value);
+                assert!((-2.0..=2.0).contains(&value));
+                assert!((value - previous).abs() <= rate * 4.0 + 1e-9);
+            }
+
+            if rate == 0.0 {
+                assert_eq!(value, 1.5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dist_f32_clamped() {
+        let mut rng = crate::test_rng();
+        // Samples outside of `0..=1` are clamped
+        let mut wrapper = DistCh::new(0.0f32, 1.0, rand::distributions::Uniform::new(-5.0, 5.0));
+
+        let mut value = 0.5;
+        for _ in 0..1000 {
+            Mutator::new(0.5, &mut rng).with(&mut wrapper, &mut value);
+            assert!((0.0..=1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn test_f16() {
+        use half::f16;
+
+        let mut rng = crate::test_rng();
+        let (min, max) = (f16::from_f32(-60000.0), f16::from_f32(60000.0));
+        let mut wrapper = DistCh::new(min, max, Triangular::new(0.0, 1.0, 0.5).unwrap());
+
+        for rate in [0.01, 0.5, 1.0, 2.0] {
+            let mut values = [min, max, f16::ZERO, f16::INFINITY];
+            for _ in 0..1000 {
+                let mut mutator = Mutator::new(rate, &mut rng);
+                for value in values.iter_mut() {
+                    mutator.with(&mut wrapper, value);
+                    assert!(value.is_finite(), "{value}");
+                    assert!(min <= *value && *value <= max, "{value}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+
+use rand::RngCore;
+
+use super::*;
+
+/// An object-safe version of [MutationWrapper], for wrappers mutating values of type `T` by reference.
+///
+/// [MutationWrapper::mutate_with] is generic over the rng, so it cannot be called on a trait object.
+/// This trait instead receives a [Mutator] whose rng is itself a trait object, `&mut dyn RngCore`,
+/// which makes it usable as `Box<dyn DynMutationWrapper<T>>`.
+/// The other constraints of object safety still apply to the values: the type `T` is fixed by the trait object,
+/// so a single boxed wrapper can only mutate one type of value, and the wrapper must be `'static` to be stored in
+/// an [OperatorRegistry].
+///
+/// This trait is implemented for every [MutationWrapper] over `&mut T`, and boxed wrappers implement [MutationWrapper],
+/// so they can be passed to [Mutator::with] like any other wrapper.
+/// Going through the trait object has a small cost, as each random number is drawn through dynamic dispatch.
+pub trait DynMutationWrapper<T: ?Sized> {
+    /// The equivalent of [MutationWrapper::mutate_with], with a type-erased rng.
+    fn mutate_dyn(&mut self, value: &mut T, mutator: &mut Mutator<&mut dyn RngCore>);
+}
+
+impl<T: ?Sized, W> DynMutationWrapper<T> for W
+where
+    W: for<'a> MutationWrapper<&'a mut T>,
+{
+    fn mutate_dyn(&mut self, value: &mut T, mutator: &mut Mutator<&mut dyn RngCore>) {
+        self.mutate_with(value, mutator);
+    }
+}
+
+impl<'a, T: ?Sized> MutationWrapper<&mut T> for Box<dyn DynMutationWrapper<T> + 'a> {
+    fn mutate_with(&mut self, value: &mut T, mutator: &mut Mutator<impl Rng>) {
+        // Calling the method on the box itself would recurse through the blanket implementation
+        mutator.erase_rng(|mutator| (**self).mutate_dyn(value, mutator));
+    }
+}
+
+/// An object-safe version of [CrossoverWrapper], for wrappers crossing over values of type `T` by reference.
+///
+/// See [DynMutationWrapper] for the constraints that this implies.
+pub trait DynCrossoverWrapper<T: ?Sized> {
+    /// The equivalent of [CrossoverWrapper::crossover_with], with a type-erased rng.
+    fn crossover_dyn(
+        &mut self,
+        value_left: &mut T,
+        value_right: &mut T,
+        crossover: &mut Crossover<&mut dyn RngCore>,
+    );
+}
+
+impl<T: ?Sized, W> DynCrossoverWrapper<T> for W
+where
+    W: for<'a> CrossoverWrapper<&'a mut T>,
+{
+    fn crossover_dyn(
+        &mut self,
+        value_left: &mut T,
+        value_right: &mut T,
+        crossover: &mut Crossover<&mut dyn RngCore>,
+    ) {
+        self.crossover_with(value_left, value_right, crossover);
+    }
+}
+
+impl<'a, T: ?Sized> CrossoverWrapper<&mut T> for Box<dyn DynCrossoverWrapper<T> + 'a> {
+    fn crossover_with(
+        &mut self,
+        value_left: &mut T,
+        value_right: &mut T,
+        crossover: &mut Crossover<impl Rng>,
+    ) {
+        crossover.erase_rng(|crossover| (**self).crossover_dyn(value_left, value_right, crossover));
+    }
+}
+
+type MutationFactory<T> = Box<dyn Fn() -> Box<dyn DynMutationWrapper<T>>>;
+type CrossoverFactory<T> = Box<dyn Fn() -> Box<dyn DynCrossoverWrapper<T>>>;
+
+/// Maps names to mutation and crossover wrappers for values of type `T`,
+/// so that the operators of a run can be chosen at runtime, for instance from a configuration file.
+///
+/// Each name is associated with a factory, which builds a new boxed wrapper every time the name is looked up.
+/// Mutation and crossover operators live in separate namespaces, so both can be registered under the same name.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::{DistCh, DynMutationWrapper, OperatorRegistry, UniformCh};
+///
+/// let mut registry = OperatorRegistry::<f64>::new();
+/// registry
+///     .register_mutation("uniform", || UniformCh::new(-1.0, 1.0))
+///     .register_mutation("triangular", || {
+///         // A distribution from `rand_distr` could be used here
+///         DistCh::new(-1.0, 1.0, rand::distributions::Uniform::new_inclusive(0.25, 0.75))
+///     });
+///
+/// struct Weight {
+///     value: f64,
+///     operator: Box<dyn DynMutationWrapper<f64>>,
+/// }
+///
+/// impl Genome for Weight {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.with(&mut self.operator, &mut self.value);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+///
+/// let name = "uniform"; // Read from a configuration file
+/// let mut weight = Weight {
+///     value: 0.0,
+///     operator: registry.mutation(name).expect("Unknown operator"),
+/// };
+/// genomic::mutate(&mut weight, 0.5, &mut rand::thread_rng());
+/// ```
+pub struct OperatorRegistry<T: ?Sized> {
+    mutations: HashMap<String, MutationFactory<T>>,
+    crossovers: HashMap<String, CrossoverFactory<T>>,
+}
+
+impl<T: ?Sized> Default for OperatorRegistry<T> {
+    fn default() -> Self {
+        Self {
+            mutations: HashMap::new(),
+            crossovers: HashMap::new(),
+        }
+    }
+}
+
+impl<T: ?Sized + 'static> OperatorRegistry<T> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the mutation wrapper built by `factory` under `name`, replacing any previous one.
+    pub fn register_mutation<W, F>(&mut self, name: impl Into<String>, factory: F) -> &mut Self
+    where
+        W: DynMutationWrapper<T> + 'static,
+        F: Fn() -> W + 'static,
+    {
+        self.mutations.insert(
+            name.into(),
+            Box::new(move || Box::new(factory()) as Box<dyn DynMutationWrapper<T>>),
+        );
+        self
+    }
+
+    /// Registers the crossover wrapper built by `factory` under `name`, replacing any previous one.
+    pub fn register_crossover<W, F>(&mut self, name: impl Into<String>, factory: F) -> &mut Self
+    where
+        W: DynCrossoverWrapper<T> + 'static,
+        F: Fn() -> W + 'static,
+    {
+        self.crossovers.insert(
+            name.into(),
+            Box::new(move || Box::new(factory()) as Box<dyn DynCrossoverWrapper<T>>),
+        );
+        self
+    }
+
+    /// Builds the mutation wrapper registered under `name`, or returns `None` if there is none.
+    pub fn mutation(&self, name: &str) -> Option<Box<dyn DynMutationWrapper<T>>> {
+        self.mutations.get(name).map(|factory| factory())
+    }
+
+    /// Builds the crossover wrapper registered under `name`, or returns `None` if there is none.
+    pub fn crossover(&self, name: &str) -> Option<Box<dyn DynCrossoverWrapper<T>>> {
+        self.crossovers.get(name).map(|factory| factory())
+    }
+
+    /// Returns the names of the registered mutation wrappers, in no particular order.
+    pub fn mutation_names(&self) -> impl Iterator<Item = &str> {
+        self.mutations.keys().map(String::as_str)
+    }
+
+    /// Returns the names of the registered crossover wrappers, in no particular order.
+    pub fn crossover_names(&self) -> impl Iterator<Item = &str> {
+        self.crossovers.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand_distr::Triangular;
+
+    use super::*;
+    use crate::traverse::CrossoverState;
+
+    struct Average;
+
+    impl CrossoverWrapper<&mut f64> for Average {
+        fn crossover_with(
+            &mut self,
+            value_left: &mut f64,
+            value_right: &mut f64,
+            crossover: &mut Crossover<impl Rng>,
+        ) {
+            crossover.custom(|should_flip, _rng| {
+                if should_flip {
+                    let average = (*value_left + *value_right) / 2.0;
+                    *value_left = average;
+                    *value_right = average;
+                }
+            });
+        }
+    }
+
+    fn registry() -> OperatorRegistry<f64> {
+        let mut registry = OperatorRegistry::new();
+        registry
+            .register_mutation("uniform", || UniformCh::new(-1.0, 1.0))
+            .register_mutation("gaussian", || {
+                DistCh::new(-1.0, 1.0, Triangular::new(0.0, 1.0, 0.5).unwrap())
+            })
+            .register_crossover("average", || Average);
+        registry
+    }
+
+    #[test]
+    fn test_registry_mutation() {
+        let registry = registry();
+        let mut rng = crate::test_rng();
+
+        let mut names = registry.mutation_names().collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, ["gaussian", "uniform"]);
+        assert!(registry.mutation("average").is_none());
+
+        for name in ["uniform", "gaussian"] {
+            let mut operator = registry.mutation(name).unwrap();
+            let mut values = std::collections::HashSet::new();
+
+            for _ in 0..100 {
+                let mut value = 0.0f64;
+                let mut mutator = Mutator::new(1.0, &mut rng);
+                mutator.with(&mut operator, &mut value);
+
+                assert!((-1.0..=1.0).contains(&value));
+                values.insert(value.to_bits());
+            }
+            assert!(values.len() > 90);
+        }
+
+        // A boxed wrapper is recorded like any other wrapper, and honors the magnitude
+        let mut operator = registry.mutation("uniform").unwrap();
+        let mut value = 0.0f64;
+        assert_eq!(
+            crate::dry_run_mutate(&mut Boxed(&mut operator, &mut value)).len(),
+            1
+        );
+        Mutator::new(1.0, &mut rng).with_magnitude(0.0, |mutator| {
+            mutator.with(&mut operator, &mut value);
+        });
+        assert_eq!(value, 0.0);
+    }
+
+    struct Boxed<'a>(&'a mut Box<dyn DynMutationWrapper<f64>>, &'a mut f64);
+
+    impl Genome for Boxed<'_> {
+        fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+            mutator.with(self.0, &mut *self.1);
+        }
+
+        fn crossover(&mut self, _other: &mut Self, _crossover: &mut Crossover<impl Rng>) {
+            unimplemented!()
+        }
+
+        fn size_hint(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn test_registry_crossover() {
+        let registry = registry();
+        let mut operator = registry.crossover("average").unwrap();
+        assert!(registry.crossover("uniform").is_none());
+
+        let (mut left, mut right) = (1.0f64, 3.0f64);
+        let (mut after_left, mut after_right) = (0u8, 1u8);
+        let mut crossover =
+            Crossover::new(crate::test_rng(), CrossoverState::Fixed(true)).record_mask();
+        crossover
+            .with(&mut operator, &mut left, &mut right)
+            .chromosome(&mut after_left, &mut after_right);
+        assert_eq!((left, right), (2.0, 2.0));
+        assert_eq!((after_left, after_right), (1, 0));
+
+        // The state of the crossover is carried through the boxed wrapper
+        assert_eq!(crossover.into_mask(), [true, true]);
+    }
+}
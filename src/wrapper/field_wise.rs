@@ -0,0 +1,63 @@
+use super::*;
+
+/// Changes the mutation of a chromosome to be all-or-nothing:
+/// with a probability of `field_rate`, the chromosome is fully re-randomized
+/// (by mutating it with a rate of `1.0`), and otherwise it is left untouched.
+///
+/// This gives a per-field mutation probability, as is commonly used in the literature,
+/// instead of the per-bit probability of the integer [Chromosome] implementations.
+/// The rate of the mutator is ignored, only `field_rate` is used.
+///
+/// Note that a re-randomized chromosome may by chance end up with the same value as before.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldWise {
+    pub field_rate: f64,
+}
+
+impl FieldWise {
+    /// Creates a new wrapper, which will re-randomize the chromosome with a probability of `field_rate`.
+    pub fn new(field_rate: f64) -> Self {
+        Self { field_rate }
+    }
+}
+
+impl From<f64> for FieldWise {
+    fn from(field_rate: f64) -> Self {
+        Self { field_rate }
+    }
+}
+
+impl<Ch: Chromosome + ?Sized> MutationWrapper<&mut Ch> for FieldWise {
+    fn mutate_with(&mut self, value: &mut Ch, mutator: &mut Mutator<impl Rng>) {
+        debug_assert!(self.field_rate <= 1.0);
+        debug_assert!(self.field_rate >= 0.0);
+
+        let rng = mutator.get_rng();
+        if rng.gen_bool(self.field_rate) {
+            value.mutate(1.0, rng);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_field_wise_rate() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = FieldWise::new(0.2);
+
+        let mut changed = 0;
+        for _ in 0..10000 {
+            let mut value = 0u64;
+            Mutator::new(0.5, &mut rng).with(&mut wrapper, &mut value);
+            if value != 0 {
+                changed += 1;
+            }
+        }
+
+        assert!((1800..2200).contains(&changed));
+    }
+}
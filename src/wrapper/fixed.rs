@@ -5,11 +5,21 @@ use rand::distributions::Distribution;
 ///
 /// For signed integers, the numbers are interpreted as `bits`-long integers in two's complement representation.
 /// This means that using `FixedBits { bits: 7 }` with a `i8` is equivalent to mutating a theoretical `i7`.
-#[derive(Clone, Copy, PartialEq, Eq)]
+///
+/// If `bits` is greater than the width of the type, then all of the bits are mutated.
+/// The default value of `FixedBits` uses `bits: u8::MAX`, and thus mutates all of the bits, whatever the type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedBits {
     pub bits: u8,
 }
 
+impl Default for FixedBits {
+    fn default() -> Self {
+        Self { bits: u8::MAX }
+    }
+}
+
 impl From<u8> for FixedBits {
     fn from(value: u8) -> Self {
         Self { bits: value }
@@ -26,17 +36,68 @@ impl FixedBits {
     pub fn new(bits: u8) -> Self {
         Self { bits }
     }
+
+    /// Creates a new wrapper for the integer type `T`, checking that `bits` is between `1` and the width of `T`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use genomic::wrapper::FixedBits;
+    ///
+    /// assert!(FixedBits::for_type::<u16>(12).is_ok());
+    /// assert!(FixedBits::for_type::<u16>(17).is_err());
+    /// ```
+    pub fn for_type<T: BitWidth>(bits: u8) -> Result<Self, FixedBitsError> {
+        if bits == 0 || bits as u32 > T::BITS {
+            Err(FixedBitsError {
+                bits,
+                width: T::BITS,
+            })
+        } else {
+            Ok(Self { bits })
+        }
+    }
+}
+
+/// The integer types that [FixedBits] can be applied to, along with their width in bits.
+pub trait BitWidth {
+    const BITS: u32;
+}
+
+/// The error returned by [FixedBits::for_type] when the number of bits is invalid for the type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedBitsError {
+    /// The requested number of bits.
+    pub bits: u8,
+    /// The width of the type, in bits.
+    pub width: u32,
+}
+
+impl std::fmt::Display for FixedBitsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cannot mutate {} bits of a {}-bit integer (expected between 1 and {})",
+            self.bits, self.width, self.width
+        )
+    }
 }
 
+impl std::error::Error for FixedBitsError {}
+
 macro_rules! impl_fixed_uint {
     ( $type:ty ) => {
+        impl BitWidth for $type {
+            const BITS: u32 = <$type>::BITS;
+        }
+
         impl MutationWrapper<&mut $type> for FixedBits {
             fn mutate_with(&mut self, value: &mut $type, mutator: &mut Mutator<impl Rng>) {
                 let rate = mutator.get_rate();
-                debug_assert!(rate <= 1.0);
                 debug_assert!(rate >= 0.0);
-                let distribution = rand::distributions::Bernoulli::new(rate * 0.5)
-                    .expect("`rate` should be between 0.0 and 1.0");
+                let distribution =
+                    rand::distributions::Bernoulli::new((rate * 0.5).clamp(0.0, 1.0))
+                        .expect("`rate` should not be NaN");
 
                 for (bit, should_flip) in (0..self.bits.min(<$type>::BITS as u8))
                     .zip(distribution.sample_iter(mutator.get_rng()))
@@ -52,36 +113,41 @@ macro_rules! impl_fixed_uint {
 
 macro_rules! impl_fixed_int {
     ( $type:ty ) => {
+        impl BitWidth for $type {
+            const BITS: u32 = <$type>::BITS;
+        }
+
         impl MutationWrapper<&mut $type> for FixedBits {
             fn mutate_with(&mut self, value: &mut $type, mutator: &mut Mutator<impl Rng>) {
                 let rate = mutator.get_rate();
-                debug_assert!(rate <= 1.0);
                 debug_assert!(rate >= 0.0);
-                let distribution = rand::distributions::Bernoulli::new(rate * 0.5)
-                    .expect("`rate` should be between 0.0 and 1.0");
+                let distribution =
+                    rand::distributions::Bernoulli::new((rate * 0.5).clamp(0.0, 1.0))
+                        .expect("`rate` should not be NaN");
+                let bits = self.bits.min(<$type>::BITS as u8);
 
-                if self.bits != <$type>::BITS as u8 {
-                    let depth = 1 << (self.bits - 1);
+                if bits != <$type>::BITS as u8 {
+                    let depth = 1 << (bits - 1);
                     *value = (*value).clamp(-depth, depth - 1);
                 }
 
                 for (bit, should_flip) in
-                    (0..(self.bits - 1)).zip(distribution.sample_iter(mutator.get_rng()))
+                    (0..(bits - 1)).zip(distribution.sample_iter(mutator.get_rng()))
                 {
                     if should_flip {
                         *value ^= 1 << bit;
                     }
                 }
 
-                // Flip the sign bit in a theoretical `self.bits`-sized two's complement integer
+                // Flip the sign bit in a theoretical `bits`-sized two's complement integer
                 // `(u_n)_2c = -u_{bits-1}*2^{bits-1} + \sum_{i=0}{i<bits-1}{u_i * 2^i}`
                 if distribution.sample(mutator.get_rng()) {
-                    if self.bits == <$type>::BITS as u8 {
-                        *value ^= 1 << (self.bits - 1);
+                    if bits == <$type>::BITS as u8 {
+                        *value ^= 1 << (bits - 1);
                     } else if *value < 0 {
-                        *value += 1 << (self.bits - 1);
+                        *value += 1 << (bits - 1);
                     } else {
-                        *value -= 1 << (self.bits - 1);
+                        *value -= 1 << (bits - 1);
                     }
                 }
             }
@@ -104,3 +170,34 @@ impl_fixed_int!(i128);
 impl_fixed_uint!(isize);
 
 // TODO: implement this for floats?
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_for_type() {
+        assert_eq!(FixedBits::for_type::<u8>(8), Ok(FixedBits::new(8)));
+        assert_eq!(FixedBits::for_type::<i32>(1), Ok(FixedBits::new(1)));
+        assert_eq!(
+            FixedBits::for_type::<u8>(9),
+            Err(FixedBitsError { bits: 9, width: 8 })
+        );
+        assert!(FixedBits::for_type::<i16>(17).is_err());
+        assert!(FixedBits::for_type::<u64>(0).is_err());
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(FixedBits::default(), FixedBits::new(u8::MAX));
+
+        let mut rng = crate::test_rng();
+        let mut values = std::collections::HashSet::new();
+        for _ in 0..100 {
+            let mut value = 0i8;
+            Mutator::new(1.0, &mut rng).with(&mut FixedBits::default(), &mut value);
+            values.insert(value < 0);
+        }
+        assert_eq!(values.len(), 2);
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use super::*;
+
+/// A uniform crossover on sequences of genes, where the genes are partitioned into groups that are always swapped together.
+///
+/// `groups[i]` is the group id of the `i`-th gene. When crossing two sequences over,
+/// a single decision is made for each group id, with a probability of `rate * 0.5` of swapping the group,
+/// like [CrossoverMethod::Uniform]; all of the genes of a group are then swapped (or kept) together,
+/// wherever they are in the sequence.
+/// This generalizes [Crossover::group] to partitions defined by data, where the genes of a group don't need to be adjacent.
+///
+/// Genes past the end of `groups` are never swapped (and a debug assertion is raised).
+///
+/// The whole sequence takes a single decision of the crossover method, like [Crossover::custom],
+/// and thus accounts for one chromosome in [Genome::size_hint] and in the mask of [crossover_with_mask](crate::crossover_with_mask).
+/// The groups are rolled independently of that decision, except within a [Crossover::group],
+/// where the whole sequence is swapped or kept along with the rest of the group.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::GroupedUniform;
+///
+/// struct Circuit {
+///     // The two first components and the last component form one functional unit
+///     components: Vec<u16>,
+/// }
+///
+/// impl Genome for Circuit {
+///     // ...
+///     # fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///         crossover.with(
+///             &mut GroupedUniform::new(vec![0, 0, 1, 2, 0], 1.0),
+///             &mut self.components,
+///             &mut other.components,
+///         );
+///     }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupedUniform {
+    pub groups: Vec<usize>,
+    pub rate: f64,
+}
+
+impl GroupedUniform {
+    /// Creates a new wrapper, where the `i`-th gene belongs to the group `groups[i]`,
+    /// and each group is swapped with a probability of `rate * 0.5`.
+    pub fn new(groups: Vec<usize>, rate: f64) -> Self {
+        Self { groups, rate }
+    }
+}
+
+impl<'a, G: 'a, I> CrossoverWrapper<I> for GroupedUniform
+where
+    I: IntoIterator<Item = &'a mut G>,
+{
+    fn crossover_with(
+        &mut self,
+        genome_left: I,
+        genome_right: I,
+        crossover: &mut Crossover<impl Rng>,
+    ) {
+        let probability = (self.rate * 0.5).clamp(0.0, 1.0);
+        let within_group = crossover.method() == CrossoverMethodKind::Fixed;
+        let mut decisions = HashMap::new();
+
+        crossover.custom(|group_flip, rng| {
+            for (index, (gene_left, gene_right)) in
+                genome_left.into_iter().zip(genome_right).enumerate()
+            {
+                let Some(&group) = self.groups.get(index) else {
+                    debug_assert!(false, "Gene {index} has no group id in GroupedUniform");
+                    break;
+                };
+
+                let should_flip = if within_group {
+                    group_flip
+                } else {
+                    *decisions
+                        .entry(group)
+                        .or_insert_with(|| rng.gen_bool(probability))
+                };
+
+                if should_flip {
+                    std::mem::swap(gene_left, gene_right);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traverse::CrossoverMethod;
+
+    struct Genes(Vec<u8>);
+
+    impl Genome for Genes {
+        fn mutate(&mut self, _mutator: &mut Mutator<impl Rng>) {
+            unimplemented!()
+        }
+
+        fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+            crossover.with(
+                &mut GroupedUniform::new(vec![0, 1, 2, 0, 1, 2, 3, 0], 1.0),
+                &mut self.0,
+                &mut other.0,
+            );
+        }
+
+        fn size_hint(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn test_grouped_uniform() {
+        let mut rng = crate::test_rng();
+        let groups = [0, 1, 2, 0, 1, 2, 3, 0];
+        let mut swapped = [0; 4];
+
+        for _ in 0..1000 {
+            let mut left = Genes((0..8).collect());
+            let mut right = Genes((100..108).collect());
+            crate::crossover(
+                &mut left,
+                &mut right,
+                CrossoverMethod::Uniform(1.0),
+                &mut rng,
+            );
+
+            for (group, swapped) in swapped.iter_mut().enumerate() {
+                let flips = (0..8)
+                    .filter(|&index| groups[index] == group)
+                    .map(|index| left.0[index] >= 100)
+                    .collect::<Vec<_>>();
+                assert!(flips.iter().all(|&flip| flip == flips[0]), "{flips:?}");
+                if flips[0] {
+                    *swapped += 1;
+                }
+            }
+
+            for index in 0..8 {
+                assert_eq!(left.0[index] % 100, index as u8);
+                assert_eq!(left.0[index] + right.0[index], 100 + 2 * index as u8);
+            }
+        }
+
+        assert!(
+            swapped.iter().all(|count| (400..600).contains(count)),
+            "{swapped:?}"
+        );
+    }
+
+    #[test]
+    fn test_grouped_uniform_decisions() {
+        struct Framed {
+            head: [u8; 2],
+            genes: Vec<u8>,
+            tail: [u8; 2],
+            grouped: bool,
+        }
+
+        impl Genome for Framed {
+            fn mutate(&mut self, _mutator: &mut Mutator<impl Rng>) {
+                unimplemented!()
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                let mut wrapper = GroupedUniform::new(vec![0, 1, 0], 0.0);
+
+                crossover.genome(&mut self.head, &mut other.head);
+                if self.grouped {
+                    crossover.group(|crossover| {
+                        crossover.with(&mut wrapper, &mut self.genes, &mut other.genes);
+                    });
+                } else {
+                    crossover.with(&mut wrapper, &mut self.genes, &mut other.genes);
+                }
+                crossover.genome(&mut self.tail, &mut other.tail);
+            }
+
+            fn size_hint(&self) -> usize {
+                5
+            }
+        }
+
+        let new = |value: u8, grouped: bool| Framed {
+            head: [value; 2],
+            genes: vec![value; 3],
+            tail: [value; 2],
+            grouped,
+        };
+
+        // The genes take a single decision, so the point at `3` falls right after them
+        let mut rng = crate::test_rng();
+        for grouped in [false, true] {
+            let (mut left, mut right) = (new(0, grouped), new(1, grouped));
+            let mask = crate::crossover_with_mask(
+                &mut left,
+                &mut right,
+                CrossoverMethod::FixedPoints(vec![3]),
+                &mut rng,
+            );
+
+            assert_eq!(mask, [false, false, false, true, true]);
+            assert_eq!(
+                (left.head, left.genes, left.tail),
+                ([0; 2], vec![0; 3], [1; 2])
+            );
+        }
+
+        // Within a group, the genes are swapped along with the group, even with a rate of `0`
+        let (mut left, mut right) = (new(0, true), new(1, true));
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![2]),
+            &mut rng,
+        );
+        assert_eq!(
+            (left.head, left.genes, left.tail),
+            ([0; 2], vec![1; 3], [1; 2])
+        );
+    }
+}
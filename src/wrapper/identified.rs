@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+
+use super::*;
+
+/// Crosses over genomes made of identified genes, pairing the genes by their identifier rather than by their position.
+///
+/// Positional crossover (like [Crossover::iter]) assumes that the genes at the same index in both parents
+/// play the same role. This stops being true once the genes have been re-ordered, for instance by [ReorderGenome]:
+/// the genes of two re-ordered parents would then be recombined with unrelated genes.
+/// Giving each gene an identifier (any type implementing [Ord]) and using this wrapper on a `Vec<(Id, G)>`
+/// instead recombines the genes that share the same identifier, wherever they are in either parent.
+///
+/// The genes are visited in increasing order of identifier, but the order of the genes within each parent is preserved.
+/// Genes whose identifier only appears in one of the parents are left untouched,
+/// and genes sharing an identifier within a parent are paired in the order in which they appear.
+///
+/// [IdentifiedCrossover::size_hint] counts every gene, including the unpaired ones:
+/// for each chromosome of the genes of the left parent that have no pair, one crossover decision is taken and discarded,
+/// so that the crossover method sees as many decisions as the size hint,
+/// and the positions of [CrossoverMethod::FixedPoints](crate::CrossoverMethod::FixedPoints) keep referring to the left parent.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::{IdentifiedCrossover, ReorderGenome};
+///
+/// struct Route {
+///     stops: Vec<(u32, u16)>,
+/// }
+///
+/// impl Genome for Route {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator
+///             .with(&mut ReorderGenome::Swap, self.stops.iter_mut())
+///             .iter(self.stops.iter_mut().map(|(_, stop)| stop));
+///     }
+///
+///     fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///         crossover.with(&mut IdentifiedCrossover, &mut self.stops, &mut other.stops);
+///     }
+///
+///     fn size_hint(&self) -> usize {
+///         IdentifiedCrossover.size_hint(&self.stops)
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IdentifiedCrossover;
+
+impl IdentifiedCrossover {
+    /// Returns the number of chromosomes in `genome`, which is the sum of the size hints of all of its genes,
+    /// whether or not they will be paired with a gene of the other parent.
+    pub fn size_hint<Id, G: Genome>(&self, genome: &[(Id, G)]) -> usize {
+        genome.iter().map(|(_, gene)| gene.size_hint()).sum()
+    }
+}
+
+/// Returns the indices of `genome`, sorted by identifier.
+fn sorted_indices<Id: Ord, G>(genome: &[(Id, G)]) -> Vec<usize> {
+    let mut indices = (0..genome.len()).collect::<Vec<_>>();
+    indices.sort_by(|&left, &right| genome[left].0.cmp(&genome[right].0));
+    indices
+}
+
+/// Takes one crossover decision per chromosome of the unpaired `gene`, without swapping anything.
+fn pad<G: Genome>(gene: &G, crossover: &mut Crossover<impl Rng>) {
+    for _ in 0..gene.size_hint() {
+        crossover.custom(|_, _| {});
+    }
+}
+
+impl<Id: Ord, G: Genome> CrossoverWrapper<&mut Vec<(Id, G)>> for IdentifiedCrossover {
+    fn crossover_with(
+        &mut self,
+        genome_left: &mut Vec<(Id, G)>,
+        genome_right: &mut Vec<(Id, G)>,
+        crossover: &mut Crossover<impl Rng>,
+    ) {
+        let indices_left = sorted_indices(genome_left);
+        let indices_right = sorted_indices(genome_right);
+
+        let mut iter_left = indices_left.into_iter().peekable();
+        let mut iter_right = indices_right.into_iter().peekable();
+
+        while let (Some(&index_left), Some(&index_right)) = (iter_left.peek(), iter_right.peek()) {
+            match genome_left[index_left].0.cmp(&genome_right[index_right].0) {
+                Ordering::Less => {
+                    pad(&genome_left[index_left].1, crossover);
+                    iter_left.next();
+                }
+                Ordering::Greater => {
+                    iter_right.next();
+                }
+                Ordering::Equal => {
+                    crossover.genome(
+                        &mut genome_left[index_left].1,
+                        &mut genome_right[index_right].1,
+                    );
+                    iter_left.next();
+                    iter_right.next();
+                }
+            }
+        }
+
+        for index_left in iter_left {
+            pad(&genome_left[index_left].1, crossover);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traverse::CrossoverMethod;
+
+    struct Genes(Vec<(u8, u8)>);
+
+    impl Genome for Genes {
+        fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+            mutator.with(&mut ReorderGenome::Swap, self.0.iter_mut());
+        }
+
+        fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+            crossover.with(&mut IdentifiedCrossover, &mut self.0, &mut other.0);
+        }
+
+        fn size_hint(&self) -> usize {
+            IdentifiedCrossover.size_hint(&self.0)
+        }
+    }
+
+    #[test]
+    fn test_identified_crossover() {
+        let mut rng = crate::test_rng();
+
+        for _ in 0..100 {
+            // Both parents are shuffled, so their genes no longer line up
+            let mut left = Genes((0..16).map(|id| (id, id)).collect());
+            let mut right = Genes((0..16).map(|id| (id, 100 + id)).collect());
+            crate::mutate(&mut left, 1.0, &mut rng);
+            crate::mutate(&mut right, 1.0, &mut rng);
+            let order_left = left.0.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+            let order_right = right.0.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+
+            crate::crossover(
+                &mut left,
+                &mut right,
+                CrossoverMethod::Uniform(1.0),
+                &mut rng,
+            );
+
+            assert_eq!(
+                left.0.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+                order_left
+            );
+            assert_eq!(
+                right.0.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+                order_right
+            );
+
+            // Each gene was only ever swapped with the gene of the same id
+            for (id, gene) in left.0.iter().chain(right.0.iter()) {
+                assert!(*gene == *id || *gene == 100 + *id);
+            }
+            let mut genes = left
+                .0
+                .iter()
+                .chain(right.0.iter())
+                .map(|(_, gene)| *gene)
+                .collect::<Vec<_>>();
+            genes.sort_unstable();
+            assert_eq!(genes, (0..16).chain(100..116).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_identified_crossover_missing() {
+        let mut rng = crate::test_rng();
+        let mut left = Genes(vec![(3, 3), (1, 1), (5, 5)]);
+        let mut right = Genes(vec![(4, 104), (3, 103), (1, 101)]);
+
+        crate::crossover(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![0]),
+            &mut rng,
+        );
+
+        assert_eq!(left.0, [(3, 103), (1, 101), (5, 5)]);
+        assert_eq!(right.0, [(4, 104), (3, 3), (1, 1)]);
+
+        // The unpaired genes of the left parent still count towards the positions of the points
+        let mut left = Genes(vec![(0, 0), (1, 1), (2, 2), (3, 3)]);
+        let mut right = Genes(vec![(0, 100), (5, 105), (2, 102), (3, 103)]);
+        let mask = crate::crossover_with_mask(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![2]),
+            &mut rng,
+        );
+
+        assert_eq!(mask, [false, false, true, true]);
+        assert_eq!(left.0, [(0, 0), (1, 1), (2, 102), (3, 103)]);
+        assert_eq!(right.0, [(0, 100), (5, 105), (2, 2), (3, 3)]);
+    }
+}
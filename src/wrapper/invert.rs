@@ -0,0 +1,145 @@
+use super::*;
+
+/// Wraps an integer, so that with a probability of `rate * 0.5`, a random contiguous range of bits is inverted,
+/// instead of flipping each bit independently.
+///
+/// The length of the range is picked uniformly between `1` and `max_len` (capped to the width of the type),
+/// and its position is then picked uniformly among the positions where it fits.
+/// This block mutation can move a binary encoding further than independent bit flips, to escape local optima.
+///
+/// A `max_len` of `0` never changes the value.
+/// The [magnitude](Mutator::magnitude) of the mutator is ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::InvertBits;
+///
+/// struct Mask {
+///     pub bits: u32,
+/// }
+///
+/// impl Genome for Mask {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.with(&mut InvertBits::new(8), &mut self.bits);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InvertBits {
+    pub max_len: u8,
+}
+
+impl InvertBits {
+    /// Creates a new wrapper, which will invert ranges of `1` to `max_len` bits.
+    pub fn new(max_len: u8) -> Self {
+        Self { max_len }
+    }
+}
+
+macro_rules! impl_invert_bits {
+    ( $type:ty, $unsigned:ty ) => {
+        impl MutationWrapper<&mut $type> for InvertBits {
+            fn mutate_with(&mut self, value: &mut $type, mutator: &mut Mutator<impl Rng>) {
+                let rate = mutator.get_rate();
+                debug_assert!(rate >= 0.0);
+
+                let max_len = (self.max_len as u32).min(<$unsigned>::BITS);
+                let rng = mutator.get_rng();
+                if max_len == 0 || !rng.gen_bool((rate * 0.5).clamp(0.0, 1.0)) {
+                    return;
+                }
+
+                let length = rng.gen_range(1..=max_len);
+                let start = rng.gen_range(0..=(<$unsigned>::BITS - length));
+                let mask = (<$unsigned>::MAX >> (<$unsigned>::BITS - length)) << start;
+
+                *value = (*value as $unsigned ^ mask) as $type;
+            }
+        }
+    };
+}
+
+impl_invert_bits!(u8, u8);
+impl_invert_bits!(u16, u16);
+impl_invert_bits!(u32, u32);
+impl_invert_bits!(u64, u64);
+impl_invert_bits!(u128, u128);
+impl_invert_bits!(usize, usize);
+
+impl_invert_bits!(i8, u8);
+impl_invert_bits!(i16, u16);
+impl_invert_bits!(i32, u32);
+impl_invert_bits!(i64, u64);
+impl_invert_bits!(i128, u128);
+impl_invert_bits!(isize, usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_single_run() {
+        let mut rng = crate::test_rng();
+        let mut lengths = [0; 9];
+
+        for _ in 0..2000 {
+            let original = rng.gen::<u8>();
+            let mut value = original;
+            Mutator::new(1.0, &mut rng).with(&mut InvertBits::new(8), &mut value);
+
+            // The flipped bits form exactly one contiguous run
+            let flipped = original ^ value;
+            if flipped == 0 {
+                lengths[0] += 1;
+                continue;
+            }
+            let run = flipped >> flipped.trailing_zeros();
+            assert_eq!(run & run.wrapping_add(1), 0, "{flipped:08b}");
+
+            lengths[flipped.count_ones() as usize] += 1;
+        }
+
+        // Half of the values are mutated, and every length is possible, including the full width
+        assert!((900..1100).contains(&lengths[0]), "{lengths:?}");
+        assert!(lengths[1..].iter().all(|&count| count > 50), "{lengths:?}");
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let mut rng = crate::test_rng();
+
+        for _ in 0..100 {
+            let mut value = 42u16;
+            Mutator::new(1.0, &mut rng).with(&mut InvertBits::new(0), &mut value);
+            assert_eq!(value, 42);
+
+            Mutator::new(0.0, &mut rng).with(&mut InvertBits::new(4), &mut value);
+            assert_eq!(value, 42);
+
+            // `max_len` is capped to the width of the type
+            let mut value = 0i8;
+            Mutator::new(1.0, &mut rng).with(&mut InvertBits::new(u8::MAX), &mut value);
+            assert!(value.count_ones() <= 8);
+
+            let mut value = 0u64;
+            Mutator::new(1.0, &mut rng).with(&mut InvertBits::new(3), &mut value);
+            assert!(value.count_ones() <= 3);
+        }
+
+        let mut value = 0u32;
+        Mutator::new(1.0, &mut crate::NullRng).with(&mut InvertBits::new(32), &mut value);
+        assert_eq!(value, 1);
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use super::*;
+
+/// Crosses over the key-value associations of two maps, treating each map as a permutation of its values over its keys.
+///
+/// This is useful for assignment problems, where the keys are fixed (tasks, for instance),
+/// and what evolves is which value (worker) is assigned to which key.
+/// Swapping the values of single keys between the two maps would break the assignments,
+/// so this wrapper performs a cycle crossover instead:
+/// the keys are split into cycles, such that swapping the values of all of the keys in a cycle keeps both maps valid.
+/// Each cycle is then swapped or not according to the crossover method, as if it was a single chromosome.
+///
+/// Both maps must be bijections between the same set of keys and the same set of values,
+/// meaning that they must have the same keys, and that each value must appear exactly once in each map.
+/// This is checked in debug builds; otherwise, the cycles are cut short where the maps don't match.
+///
+/// The keys are visited in ascending order, and each cycle takes one decision of the crossover method.
+/// Since the number of cycles depends on both maps, the wrapper then discards decisions until it has taken one per key:
+/// each map thus accounts for `len()` chromosomes in [Genome::size_hint], whatever its cycles are.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use genomic::prelude::*;
+/// use genomic::wrapper::KeyPermutation;
+///
+/// struct Assignment {
+///     workers: HashMap<&'static str, u32>,
+/// }
+///
+/// impl Genome for Assignment {
+///     // ...
+///     # fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///         crossover.with(&mut KeyPermutation, &mut self.workers, &mut other.workers);
+///     }
+///
+///     fn size_hint(&self) -> usize {
+///         self.workers.len()
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyPermutation;
+
+impl<K, V> CrossoverWrapper<&mut HashMap<K, V>> for KeyPermutation
+where
+    K: Eq + Hash + Ord + Clone,
+    V: Eq + Hash,
+{
+    fn crossover_with(
+        &mut self,
+        map_left: &mut HashMap<K, V>,
+        map_right: &mut HashMap<K, V>,
+        crossover: &mut Crossover<impl Rng>,
+    ) {
+        debug_assert!(
+            map_left.len() == map_right.len()
+                && map_left.keys().all(|key| map_right.contains_key(key)),
+            "KeyPermutation requires both maps to have the same keys"
+        );
+
+        let mut keys = map_left.keys().cloned().collect::<Vec<_>>();
+        keys.sort_unstable();
+
+        let cycles = {
+            let key_of_value = map_left
+                .iter()
+                .map(|(key, value)| (value, key))
+                .collect::<HashMap<_, _>>();
+            debug_assert_eq!(
+                key_of_value.len(),
+                map_left.len(),
+                "KeyPermutation requires each value to appear once"
+            );
+
+            let mut visited = HashSet::with_capacity(keys.len());
+            let mut cycles = Vec::new();
+
+            for start in keys.iter() {
+                if visited.contains(start) {
+                    continue;
+                }
+
+                let mut cycle = Vec::new();
+                let mut key = start;
+                while visited.insert(key) {
+                    cycle.push(key.clone());
+
+                    match map_right.get(key).and_then(|value| key_of_value.get(value)) {
+                        Some(next) => key = next,
+                        None => break,
+                    }
+                }
+
+                cycles.push(cycle);
+            }
+
+            cycles
+        };
+
+        let padding = keys.len() - cycles.len();
+
+        for cycle in cycles {
+            crossover.custom(|should_flip, _rng| {
+                if should_flip {
+                    for key in cycle.iter() {
+                        if let (Some(value_left), Some(value_right)) =
+                            (map_left.get_mut(key), map_right.get_mut(key))
+                        {
+                            std::mem::swap(value_left, value_right);
+                        }
+                    }
+                }
+            });
+        }
+
+        for _ in 0..padding {
+            crossover.custom(|_, _| {});
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traverse::CrossoverMethod;
+
+    struct Assignment(HashMap<u32, char>);
+
+    impl Genome for Assignment {
+        fn mutate(&mut self, _mutator: &mut Mutator<impl Rng>) {
+            unimplemented!()
+        }
+
+        fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+            crossover.with(&mut KeyPermutation, &mut self.0, &mut other.0);
+        }
+
+        fn size_hint(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    fn sorted_values(map: &HashMap<u32, char>) -> Vec<char> {
+        let mut values = map.values().copied().collect::<Vec<_>>();
+        values.sort_unstable();
+        values
+    }
+
+    #[test]
+    fn test_key_permutation() {
+        let mut rng = crate::test_rng();
+        let left = HashMap::from_iter((0..8).zip("abcdefgh".chars()));
+        let right = HashMap::from_iter((0..8).zip("badcfehg".chars()));
+
+        let mut mixed = 0;
+        for _ in 0..100 {
+            let mut child_left = Assignment(left.clone());
+            let mut child_right = Assignment(right.clone());
+
+            crate::crossover(
+                &mut child_left,
+                &mut child_right,
+                CrossoverMethod::Uniform(1.0),
+                &mut rng,
+            );
+
+            for child in [&child_left, &child_right] {
+                assert_eq!(child.0.len(), 8);
+                assert_eq!(
+                    sorted_values(&child.0),
+                    "abcdefgh".chars().collect::<Vec<_>>()
+                );
+            }
+            if child_left.0 != left && child_left.0 != right {
+                mixed += 1;
+            }
+        }
+        assert!(mixed > 50);
+
+        // Swapping every cycle swaps the whole maps
+        let mut child_left = Assignment(left.clone());
+        let mut child_right = Assignment(right.clone());
+        crate::crossover(
+            &mut child_left,
+            &mut child_right,
+            CrossoverMethod::FixedPoints(vec![0]),
+            &mut rng,
+        );
+        assert_eq!(child_left.0, right);
+        assert_eq!(child_right.0, left);
+    }
+
+    #[test]
+    fn test_key_permutation_size() {
+        let mut rng = crate::test_rng();
+        // Two cycles: `{0, 1}` and `{2, 3, 4}`
+        let mut left = Assignment(HashMap::from_iter((0..5).zip("abcde".chars())));
+        let mut right = Assignment(HashMap::from_iter((0..5).zip("baecd".chars())));
+
+        let mask = crate::crossover_with_mask(
+            &mut left,
+            &mut right,
+            CrossoverMethod::FixedPoints(vec![1]),
+            &mut rng,
+        );
+        assert_eq!(mask, [false, true, true, true, true]);
+        assert_eq!(sorted_values(&left.0), "abcde".chars().collect::<Vec<_>>());
+        assert_eq!(left.0[&0], 'a');
+        assert_eq!(left.0[&2], 'e');
+    }
+}
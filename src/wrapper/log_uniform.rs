@@ -0,0 +1,159 @@
+use std::ops::RangeInclusive;
+
+use super::*;
+
+/// A wrapper around positive floating-point values, performing the same uniform random walk as [UniformCh],
+/// but in log space.
+///
+/// This means that the mutation steps are multiplicative rather than additive,
+/// which is better suited for parameters spanning several orders of magnitude, like learning rates.
+///
+/// If `rate` is `1.0`, then the mutated value will become any number between `min` and `max`,
+/// with each order of magnitude being equally likely.
+/// As with [UniformCh], the width of the random walk is also scaled by the [magnitude](Mutator::magnitude) of the mutator.
+///
+/// # Panics
+///
+/// Mutating a value panics if `min` is not strictly positive, or if `max` is smaller than `min`;
+/// [LogUniformCh::new] already checks this, but setting the fields directly or deserializing the wrapper doesn't.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::LogUniformCh;
+///
+/// struct Hyperparameters {
+///     pub learning_rate: f64,
+/// }
+///
+/// impl Genome for Hyperparameters {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.with(&mut LogUniformCh::new(1e-5, 1e-1), &mut self.learning_rate);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogUniformCh<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T: Into<f64> + Copy> LogUniformCh<T> {
+    /// Creates a new wrapper, where the value will be mutated between `min` and `max`, in log space.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is not strictly positive, or if `max` is smaller than `min`.
+    pub fn new(min: T, max: T) -> Self {
+        assert!(min.into() > 0.0, "`min` should be strictly positive");
+        assert!(
+            max.into() >= min.into(),
+            "`max` should not be smaller than `min`"
+        );
+
+        Self { min, max }
+    }
+}
+
+impl<T: Into<f64> + Copy> From<RangeInclusive<T>> for LogUniformCh<T> {
+    fn from(value: RangeInclusive<T>) -> Self {
+        let (min, max) = value.into_inner();
+
+        Self::new(min, max)
+    }
+}
+
+impl<T> From<LogUniformCh<T>> for RangeInclusive<T> {
+    fn from(value: LogUniformCh<T>) -> Self {
+        (value.min)..=(value.max)
+    }
+}
+
+macro_rules! impl_log_uniform_float {
+    ( $type:ty ) => {
+        impl MutationWrapper<&mut $type> for LogUniformCh<$type> {
+            fn mutate_with(&mut self, value: &mut $type, mutator: &mut Mutator<impl Rng>) {
+                assert!(
+                    self.min > 0.0 && self.max >= self.min,
+                    "`min` should be strictly positive, and `max` should not be smaller than `min`"
+                );
+
+                let mut log_value = value.clamp(self.min, self.max).ln();
+                UniformCh::new(self.min.ln(), self.max.ln()).mutate_with(&mut log_value, mutator);
+
+                *value = log_value.exp().clamp(self.min, self.max);
+            }
+        }
+    };
+}
+
+impl_log_uniform_float!(f32);
+impl_log_uniform_float!(f64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_log_uniform_range() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = LogUniformCh::new(1e-5f64, 1e-1);
+
+        let mut small = 0;
+        let mut value = 1e-3;
+        for _ in 0..1000 {
+            Mutator::new(1.0, &mut rng).with(&mut wrapper, &mut value);
+            assert!((1e-5..=1e-1).contains(&value));
+            if value < 1e-3 {
+                small += 1;
+            }
+        }
+
+        // Half of the values should be below `1e-3`
+        assert!(small > 400);
+    }
+
+    #[test]
+    fn test_log_uniform_multiplicative() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = LogUniformCh::new(1e-5f64, 1e-1);
+
+        // The walk spans `0.1 * ln(1e4) / 2 ≈ 0.46` in log space, in each direction
+        for start in [1e-4, 1e-2] {
+            for _ in 0..100 {
+                let mut value = start;
+                Mutator::new(0.1, &mut rng).with(&mut wrapper, &mut value);
+
+                let ratio = value / start;
+                assert!(ratio > 0.63 && ratio < 1.59);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_log_uniform_non_positive() {
+        LogUniformCh::new(0.0f32, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_log_uniform_invalid_fields() {
+        let mut wrapper = LogUniformCh {
+            min: -1.0,
+            max: 1.0,
+        };
+        Mutator::new(0.5, crate::test_rng()).with(&mut wrapper, &mut 0.5f64);
+    }
+}
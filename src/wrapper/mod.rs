@@ -4,15 +4,76 @@ mod reorder;
 pub use reorder::*;
 
 mod uniform;
-pub use uniform::UniformCh;
+pub use uniform::{BoundaryPolicy, UniformCh};
 
 mod fixed;
-pub use fixed::FixedBits;
+pub use fixed::{BitWidth, FixedBits, FixedBitsError};
+
+mod set;
+pub use set::SetCh;
+
+mod chain;
+pub use chain::Chain;
+
+mod bounded;
+pub use bounded::BoundedCh;
+
+mod field_wise;
+pub use field_wise::FieldWise;
+
+mod wrapping;
+pub use wrapping::WrappingUniformCh;
+
+mod log_uniform;
+pub use log_uniform::LogUniformCh;
+
+mod dist;
+pub use dist::DistCh;
+
+mod option;
+pub use option::OptionCh;
+
+mod checksum;
+pub use checksum::ChecksumCh;
+
+mod key_permutation;
+pub use key_permutation::KeyPermutation;
+
+mod identified;
+pub use identified::IdentifiedCrossover;
+
+mod grouped;
+pub use grouped::GroupedUniform;
+
+mod sensitivity;
+pub use sensitivity::Sensitivity;
+
+mod invert;
+pub use invert::InvertBits;
+
+mod shared_shift;
+pub use shared_shift::SharedShiftCh;
+
+mod dynamic;
+pub use dynamic::{DynCrossoverWrapper, DynMutationWrapper, OperatorRegistry};
+
+mod plastic;
+pub use plastic::Plastic;
+
+mod tuple;
 
 /// In some cases, you may want to alter the behavior of the mutation operation,
 /// without having to change the types in your structures.
 ///
 /// The `MutationWrapper` trait lets you do so, by calling `mutator.with(&mut wrapper_instance, &mut self.thing)`.
+///
+/// Unlike the deprecated wrappers of the [chromosome] module, which hold the value they mutate
+/// and expose it through `into_inner`, the wrappers in this module are stateless:
+/// they only hold their parameters, and the value is passed by reference to [MutationWrapper::mutate_with].
+///
+/// A tuple of wrappers (of up to 6 elements) is itself a wrapper for the tuples of values of the same size,
+/// applying each wrapper to the value at the same position:
+/// `mutator.with(&mut (UniformCh::new(0, 10), FixedBits::new(3)), &mut self.pair)`.
 pub trait MutationWrapper<G> {
     /// The equivalent of [Genome::mutate], with the wrapper added as first parameter.
     fn mutate_with(&mut self, genome: G, mutator: &mut Mutator<impl rand::Rng>);
@@ -29,3 +90,134 @@ pub trait CrossoverWrapper<G> {
         crossover: &mut Crossover<impl rand::Rng>,
     );
 }
+
+/// A wrapper implementing both [MutationWrapper] and [CrossoverWrapper] for the same type of genome.
+///
+/// Symmetric operators, whose mutation and crossover are meant to work together (for instance,
+/// a bounded mutation and a crossover blending values within the same bounds), can be stored as a single value
+/// and passed to both [Mutator::with] and [Crossover::with], so that both operations share the same parameters.
+///
+/// This trait is implemented for every type implementing both wrapper traits; there is no need to implement it by hand.
+/// It is mostly useful as a bound, to require a single value that can drive both operations.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::Operator;
+///
+/// struct Neuron<O> {
+///     bias: f64,
+///     operator: O,
+/// }
+///
+/// impl<O: for<'a> Operator<&'a mut f64>> Genome for Neuron<O> {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.with(&mut self.operator, &mut self.bias);
+///     }
+///
+///     fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///         crossover.with(&mut self.operator, &mut self.bias, &mut other.bias);
+///     }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+pub trait Operator<G>: MutationWrapper<G> + CrossoverWrapper<G> {}
+
+impl<G, W: MutationWrapper<G> + CrossoverWrapper<G>> Operator<G> for W {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traverse::CrossoverMethod;
+
+    /// Mutates within `[min, max]`, and blends both parents by a random factor, clamped to `[min, max]`.
+    struct Blend {
+        uniform: UniformCh<f64>,
+    }
+
+    impl MutationWrapper<&mut f64> for Blend {
+        fn mutate_with(&mut self, value: &mut f64, mutator: &mut Mutator<impl Rng>) {
+            self.uniform.mutate_with(value, mutator);
+        }
+    }
+
+    impl CrossoverWrapper<&mut f64> for Blend {
+        fn crossover_with(
+            &mut self,
+            value_left: &mut f64,
+            value_right: &mut f64,
+            crossover: &mut Crossover<impl Rng>,
+        ) {
+            crossover.custom(|should_flip, rng| {
+                if should_flip {
+                    let factor = rng.gen_range(0.0..=1.0);
+                    let (left, right) = (*value_left, *value_right);
+                    *value_left = (left * factor + right * (1.0 - factor))
+                        .clamp(self.uniform.min, self.uniform.max);
+                    *value_right = (right * factor + left * (1.0 - factor))
+                        .clamp(self.uniform.min, self.uniform.max);
+                }
+            });
+        }
+    }
+
+    struct Weight(f64);
+
+    impl Weight {
+        fn operator() -> impl for<'a> Operator<&'a mut f64> {
+            Blend {
+                uniform: UniformCh::new(-1.0, 1.0),
+            }
+        }
+    }
+
+    impl Genome for Weight {
+        fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+            mutator.with(&mut Self::operator(), &mut self.0);
+        }
+
+        fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+            crossover.with(&mut Self::operator(), &mut self.0, &mut other.0);
+        }
+
+        fn size_hint(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn test_operator() {
+        let mut rng = crate::test_rng();
+
+        let mut mutated = 0;
+        let mut blended = 0;
+        for _ in 0..100 {
+            let mut weight = Weight(0.5);
+            crate::mutate(&mut weight, 1.0, &mut rng);
+            assert!((-1.0..=1.0).contains(&weight.0));
+            if weight.0 != 0.5 {
+                mutated += 1;
+            }
+
+            let mut left = Weight(-1.0);
+            let mut right = Weight(1.0);
+            crate::crossover(
+                &mut left,
+                &mut right,
+                CrossoverMethod::FixedPoints(vec![0]),
+                &mut rng,
+            );
+            assert!((-1.0..=1.0).contains(&left.0));
+            assert!((left.0 + right.0).abs() < 1e-9);
+            if left.0.abs() < 1.0 {
+                blended += 1;
+            }
+        }
+        assert!(mutated > 90);
+        assert!(blended > 90);
+    }
+}
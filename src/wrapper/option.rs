@@ -0,0 +1,107 @@
+use rand::RngCore;
+
+use super::*;
+
+/// A wrapper around optional chromosomes, controlling how often `None` appears
+/// and how fresh values are generated.
+///
+/// With a probability of `rate * 0.5`, the presence of the value is re-rolled:
+/// the option becomes `Some` with a probability of `presence`, and `None` otherwise.
+/// A value that was already present is kept, while a missing value is created by calling `generator`.
+/// If the presence isn't re-rolled, then the inner value (if any) is mutated as usual.
+///
+/// In the long run, the option is thus `Some` about `presence` of the time.
+/// See the implementation of [Chromosome] for `Option<T>` for a simpler alternative.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::OptionCh;
+/// use genomic::Rng;
+///
+/// struct Shield {
+///     pub strength: Option<u8>,
+/// }
+///
+/// impl Genome for Shield {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         let mut wrapper = OptionCh::new(0.2, |rng: &mut dyn rand::RngCore| rng.gen_range(1..=10));
+///         mutator.with(&mut wrapper, &mut self.strength);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptionCh<F> {
+    pub presence: f64,
+    pub generator: F,
+}
+
+impl<F> OptionCh<F> {
+    /// Creates a new wrapper, where the value is present with a probability of `presence`,
+    /// and new values are created with `generator`.
+    pub fn new(presence: f64, generator: F) -> Self {
+        Self {
+            presence,
+            generator,
+        }
+    }
+}
+
+impl<T, F> MutationWrapper<&mut Option<T>> for OptionCh<F>
+where
+    T: Chromosome,
+    F: FnMut(&mut dyn RngCore) -> T,
+{
+    fn mutate_with(&mut self, value: &mut Option<T>, mutator: &mut Mutator<impl Rng>) {
+        let rate = mutator.get_rate();
+        let rng = mutator.get_rng();
+
+        if rng.gen_bool((rate * 0.5).clamp(0.0, 1.0)) {
+            if rng.gen_bool(self.presence.clamp(0.0, 1.0)) {
+                if value.is_none() {
+                    *value = Some((self.generator)(rng));
+                }
+            } else {
+                *value = None;
+            }
+        } else if let Some(inner) = value {
+            inner.mutate(rate, rng);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_option_presence() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = OptionCh::new(0.2, |rng: &mut dyn RngCore| rng.gen_range(1..=10u8));
+
+        let mut value = None;
+        let mut some = 0;
+        for _ in 0..10000 {
+            Mutator::new(1.0, &mut rng).with(&mut wrapper, &mut value);
+            if value.is_some() {
+                some += 1;
+            }
+        }
+        assert!((1500..2500).contains(&some));
+
+        let mut value = None;
+        let mut wrapper = OptionCh::new(1.0, |_: &mut dyn RngCore| 0u8);
+        Mutator::new(2.0, &mut rng).with(&mut wrapper, &mut value);
+        assert_eq!(value, Some(0));
+    }
+}
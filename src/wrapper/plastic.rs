@@ -0,0 +1,101 @@
+use std::ops::Range;
+
+use super::*;
+
+/// Wraps a list of genes, so that only the genes whose index is within `active_range` are mutated.
+///
+/// This is meant for developmental encodings, where each gene is expressed during a specific phase
+/// of the individual's life, and only some of these phases should be subject to mutation.
+/// The genes outside of `active_range` are left untouched, and so are all of the genes if `active_range` is empty.
+/// The part of `active_range` beyond the end of the list is ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::Plastic;
+///
+/// struct Development {
+///     pub stages: Vec<u8>,
+///     pub maturity: usize,
+/// }
+///
+/// impl Genome for Development {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         // The stages that were already reached are fixed
+///         mutator.with(&mut Plastic::new(self.maturity..self.stages.len()), &mut self.stages);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         self.stages.len() - self.maturity
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plastic {
+    pub active_range: Range<usize>,
+}
+
+impl Plastic {
+    /// Creates a new wrapper, which will only mutate the genes whose index is within `active_range`.
+    pub fn new(active_range: Range<usize>) -> Self {
+        Self { active_range }
+    }
+}
+
+impl From<Range<usize>> for Plastic {
+    fn from(active_range: Range<usize>) -> Self {
+        Self { active_range }
+    }
+}
+
+impl<G: Genome> MutationWrapper<&mut [G]> for Plastic {
+    fn mutate_with(&mut self, genes: &mut [G], mutator: &mut Mutator<impl Rng>) {
+        let end = self.active_range.end.min(genes.len());
+        let start = self.active_range.start.min(end);
+
+        mutator.iter(&mut genes[start..end]);
+    }
+}
+
+impl<G: Genome> MutationWrapper<&mut Vec<G>> for Plastic {
+    fn mutate_with(&mut self, genes: &mut Vec<G>, mutator: &mut Mutator<impl Rng>) {
+        self.mutate_with(genes.as_mut_slice(), mutator);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_plastic() {
+        let mut rng = crate::test_rng();
+
+        let mut genes = vec![0u32; 10];
+        Mutator::new(1.0, &mut rng).with(&mut Plastic::new(3..6), &mut genes);
+        for (index, gene) in genes.iter().enumerate() {
+            assert_eq!(*gene != 0, (3..6).contains(&index), "{genes:?}");
+        }
+
+        // Empty ranges are a no-op
+        #[allow(clippy::reversed_empty_ranges)]
+        for range in [5..5, 6..3, 10..12] {
+            let mut genes = vec![0u32; 10];
+            Mutator::new(1.0, &mut rng).with(&mut Plastic::new(range), &mut genes);
+            assert_eq!(genes, vec![0; 10]);
+        }
+
+        // The range is truncated to the length of the list
+        let mut genes = [[0u16; 2]; 10];
+        Mutator::new(1.0, &mut rng).with(&mut Plastic::new(8..20), &mut genes[..]);
+        assert!(genes[..8].iter().all(|gene| *gene == [0, 0]));
+        assert!(genes[8..].iter().all(|gene| *gene != [0, 0]));
+    }
+}
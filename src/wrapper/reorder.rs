@@ -3,8 +3,19 @@ use super::*;
 /// Re-orders the genes in the wrapped genome, instead of modifying the chromosomes one-by-one.
 /// The wrapped genome does not need to implement the `Genome` trait,
 /// although it needs to implement `IntoIterator` for its .
-#[derive(Clone, Copy, PartialEq)]
+///
+/// Defaults to [ReorderGenome::Swap].
+///
+/// The number of swaps follows a binomial distribution with `n = len - 1` trials and a probability of `rate`,
+/// so `(len - 1) * rate` swaps are performed on average.
+/// A rate of `0.0` thus never re-orders the genome, and a rate of `1.0` always performs `len - 1` swaps.
+///
+/// The positions of the swaps are sampled in a platform-independent way,
+/// so a seeded rng yields the same swaps on 32-bit and 64-bit targets.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReorderGenome {
+    #[default]
     Swap,
 }
 
@@ -16,27 +27,16 @@ where
         let iter = genome.into_iter();
         // TODO: do this in-place or re-use the vector across calls?
         let mut vec = iter.collect::<Vec<_>>();
-        if vec.len() == 0 {
+        if vec.len() < 2 {
             return;
         }
 
-        let swaps = ((vec.len() as f64 - 1.0) * mutator.get_rate()) as usize;
-        let swaps = if mutator.get_rate() > 0.0 && swaps == 0 {
-            1
-        } else {
-            swaps
-        };
-
+        let rate = mutator.get_rate();
         let rng = mutator.get_rng();
-        for _ in 0..swaps {
-            let mut index_a = rng.gen_range(0..vec.len());
-            let mut index_b = rng.gen_range(0..vec.len() - 1);
-            if index_b >= index_a {
-                index_b += 1;
-            } else {
-                std::mem::swap(&mut index_a, &mut index_b);
-            }
+        let swaps = swap_count(vec.len(), rate, rng);
 
+        for _ in 0..swaps {
+            let (index_a, index_b) = distinct_indices(vec.len(), rng);
             debug_assert!(index_a < index_b);
 
             let split = vec.split_at_mut(index_b);
@@ -54,6 +54,241 @@ impl ReorderGenome {
     }
 }
 
+/// Returns the number of swaps to perform on a genome of `length` genes,
+/// drawn from a binomial distribution with `length - 1` trials and a probability of `rate`.
+fn swap_count(length: usize, rate: f64, rng: &mut impl rand::Rng) -> usize {
+    let distribution = rand::distributions::Bernoulli::new(rate.clamp(0.0, 1.0))
+        .expect("`rate` should not be NaN");
+
+    (1..length).filter(|_| rng.sample(distribution)).count()
+}
+
+/// Like [ReorderGenome::Swap], re-orders the genes in the wrapped genome by swapping pairs of genes,
+/// but with the pairs being chosen according to a cost function.
+///
+/// `cost(a, b)` should return the cost of swapping the genes at positions `a` and `b` (with `a < b`),
+/// lower costs meaning better moves.
+/// Each pair is then chosen with a weight of `1 / (1 + cost(a, b) - min_cost)`,
+/// so that the cheapest swaps are the most likely, while the other swaps remain possible.
+/// If all of the costs are equal, then the swaps are chosen uniformly.
+///
+/// The number of swaps is drawn as with [ReorderGenome::Swap].
+/// Note that the cost of every pair is computed for each swap, which takes `O(n²)` calls to `cost`.
+///
+/// This is a separate type rather than a variant of [ReorderGenome], since a variant holding `cost`
+/// would make `ReorderGenome` generic over `F`, and `ReorderGenome::Swap` would then need a type annotation.
+#[derive(Clone, Copy)]
+pub struct GuidedSwap<F> {
+    pub cost: F,
+}
+
+impl<F: Fn(usize, usize) -> f64> GuidedSwap<F> {
+    /// Creates a new wrapper, where `cost(a, b)` is the cost of swapping the genes at positions `a` and `b`.
+    pub fn new(cost: F) -> Self {
+        Self { cost }
+    }
+
+    /// Returns the number of genes in `genome`, which is what this wrapper contributes to [Genome::size_hint].
+    pub fn size_hint<G>(&self, genome: G) -> usize
+    where
+        G: IntoIterator,
+    {
+        genome.into_iter().count()
+    }
+}
+
+impl<'a, G, Ch: 'a, F> MutationWrapper<G> for GuidedSwap<F>
+where
+    G: IntoIterator<Item = &'a mut Ch>,
+    F: Fn(usize, usize) -> f64,
+{
+    fn mutate_with(&mut self, genome: G, mutator: &mut Mutator<impl rand::Rng>) {
+        let mut vec = genome.into_iter().collect::<Vec<_>>();
+        if vec.len() < 2 {
+            return;
+        }
+
+        let rate = mutator.get_rate();
+        let rng = mutator.get_rng();
+        let swaps = swap_count(vec.len(), rate, rng);
+
+        for _ in 0..swaps {
+            let pairs = (0..vec.len())
+                .flat_map(|a| ((a + 1)..vec.len()).map(move |b| (a, b)))
+                .map(|(a, b)| (a, b, (self.cost)(a, b)))
+                .collect::<Vec<_>>();
+            let min_cost = pairs
+                .iter()
+                .map(|&(_, _, cost)| cost)
+                .fold(f64::INFINITY, f64::min);
+
+            let weights = pairs
+                .iter()
+                .map(|&(_, _, cost)| 1.0 / (1.0 + cost - min_cost));
+            let index = match rand::distributions::WeightedIndex::new(weights) {
+                Ok(distribution) => rng.sample(distribution),
+                Err(_) => crate::gen_index(rng, pairs.len()),
+            };
+            let (index_a, index_b, _) = pairs[index];
+
+            let split = vec.split_at_mut(index_b);
+            std::mem::swap(split.0[index_a], split.1[0]);
+        }
+    }
+}
+
+/// Re-orders a tour (a sequence visited in order, like the cities of a travelling salesman) by reversing segments of it,
+/// which is known as the 2-opt move.
+///
+/// Reversing the segment between two positions only replaces the two edges at its ends,
+/// while [ReorderGenome::Swap] replaces up to four edges, so this operator preserves much more of the structure of the tour.
+/// The tour stays a permutation of its original values.
+///
+/// The number of reversals is drawn as with [ReorderGenome::Swap], and the bounds of each segment are picked uniformly.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::{OrOpt, TwoOpt};
+///
+/// struct Tour {
+///     pub cities: Vec<u32>,
+/// }
+///
+/// impl Genome for Tour {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator
+///             .with(&mut TwoOpt, &mut self.cities)
+///             .with(&mut OrOpt::default(), &mut self.cities);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         2
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TwoOpt;
+
+impl<T> MutationWrapper<&mut [T]> for TwoOpt {
+    fn mutate_with(&mut self, tour: &mut [T], mutator: &mut Mutator<impl rand::Rng>) {
+        if tour.len() < 2 {
+            return;
+        }
+
+        let rate = mutator.get_rate();
+        let rng = mutator.get_rng();
+
+        for _ in 0..swap_count(tour.len(), rate, rng) {
+            reverse_segment(tour, rng);
+        }
+    }
+}
+
+impl<T> MutationWrapper<&mut Vec<T>> for TwoOpt {
+    fn mutate_with(&mut self, tour: &mut Vec<T>, mutator: &mut Mutator<impl rand::Rng>) {
+        self.mutate_with(tour.as_mut_slice(), mutator);
+    }
+}
+
+/// Reverses a random segment of at least two elements of `tour`, which should contain at least two elements.
+fn reverse_segment<T>(tour: &mut [T], rng: &mut impl rand::Rng) {
+    let (start, end) = distinct_indices(tour.len(), rng);
+
+    tour[start..=end].reverse();
+}
+
+/// Re-orders a tour by moving short segments of it to another position, which is known as the Or-opt move.
+///
+/// Each move takes a segment of `1` to `max_segment` consecutive elements, and re-inserts it elsewhere in the tour,
+/// keeping its direction; this replaces three edges of the tour, and keeps the segment itself intact.
+/// The tour stays a permutation of its original values.
+///
+/// The number of moves is drawn as with [ReorderGenome::Swap].
+/// The length of each segment is picked uniformly (and is at most `len - 1`),
+/// then its position, then its new position.
+/// The default value of `OrOpt` uses `max_segment: 3`, as is common in the literature.
+/// See [TwoOpt] for an example.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrOpt {
+    /// The maximum length of the moved segments; a value of `0` is treated as `1`.
+    pub max_segment: usize,
+}
+
+impl Default for OrOpt {
+    fn default() -> Self {
+        Self { max_segment: 3 }
+    }
+}
+
+impl OrOpt {
+    /// Creates a new wrapper, which will move segments of up to `max_segment` elements (at least `1`).
+    pub fn new(max_segment: usize) -> Self {
+        Self { max_segment }
+    }
+}
+
+impl<T> MutationWrapper<&mut [T]> for OrOpt {
+    fn mutate_with(&mut self, tour: &mut [T], mutator: &mut Mutator<impl rand::Rng>) {
+        if tour.len() < 2 {
+            return;
+        }
+
+        let rate = mutator.get_rate();
+        let rng = mutator.get_rng();
+
+        for _ in 0..swap_count(tour.len(), rate, rng) {
+            move_segment(tour, self.max_segment, rng);
+        }
+    }
+}
+
+impl<T> MutationWrapper<&mut Vec<T>> for OrOpt {
+    fn mutate_with(&mut self, tour: &mut Vec<T>, mutator: &mut Mutator<impl rand::Rng>) {
+        self.mutate_with(tour.as_mut_slice(), mutator);
+    }
+}
+
+/// Moves a random segment of `1` to `max_segment` elements of `tour` to another position.
+/// `tour` should contain at least two elements.
+fn move_segment<T>(tour: &mut [T], max_segment: usize, rng: &mut impl rand::Rng) {
+    let max_segment = max_segment.clamp(1, tour.len() - 1);
+    let length = crate::gen_index(rng, max_segment) + 1;
+
+    // There are `len - length + 1` positions for the segment, one of which is its current position
+    let start = crate::gen_index(rng, tour.len() - length + 1);
+    let mut target = crate::gen_index(rng, tour.len() - length);
+    if target >= start {
+        target += 1;
+    }
+
+    if target < start {
+        tour[target..start + length].rotate_right(length);
+    } else {
+        tour[start..target + length].rotate_left(length);
+    }
+}
+
+/// Returns two distinct indices lower than `length`, in increasing order.
+fn distinct_indices(length: usize, rng: &mut impl rand::Rng) -> (usize, usize) {
+    let index_a = crate::gen_index(rng, length);
+    let index_b = crate::gen_index(rng, length - 1);
+
+    if index_b >= index_a {
+        (index_a, index_b + 1)
+    } else {
+        (index_b, index_a)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use rand::Rng;
@@ -61,6 +296,67 @@ mod test {
 
     use super::*;
 
+    #[test]
+    fn test_default() {
+        assert_eq!(ReorderGenome::default(), ReorderGenome::Swap);
+    }
+
+    #[test]
+    fn test_reorder_seeded() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1402);
+        let mut values = (0..8).collect::<Vec<u32>>();
+
+        Mutator::new(0.5, &mut rng).with(&mut ReorderGenome::Swap, &mut values);
+
+        // This sequence should be the same on every target, whatever the width of `usize`
+        assert_eq!(values, vec![0, 1, 3, 2, 4, 5, 7, 6]);
+    }
+
+    #[test]
+    fn test_guided_swap() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = GuidedSwap::new(|a, b| if (a, b) == (0, 1) { -10.0 } else { 0.0 });
+
+        let (mut guided, mut single) = (0, 0);
+        for _ in 0..2000 {
+            let mut values = vec![0u32, 1, 2, 3, 4];
+            Mutator::new(0.25, &mut rng).with(&mut wrapper, &mut values);
+
+            let mut sorted = values.clone();
+            sorted.sort();
+            assert_eq!(sorted, vec![0, 1, 2, 3, 4]);
+
+            // Only look at the mutations made of a single swap
+            let moved = (0..5)
+                .filter(|&index| values[index] != index as u32)
+                .count();
+            if moved == 2 {
+                single += 1;
+                if values[0] == 1 {
+                    guided += 1;
+                }
+            }
+        }
+
+        // The guided swap has a weight of 1, while the 9 others have a weight of 1/11
+        assert!(guided as f64 > single as f64 * 0.45);
+
+        let mut uniform = GuidedSwap::new(|_, _| 1.0);
+        let mut swapped = [0; 5];
+        for _ in 0..1000 {
+            let mut values = vec![0u32, 1, 2, 3, 4];
+            Mutator::new(0.5, &mut rng).with(&mut uniform, &mut values);
+            for (index, value) in values.into_iter().enumerate() {
+                if value != index as u32 {
+                    swapped[index] += 1;
+                }
+            }
+        }
+        assert!(swapped.into_iter().all(|count| count > 250));
+    }
+
     #[test]
     fn test_reorder_vec() {
         struct ReorderVec {
@@ -85,7 +381,7 @@ mod test {
             values: vec![0, 1, 2, 3],
         };
 
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::test_rng();
         for _ in 0..100 {
             crate::mutate(&mut instance, 0.25, &mut rng);
 
@@ -122,7 +418,7 @@ mod test {
             values: vec![0, 1, 2, 3].into_boxed_slice(),
         };
 
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::test_rng();
         for _ in 0..100 {
             crate::mutate(&mut instance, 0.25, &mut rng);
 
@@ -159,7 +455,7 @@ mod test {
             values: HashMap::from([(0, 0), (1, 1), (2, 2), (3, 3)]),
         };
 
-        let mut rng = rand::thread_rng();
+        let mut rng = crate::test_rng();
         for _ in 0..100 {
             crate::mutate(&mut instance, 0.25, &mut rng);
 
@@ -171,4 +467,109 @@ mod test {
             assert!(counts.into_iter().all(|c| c == 1));
         }
     }
+
+    /// Returns the number of edges of the cyclic tour `after` that aren't in `before`, ignoring their direction.
+    fn broken_edges(before: &[u32], after: &[u32]) -> usize {
+        let edges = |tour: &[u32]| {
+            (0..tour.len())
+                .map(|index| {
+                    let (a, b) = (tour[index], tour[(index + 1) % tour.len()]);
+                    (a.min(b), a.max(b))
+                })
+                .collect::<std::collections::HashSet<_>>()
+        };
+
+        edges(after).difference(&edges(before)).count()
+    }
+
+    fn assert_permutation(tour: &[u32]) {
+        let mut sorted = tour.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..tour.len() as u32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_two_opt() {
+        let mut rng = crate::test_rng();
+        let original = (0..20).collect::<Vec<u32>>();
+
+        let mut moved = 0;
+        for _ in 0..1000 {
+            let mut tour = original.clone();
+            reverse_segment(&mut tour, &mut rng);
+            assert_permutation(&tour);
+            assert!(broken_edges(&original, &tour) <= 2);
+            if tour != original {
+                moved += 1;
+            }
+        }
+        assert_eq!(moved, 1000);
+
+        for length in [0, 1, 2, 3, 10] {
+            let mut tour = (0..length).collect::<Vec<u32>>();
+            for rate in [0.1, 0.5, 1.0] {
+                Mutator::new(rate, &mut rng).with(&mut TwoOpt, &mut tour);
+                assert_permutation(&tour);
+            }
+        }
+
+        let mut tour = original.clone();
+        Mutator::new(0.0, &mut rng).with(&mut TwoOpt, &mut tour);
+        assert_eq!(tour, original);
+    }
+
+    #[test]
+    fn test_or_opt() {
+        let mut rng = crate::test_rng();
+        let original = (0..20).collect::<Vec<u32>>();
+
+        let mut broken = [0; 4];
+        for _ in 0..1000 {
+            let mut tour = original.clone();
+            move_segment(&mut tour, 3, &mut rng);
+            assert_permutation(&tour);
+            assert_ne!(tour, original);
+            broken[broken_edges(&original, &tour)] += 1;
+        }
+        // Most moves replace three edges, while moving a segment next to its neighbour replaces two
+        assert!(broken[3] > broken[2] && broken[2] > 0);
+
+        // Segments are never as long as the whole tour
+        for _ in 0..100 {
+            let mut tour = vec![0u32, 1];
+            move_segment(&mut tour, 10, &mut rng);
+            assert_eq!(tour, vec![1, 0]);
+        }
+
+        for length in [0, 1, 2, 3, 10] {
+            let mut tour = (0..length).collect::<Vec<u32>>();
+            for (rate, max_segment) in [(0.1, 0), (0.5, 3), (1.0, 20)] {
+                Mutator::new(rate, &mut rng).with(&mut OrOpt::new(max_segment), &mut tour);
+                assert_permutation(&tour);
+            }
+        }
+    }
+
+    #[test]
+    fn test_swap_count_binomial() {
+        let mut rng = crate::test_rng();
+
+        for (length, rate) in [(2, 0.99), (2, 0.5), (10, 0.1), (10, 0.5), (33, 0.25)] {
+            let trials = 10000;
+            let total = (0..trials)
+                .map(|_| swap_count(length, rate, &mut rng))
+                .sum::<usize>();
+            let mean = total as f64 / trials as f64;
+            let expected = (length - 1) as f64 * rate;
+
+            assert!(
+                (mean - expected).abs() < 0.05 * expected.max(1.0),
+                "{mean} {expected}"
+            );
+        }
+
+        assert_eq!(swap_count(10, 0.0, &mut rng), 0);
+        assert_eq!(swap_count(10, 1.0, &mut rng), 9);
+        assert_eq!(swap_count(1, 1.0, &mut rng), 0);
+    }
 }
@@ -0,0 +1,129 @@
+use super::*;
+
+/// Scales the mutation rate of each gene of a sequence by a per-gene weight,
+/// to focus the mutation on the genes that matter the most (as found by a prior sensitivity analysis, for instance).
+///
+/// The `i`-th gene is mutated with the current rate multiplied by `weights[i]`, as with [Mutator::multiply_rate]:
+/// the resulting rate is thus clamped between `0.0` and `1.0`. Genes past the end of `weights` use a weight of `1.0`.
+///
+/// The weights are used as-is, so their scale matters: weights averaging to `2.0` double the overall mutation rate.
+/// To only redistribute the mutations between genes while keeping the same average rate,
+/// create the wrapper with [Sensitivity::normalized], which rescales the weights so that their mean is `1.0`.
+///
+/// Each gene accounts for its own [Genome::size_hint], which can be summed with [Sensitivity::size_hint].
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::Sensitivity;
+///
+/// struct Controller {
+///     gains: Vec<u8>,
+/// }
+///
+/// impl Genome for Controller {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         // The first gain is much more influential than the others
+///         let mut sensitivity = Sensitivity::normalized(vec![4.0, 1.0, 1.0, 0.5]);
+///         mutator.with(&mut sensitivity, &mut self.gains);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         Sensitivity::size_hint(&self.gains)
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sensitivity {
+    pub weights: Vec<f64>,
+}
+
+impl Sensitivity {
+    /// Creates a new wrapper, where the `i`-th gene has its rate multiplied by `weights[i]`.
+    pub fn new(weights: Vec<f64>) -> Self {
+        Self { weights }
+    }
+
+    /// Creates a new wrapper, rescaling `weights` so that their mean is `1.0`.
+    ///
+    /// If the weights sum up to zero (or if there are none), then they are kept as-is.
+    pub fn normalized(mut weights: Vec<f64>) -> Self {
+        let sum = weights.iter().sum::<f64>();
+
+        if sum > 0.0 {
+            let scale = weights.len() as f64 / sum;
+            weights.iter_mut().for_each(|weight| *weight *= scale);
+        }
+
+        Self { weights }
+    }
+
+    /// Returns the number of chromosomes in `genome`, which is the sum of the size hints of its genes.
+    pub fn size_hint<'a, G: Genome + 'a>(genome: impl IntoIterator<Item = &'a G>) -> usize {
+        genome.into_iter().map(Genome::size_hint).sum()
+    }
+}
+
+impl<'a, G: Genome + 'a, I> MutationWrapper<I> for Sensitivity
+where
+    I: IntoIterator<Item = &'a mut G>,
+{
+    fn mutate_with(&mut self, genome: I, mutator: &mut Mutator<impl Rng>) {
+        for (index, gene) in genome.into_iter().enumerate() {
+            let weight = self.weights.get(index).copied().unwrap_or(1.0);
+
+            mutator.multiply_rate(weight, |mutator| {
+                mutator.genome(gene);
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalized() {
+        assert_eq!(
+            Sensitivity::normalized(vec![4.0, 1.0, 1.0, 2.0]).weights,
+            vec![2.0, 0.5, 0.5, 1.0]
+        );
+        assert_eq!(
+            Sensitivity::normalized(vec![0.0, 0.0]).weights,
+            vec![0.0, 0.0]
+        );
+        assert!(Sensitivity::normalized(vec![]).weights.is_empty());
+    }
+
+    #[test]
+    fn test_sensitivity() {
+        let mut rng = crate::test_rng();
+        let mut sensitivity = Sensitivity::normalized(vec![0.1, 1.0, 4.0, 0.0]);
+        let mut changes = [0; 5];
+
+        for _ in 0..2000 {
+            let mut genes = vec![false; 5];
+            Mutator::new(0.2, &mut rng).with(&mut sensitivity, &mut genes);
+
+            for (changes, gene) in changes.iter_mut().zip(genes) {
+                *changes += gene as u32;
+            }
+        }
+
+        // The mean weight is `1.275`, so the rates are about `0.016`, `0.157`, `0.627` and `0.0`,
+        // and the gene without a weight keeps a rate of `0.2` (each boolean flipping with half of its rate)
+        assert!(changes[0] < 50, "{changes:?}");
+        assert!(changes[2] > 3 * changes[1], "{changes:?}");
+        assert!(changes[1] > 3 * changes[0], "{changes:?}");
+        assert_eq!(changes[3], 0, "{changes:?}");
+        assert!((150..250).contains(&changes[4]), "{changes:?}");
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use super::*;
+
+/// Mutates a set of genes by replacing some of its members with new values,
+/// picked from a list of `candidates`.
+///
+/// Each member of the set has a probability of `rate * 0.5` to be replaced, like the other operators.
+/// A replacement removes a random member and inserts a random candidate that isn't already in the set,
+/// so the size of the set never changes.
+/// The removed member is picked among the sorted members of the set,
+/// so that the outcome of a seeded mutation doesn't depend on the iteration order of the `HashSet`.
+/// If all of the candidates are already in the set, then nothing happens.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use genomic::prelude::*;
+/// use genomic::wrapper::SetCh;
+///
+/// struct Team {
+///     members: HashSet<u32>,
+/// }
+///
+/// impl Genome for Team {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.with(&mut SetCh::new((0..20).collect()), &mut self.members);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         self.members.len()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetCh<T> {
+    pub candidates: Vec<T>,
+}
+
+impl<T> SetCh<T> {
+    /// Creates a new wrapper, which will pick the new members of the set from `candidates`.
+    pub fn new(candidates: Vec<T>) -> Self {
+        Self { candidates }
+    }
+}
+
+impl<T> From<Vec<T>> for SetCh<T> {
+    fn from(candidates: Vec<T>) -> Self {
+        Self { candidates }
+    }
+}
+
+impl<T: Hash + Ord + Clone> MutationWrapper<&mut HashSet<T>> for SetCh<T> {
+    fn mutate_with(&mut self, set: &mut HashSet<T>, mutator: &mut Mutator<impl Rng>) {
+        let rate = mutator.get_rate();
+        debug_assert!(rate >= 0.0);
+
+        let rng = mutator.get_rng();
+        let replacements = (0..set.len())
+            .filter(|_| rng.gen_bool((rate * 0.5).clamp(0.0, 1.0)))
+            .count();
+
+        for _ in 0..replacements {
+            let available = self
+                .candidates
+                .iter()
+                .filter(|candidate| !set.contains(candidate))
+                .collect::<Vec<_>>();
+            if available.is_empty() {
+                return;
+            }
+
+            let inserted = available[crate::gen_index(rng, available.len())].clone();
+
+            let mut members = set.iter().collect::<Vec<_>>();
+            members.sort_unstable();
+            let removed = members[crate::gen_index(rng, members.len())].clone();
+
+            set.remove(&removed);
+            set.insert(inserted);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    struct Team {
+        members: HashSet<u32>,
+    }
+
+    impl Genome for Team {
+        fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+            mutator.with(&mut SetCh::new((0..10).collect()), &mut self.members);
+        }
+
+        fn crossover(&mut self, _other: &mut Self, _crossover: &mut Crossover<impl Rng>) {
+            unimplemented!()
+        }
+
+        fn size_hint(&self) -> usize {
+            self.members.len()
+        }
+    }
+
+    #[test]
+    fn test_set_size_preserved() {
+        let mut instance = Team {
+            members: HashSet::from([0, 1, 2, 3]),
+        };
+
+        let mut rng = crate::test_rng();
+        let mut seen = HashSet::new();
+        for _ in 0..100 {
+            crate::mutate(&mut instance, 0.5, &mut rng);
+
+            assert_eq!(instance.members.len(), 4);
+            assert!(instance.members.iter().all(|&member| member < 10));
+            seen.extend(instance.members.iter().copied());
+        }
+
+        assert_eq!(seen.len(), 10);
+    }
+
+    #[test]
+    fn test_set_seeded() {
+        // Both sets hold the same members, but each `HashSet` has its own iteration order
+        let new = || Team {
+            members: (0..5).collect(),
+        };
+
+        for seed in 0..20 {
+            let (mut left, mut right) = (new(), new());
+            crate::mutate(&mut left, 1.0, &mut rand::rngs::StdRng::seed_from_u64(seed));
+            crate::mutate(
+                &mut right,
+                1.0,
+                &mut rand::rngs::StdRng::seed_from_u64(seed),
+            );
+
+            assert_eq!(left.members, right.members);
+        }
+    }
+
+    #[test]
+    fn test_set_all_candidates_present() {
+        let mut instance = Team {
+            members: (0..10).collect(),
+        };
+
+        let mut rng = crate::test_rng();
+        for _ in 0..10 {
+            crate::mutate(&mut instance, 1.0, &mut rng);
+
+            assert_eq!(instance.members, (0..10).collect());
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use super::*;
+
+/// Wraps a list of scalar values, so that they are all shifted by the same random delta,
+/// keeping the differences between them intact.
+///
+/// This is meant for genomes where the relationship between the genes matters more than their absolute values,
+/// like a melody that can be transposed.
+/// The delta is drawn like the step of [UniformCh]: if `rate` is `1.0`, it can be as large as half of `max - min`,
+/// and its range is also scaled by the [magnitude](Mutator::magnitude) of the mutator.
+///
+/// The delta is then clamped so that every value stays between `min` and `max` (inclusive),
+/// which keeps the whole list shifted by the same amount.
+/// If some values are already out of bounds, the delta can't be clamped this way,
+/// so each value is clamped individually after being shifted.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::SharedShiftCh;
+///
+/// struct Melody {
+///     pub notes: Vec<i8>,
+/// }
+///
+/// impl Genome for Melody {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         // Transpose the whole melody, without leaving the range of a piano
+///         mutator.with(&mut SharedShiftCh::new(-39, 48), &mut self.notes);
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SharedShiftCh<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T> SharedShiftCh<T> {
+    /// Creates a new wrapper, where the values will be shifted together while staying between `min` and `max`.
+    pub fn new(min: T, max: T) -> Self {
+        Self { min, max }
+    }
+}
+
+macro_rules! impl_shared_shift_int {
+    ( $type:ty ) => {
+        impl MutationWrapper<&mut [$type]> for SharedShiftCh<$type> {
+            fn mutate_with(&mut self, values: &mut [$type], mutator: &mut Mutator<impl Rng>) {
+                let (Some(&lowest), Some(&highest)) = (values.iter().min(), values.iter().max())
+                else {
+                    return;
+                };
+
+                // The values are shifted as `i128`s, which can hold the difference between any two `i64` or `u64`
+                // (and thus `isize` or `usize`)
+                let (min, max) = (self.min as i128, self.max as i128);
+                let spread = (mutator.get_rate() * mutator.magnitude()).min(1.0);
+                let half_range = (((max - min) as f64 * spread / 2.0) as i128).max(0);
+                let delta = mutator.get_rng().gen_range(-half_range..=half_range);
+
+                if lowest >= self.min && highest <= self.max {
+                    let delta = delta.clamp(min - lowest as i128, max - highest as i128);
+                    for value in values.iter_mut() {
+                        *value = (*value as i128 + delta) as $type;
+                    }
+                } else {
+                    for value in values.iter_mut() {
+                        *value = (*value as i128 + delta).clamp(min, max) as $type;
+                    }
+                }
+            }
+        }
+
+        impl MutationWrapper<&mut Vec<$type>> for SharedShiftCh<$type> {
+            fn mutate_with(&mut self, values: &mut Vec<$type>, mutator: &mut Mutator<impl Rng>) {
+                self.mutate_with(values.as_mut_slice(), mutator);
+            }
+        }
+    };
+}
+
+macro_rules! impl_shared_shift_float {
+    ( $type:ty ) => {
+        impl MutationWrapper<&mut [$type]> for SharedShiftCh<$type> {
+            fn mutate_with(&mut self, values: &mut [$type], mutator: &mut Mutator<impl Rng>) {
+                if values.is_empty() {
+                    return;
+                }
+
+                let lowest = values.iter().copied().fold(<$type>::INFINITY, <$type>::min);
+                let highest = values
+                    .iter()
+                    .copied()
+                    .fold(<$type>::NEG_INFINITY, <$type>::max);
+
+                let spread = (mutator.get_rate() * mutator.magnitude()).min(1.0);
+                let half_range = (self.max - self.min) * spread as $type / 2.0;
+                let delta = if half_range > 0.0 {
+                    mutator.get_rng().gen_range(-half_range..half_range)
+                } else {
+                    0.0
+                };
+
+                let delta = if lowest >= self.min && highest <= self.max {
+                    delta.clamp(self.min - lowest, self.max - highest)
+                } else {
+                    delta
+                };
+
+                for value in values.iter_mut() {
+                    // Even with a clamped delta, rounding errors could push a value just past a bound
+                    *value = (*value + delta).clamp(self.min, self.max);
+                }
+            }
+        }
+
+        impl MutationWrapper<&mut Vec<$type>> for SharedShiftCh<$type> {
+            fn mutate_with(&mut self, values: &mut Vec<$type>, mutator: &mut Mutator<impl Rng>) {
+                self.mutate_with(values.as_mut_slice(), mutator);
+            }
+        }
+    };
+}
+
+impl_shared_shift_int!(u8);
+impl_shared_shift_int!(u16);
+impl_shared_shift_int!(u32);
+impl_shared_shift_int!(u64);
+impl_shared_shift_int!(usize);
+impl_shared_shift_int!(i8);
+impl_shared_shift_int!(i16);
+impl_shared_shift_int!(i32);
+impl_shared_shift_int!(i64);
+impl_shared_shift_int!(isize);
+
+impl_shared_shift_float!(f32);
+impl_shared_shift_float!(f64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shared_delta() {
+        let mut rng = crate::test_rng();
+        let mut deltas = std::collections::HashSet::new();
+
+        for _ in 0..1000 {
+            let original = vec![-10i32, 0, 3, 20];
+            let mut values = original.clone();
+            Mutator::new(0.5, &mut rng).with(&mut SharedShiftCh::new(-100, 100), &mut values);
+
+            let delta = values[0] - original[0];
+            assert!(values
+                .iter()
+                .zip(&original)
+                .all(|(value, original)| value - original == delta));
+            assert!((-50..=50).contains(&delta));
+            deltas.insert(delta);
+        }
+        assert!(deltas.len() > 90);
+
+        for _ in 0..1000 {
+            let original = [0.25f64, 0.5, 0.75];
+            let mut values = original;
+            Mutator::new(1.0, &mut rng).with(&mut SharedShiftCh::new(0.0, 1.0), &mut values[..]);
+
+            let delta = values[0] - original[0];
+            for (value, original) in values.iter().zip(original) {
+                assert!((value - original - delta).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_clamped_delta() {
+        let mut rng = crate::test_rng();
+        let mut deltas = std::collections::HashSet::new();
+
+        for _ in 0..1000 {
+            // The values can only go down by 2, or up by 1
+            let mut values = vec![2u8, 5, 9];
+            Mutator::new(1.0, &mut rng).with(&mut SharedShiftCh::new(0, 10), &mut values);
+
+            let delta = values[0] as i32 - 2;
+            assert_eq!(values, [2, 5, 9].map(|value| (value + delta) as u8));
+            deltas.insert(delta);
+        }
+        assert_eq!(deltas.len(), 4);
+
+        let mut values = vec![0usize, 3];
+        Mutator::new(1.0, &mut rng).with(&mut SharedShiftCh::new(0, 4), &mut values);
+        assert!(values == [0, 3] || values == [1, 4]);
+
+        // Values out of bounds are clamped individually
+        for _ in 0..100 {
+            let mut values = vec![-5.0f32, 0.5, 3.0];
+            Mutator::new(1.0, &mut rng).with(&mut SharedShiftCh::new(0.0, 1.0), &mut values);
+            assert!(values.iter().all(|value| (0.0..=1.0).contains(value)));
+        }
+
+        let mut values: Vec<i64> = Vec::new();
+        Mutator::new(1.0, &mut rng).with(&mut SharedShiftCh::new(0, 10), &mut values);
+        assert!(values.is_empty());
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+// Tuples of wrappers mutate tuples of values, with each wrapper being applied to the value at the same position.
+// This lets you write `mutator.with(&mut (UniformCh::new(0, 10), FixedBits::new(3)), &mut self.pair)`,
+// with each element of the tuple accounting for one chromosome in `Genome::size_hint`.
+macro_rules! impl_wrapper_tuple {
+    ( $( $wrapper:ident, $value:ident => $id:tt ),+ ) => {
+        impl<$($wrapper, $value),+> MutationWrapper<&mut ($($value,)+)> for ($($wrapper,)+)
+        where
+            $($wrapper: for<'a> MutationWrapper<&'a mut $value>),+
+        {
+            fn mutate_with(&mut self, values: &mut ($($value,)+), mutator: &mut Mutator<impl Rng>) {
+                $(self.$id.mutate_with(&mut values.$id, mutator);)+
+            }
+        }
+    }
+}
+
+impl_wrapper_tuple!(W1, T1 => 0, W2, T2 => 1);
+impl_wrapper_tuple!(W1, T1 => 0, W2, T2 => 1, W3, T3 => 2);
+impl_wrapper_tuple!(W1, T1 => 0, W2, T2 => 1, W3, T3 => 2, W4, T4 => 3);
+impl_wrapper_tuple!(W1, T1 => 0, W2, T2 => 1, W3, T3 => 2, W4, T4 => 3, W5, T5 => 4);
+impl_wrapper_tuple!(W1, T1 => 0, W2, T2 => 1, W3, T3 => 2, W4, T4 => 3, W5, T5 => 4, W6, T6 => 5);
+
+#[cfg(test)]
+mod test {
+    use rand::Rng;
+
+    use super::*;
+    use crate::wrapper::{FixedBits, UniformCh};
+
+    #[test]
+    fn test_tuple_with() {
+        struct Pair((i32, u8));
+
+        impl Genome for Pair {
+            fn mutate(&mut self, mutator: &mut Mutator<impl Rng>) {
+                mutator.with(&mut (UniformCh::new(-5, 5), FixedBits::new(2)), &mut self.0);
+            }
+
+            fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl Rng>) {
+                crossover.genome(&mut self.0, &mut other.0);
+            }
+
+            fn size_hint(&self) -> usize {
+                2
+            }
+        }
+
+        let mut instance = Pair((100, 0));
+
+        let mut rng = crate::test_rng();
+        for _ in 0..100 {
+            crate::mutate(&mut instance, 1.0, &mut rng);
+
+            assert!((-5..=5).contains(&instance.0 .0));
+            assert!(instance.0 .1 < 4);
+        }
+    }
+}
@@ -7,6 +7,10 @@ use super::*;
 /// to uniform random walk.
 ///
 /// If `rate` is `1.0`, then the mutated value will become any number between `min` and `max`.
+/// The width of the random walk is also scaled by the [magnitude](Mutator::magnitude) of the mutator.
+///
+/// What happens when the random walk reaches `min` or `max` is controlled by the [boundary](BoundaryPolicy) field,
+/// which defaults to [BoundaryPolicy::Clamp].
 ///
 /// The [MutationWrapper] trait is implemented for various scalar types on this trait.
 ///
@@ -41,15 +45,74 @@ use super::*;
 ///     }
 /// }
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformCh<T> {
     pub min: T,
     pub max: T,
+    pub boundary: BoundaryPolicy,
+}
+
+/// How [UniformCh] handles the random walk reaching the bounds of its interval.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BoundaryPolicy {
+    /// The random walk is shifted to fit within `[min, max]`.
+    /// Values close to the bounds are thus more likely to be picked than values in the middle of the interval.
+    #[default]
+    Clamp,
+    /// Steps going past a bound bounce back into the interval, by as much as they overshot.
+    /// The walk keeps its width, but values near the bounds are still slightly more likely than in the middle.
+    Reflect,
+    /// Steps going past a bound continue from the other bound, as if `min` and `max` were adjacent.
+    /// The density stays uniform across the whole interval; this is best suited for periodic values.
+    Wrap,
 }
 
 impl<T> UniformCh<T> {
     /// Creates a new chromosome, where the scalar value will uniformly be mutated between `min` and `max`.
     pub fn new(min: T, max: T) -> Self {
-        Self { min, max }
+        Self {
+            min,
+            max,
+            boundary: BoundaryPolicy::Clamp,
+        }
+    }
+
+    /// Creates a new chromosome like [UniformCh::new], checking that `min` is not greater than `max`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use genomic::wrapper::UniformCh;
+    ///
+    /// assert!(UniformCh::try_new(0.0, 1.0).is_ok());
+    /// assert!(UniformCh::try_new(1.0, 0.0).is_err());
+    /// assert!(UniformCh::try_new(f64::NAN, 1.0).is_err());
+    /// ```
+    pub fn try_new(min: T, max: T) -> Result<Self, crate::GenomicError>
+    where
+        T: PartialOrd,
+    {
+        if min <= max {
+            Ok(Self::new(min, max))
+        } else {
+            Err(crate::GenomicError::InvalidRange)
+        }
+    }
+
+    /// Returns the wrapper with its [boundary policy](BoundaryPolicy) set to `boundary`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use genomic::wrapper::{BoundaryPolicy, UniformCh};
+    ///
+    /// let uniform = UniformCh::new(0.0, 1.0).with_boundary(BoundaryPolicy::Reflect);
+    /// assert_eq!(uniform.boundary, BoundaryPolicy::Reflect);
+    /// ```
+    pub fn with_boundary(mut self, boundary: BoundaryPolicy) -> Self {
+        self.boundary = boundary;
+        self
     }
 }
 
@@ -57,7 +120,7 @@ impl<T> From<RangeInclusive<T>> for UniformCh<T> {
     fn from(value: RangeInclusive<T>) -> Self {
         let (min, max) = value.into_inner();
 
-        Self { min, max }
+        Self::new(min, max)
     }
 }
 
@@ -72,12 +135,43 @@ macro_rules! impl_uniform_int {
         impl MutationWrapper<&mut $type> for UniformCh<$type> {
             fn mutate_with(&mut self, value: &mut $type, mutator: &mut Mutator<impl Rng>) {
                 let range = self.max - self.min;
-                let range = (range as f64 * mutator.get_rate()) as $type;
+                let spread = (mutator.get_rate() * mutator.magnitude()).min(1.0);
+                let range = (range as f64 * spread) as $type;
                 let half_range_low = range / 2;
                 let half_range_high = range / 2 + range % 2;
 
                 *value = (*value).clamp(self.min, self.max);
 
+                if self.boundary != BoundaryPolicy::Clamp {
+                    let step = mutator.get_rng().gen_range(0..=range);
+
+                    *value = if step < half_range_low {
+                        let distance = half_range_low - step;
+                        if *value - self.min >= distance {
+                            *value - distance
+                        } else {
+                            let overshoot = distance - (*value - self.min);
+                            match self.boundary {
+                                BoundaryPolicy::Wrap => self.max - (overshoot - 1),
+                                _ => self.min + overshoot,
+                            }
+                        }
+                    } else {
+                        let distance = step - half_range_low;
+                        if self.max - *value >= distance {
+                            *value + distance
+                        } else {
+                            let overshoot = distance - (self.max - *value);
+                            match self.boundary {
+                                BoundaryPolicy::Wrap => self.min + (overshoot - 1),
+                                _ => self.max - overshoot,
+                            }
+                        }
+                    };
+
+                    return;
+                }
+
                 let range = if *value - self.min <= half_range_low {
                     self.min..=self.min.saturating_add(range).min(self.max)
                 } else if self.max - *value <= half_range_high {
@@ -99,9 +193,48 @@ macro_rules! impl_uniform_float {
         impl MutationWrapper<&mut $type> for UniformCh<$type> {
             fn mutate_with(&mut self, value: &mut $type, mutator: &mut Mutator<impl Rng>) {
                 let range = self.max - self.min;
-                let range = range * mutator.get_rate() as $type;
+                let spread = (mutator.get_rate() * mutator.magnitude()).min(1.0);
+                let range = range * spread as $type;
                 let half_range = range / 2.0;
 
+                if self.boundary != BoundaryPolicy::Clamp {
+                    let period = self.max - self.min;
+                    let offset = if half_range > 0.0 {
+                        mutator.get_rng().gen_range(-half_range..half_range)
+                    } else {
+                        0.0
+                    };
+                    let position = (*value).clamp(self.min, self.max) + offset - self.min;
+
+                    *value = if period <= 0.0 {
+                        self.min
+                    } else if self.boundary == BoundaryPolicy::Wrap {
+                        let wrapped = self.min + position.rem_euclid(period);
+                        // `rem_euclid` may round up to `period`
+                        if wrapped >= self.max {
+                            self.min
+                        } else {
+                            wrapped
+                        }
+                    } else {
+                        let folded = position.rem_euclid(2.0 * period);
+                        let reflected = if folded > period {
+                            2.0 * period - folded
+                        } else {
+                            folded
+                        };
+                        (self.min + reflected).clamp(self.min, self.max)
+                    };
+
+                    return;
+                }
+
+                // An empty range can't be sampled from, and wouldn't move the value anyway
+                if range <= 0.0 {
+                    *value = (*value).clamp(self.min, self.max);
+                    return;
+                }
+
                 let range = if *value - self.min < half_range {
                     self.min..(self.min + range)
                 } else if self.max - *value < half_range {
@@ -134,3 +267,141 @@ impl_uniform_int!(isize);
 
 impl_uniform_float!(f32);
 impl_uniform_float!(f64);
+
+/// The random walk is performed on `f32`s, then rounded back to the nearest `f16`,
+/// so that scaling the width of the interval by the rate can't overflow `f16`'s narrow range.
+///
+/// Only available with the `half` feature flag.
+#[cfg(feature = "half")]
+impl MutationWrapper<&mut half::f16> for UniformCh<half::f16> {
+    fn mutate_with(&mut self, value: &mut half::f16, mutator: &mut Mutator<impl Rng>) {
+        let mut value_f32 = value.to_f32();
+        UniformCh::new(self.min.to_f32(), self.max.to_f32())
+            .with_boundary(self.boundary)
+            .mutate_with(&mut value_f32, mutator);
+
+        *value = half::f16::from_f32(value_f32).clamp(self.min, self.max);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const POLICIES: [BoundaryPolicy; 3] = [
+        BoundaryPolicy::Clamp,
+        BoundaryPolicy::Reflect,
+        BoundaryPolicy::Wrap,
+    ];
+
+    #[test]
+    fn test_rate_zero() {
+        let mut rng = crate::test_rng();
+
+        for policy in POLICIES {
+            let mut wrapper = UniformCh::new(-1.0f64, 1.0).with_boundary(policy);
+            let mut value = 0.25;
+            Mutator::new(0.0, &mut rng).with(&mut wrapper, &mut value);
+            assert_eq!(value, 0.25);
+        }
+
+        let mut value = 3.0f32;
+        Mutator::new(0.0, &mut rng).with(&mut UniformCh::new(-1.0, 1.0), &mut value);
+        assert_eq!(value, 1.0);
+    }
+
+    #[test]
+    fn test_boundary_stays_in_range() {
+        let mut rng = crate::test_rng();
+
+        for boundary in POLICIES {
+            let mut float = UniformCh::new(-1.0f64, 3.0).with_boundary(boundary);
+            let mut int = UniformCh::new(-10i8, 20).with_boundary(boundary);
+
+            for rate in [0.1, 0.5, 1.0, 2.0] {
+                let mut float_value = 3.0;
+                let mut int_value = 20;
+                for _ in 0..1000 {
+                    let mut mutator = Mutator::new(rate, &mut rng);
+                    mutator
+                        .with(&mut float, &mut float_value)
+                        .with(&mut int, &mut int_value);
+                    assert!((-1.0..=3.0).contains(&float_value));
+                    assert!((-10..=20).contains(&int_value));
+                }
+            }
+        }
+    }
+
+    /// Counts where a value sitting on `min` lands, in each quarter of `[0, 100]`, after a mutation with a rate of `0.5`.
+    fn quarters_from_min(boundary: BoundaryPolicy) -> ([u32; 4], [u32; 4]) {
+        let mut rng = crate::test_rng();
+        let mut float = UniformCh::new(0.0f64, 100.0).with_boundary(boundary);
+        let mut int = UniformCh::new(0u8, 100).with_boundary(boundary);
+
+        let mut float_quarters = [0; 4];
+        let mut int_quarters = [0; 4];
+        for _ in 0..4000 {
+            let mut float_value = 0.0;
+            let mut int_value = 0;
+            Mutator::new(0.5, &mut rng)
+                .with(&mut float, &mut float_value)
+                .with(&mut int, &mut int_value);
+
+            float_quarters[((float_value / 25.0) as usize).min(3)] += 1;
+            // `25`, `50` and `100` are included in the quarter they end
+            int_quarters[(int_value.saturating_sub(1) as usize / 25).min(3)] += 1;
+        }
+
+        (float_quarters, int_quarters)
+    }
+
+    #[test]
+    fn test_boundary_edge_density() {
+        // Clamping shifts the walk to `[0, 50]`
+        for quarters in <[_; 2]>::from(quarters_from_min(BoundaryPolicy::Clamp)) {
+            assert!((1700..2300).contains(&quarters[0]), "{quarters:?}");
+            assert!((1700..2300).contains(&quarters[1]), "{quarters:?}");
+            assert_eq!(quarters[2] + quarters[3], 0, "{quarters:?}");
+        }
+
+        // Reflecting folds `[-25, 0]` onto `[0, 25]`
+        for quarters in <[_; 2]>::from(quarters_from_min(BoundaryPolicy::Reflect)) {
+            assert!(quarters[0] > 3800, "{quarters:?}");
+            assert_eq!(quarters[2] + quarters[3], 0, "{quarters:?}");
+        }
+
+        // Wrapping moves `[-25, 0]` to `[75, 100]`
+        for quarters in <[_; 2]>::from(quarters_from_min(BoundaryPolicy::Wrap)) {
+            assert!((1700..2300).contains(&quarters[0]), "{quarters:?}");
+            assert!((1700..2300).contains(&quarters[3]), "{quarters:?}");
+            assert_eq!(quarters[1] + quarters[2], 0, "{quarters:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn test_f16() {
+        use half::f16;
+
+        let mut rng = crate::test_rng();
+        // The width of this interval overflows `f16`
+        let (min, max) = (f16::from_f32(-60000.0), f16::from_f32(60000.0));
+
+        for boundary in POLICIES {
+            let mut wrapper = UniformCh::new(min, max).with_boundary(boundary);
+
+            for rate in [0.01, 0.5, 1.0, 2.0] {
+                let mut values = [min, max, f16::ZERO, f16::INFINITY];
+                for _ in 0..1000 {
+                    let mut mutator = Mutator::new(rate, &mut rng);
+                    for value in values.iter_mut() {
+                        mutator.with(&mut wrapper, value);
+                        assert!(value.is_finite(), "{value}");
+                        assert!(min <= *value && *value <= max, "{value}");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::ops::Range;
+
+use super::*;
+
+/// A wrapper around floating-point values, performing a uniform random walk
+/// on the interval `[min, max)`, where `min` and `max` are considered adjacent.
+///
+/// Unlike [UniformCh], which clamps the value at the bounds, values that would go past `max`
+/// wrap around to `min` and vice-versa. This is best suited for angles and other periodic values.
+///
+/// If `rate` is `1.0`, then the mutated value will become any number between `min` and `max`.
+/// The width of the random walk is also scaled by the [magnitude](Mutator::magnitude) of the mutator.
+/// If the interval is empty (`max` isn't greater than `min`) or infinite, then the value is left untouched.
+///
+/// # Example
+///
+/// ```rust
+/// use genomic::prelude::*;
+/// use genomic::wrapper::WrappingUniformCh;
+///
+/// struct Heading {
+///     pub angle: f32,
+/// }
+///
+/// impl Genome for Heading {
+///     fn mutate(&mut self, mutator: &mut Mutator<impl rand::Rng>) {
+///         mutator.with(
+///             &mut WrappingUniformCh::new(0.0, std::f32::consts::TAU),
+///             &mut self.angle,
+///         );
+///     }
+///
+///     // ...
+///     # fn crossover(&mut self, other: &mut Self, crossover: &mut Crossover<impl rand::Rng>) {
+///     #     unimplemented!();
+///     # }
+///
+///     fn size_hint(&self) -> usize {
+///         1
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WrappingUniformCh<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T> WrappingUniformCh<T> {
+    /// Creates a new wrapper, where the value will be mutated within `[min, max)`, wrapping around at the bounds.
+    pub fn new(min: T, max: T) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T> From<Range<T>> for WrappingUniformCh<T> {
+    fn from(value: Range<T>) -> Self {
+        Self {
+            min: value.start,
+            max: value.end,
+        }
+    }
+}
+
+impl<T> From<WrappingUniformCh<T>> for Range<T> {
+    fn from(value: WrappingUniformCh<T>) -> Self {
+        (value.min)..(value.max)
+    }
+}
+
+macro_rules! impl_wrapping_float {
+    ( $type:ty ) => {
+        impl MutationWrapper<&mut $type> for WrappingUniformCh<$type> {
+            fn mutate_with(&mut self, value: &mut $type, mutator: &mut Mutator<impl Rng>) {
+                let period = self.max - self.min;
+                // Also catches `NaN` bounds
+                if !period.is_finite() || period <= 0.0 {
+                    return;
+                }
+
+                let spread = mutator.get_rate() * mutator.magnitude();
+                let half_range = period * spread as $type / 2.0;
+
+                let offset = if half_range > 0.0 {
+                    mutator.get_rng().gen_range(-half_range..half_range)
+                } else {
+                    0.0
+                };
+
+                let wrapped = self.min + (*value + offset - self.min).rem_euclid(period);
+
+                // `rem_euclid` may round up to `period`
+                *value = if wrapped >= self.max {
+                    self.min
+                } else {
+                    wrapped
+                };
+            }
+        }
+    };
+}
+
+impl_wrapping_float!(f32);
+impl_wrapping_float!(f64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_wrapping_stays_in_range() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = WrappingUniformCh::new(-1.0f64, 3.0);
+
+        for rate in [0.1, 0.5, 1.0] {
+            let mut value = 0.0;
+            for _ in 0..1000 {
+                Mutator::new(rate, &mut rng).with(&mut wrapper, &mut value);
+                assert!((-1.0..3.0).contains(&value));
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrapping_empty_interval() {
+        let mut rng = crate::test_rng();
+
+        for (min, max) in [
+            (1.0f64, 1.0),
+            (2.0, 1.0),
+            (0.0, f64::NAN),
+            (0.0, f64::INFINITY),
+        ] {
+            for rate in [0.0, 1.0] {
+                let mut value = 1.5;
+                Mutator::new(rate, &mut rng)
+                    .with(&mut WrappingUniformCh::new(min, max), &mut value);
+                assert_eq!(value, 1.5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrapping_wraps_around() {
+        let mut rng = crate::test_rng();
+        let mut wrapper = WrappingUniformCh::new(0.0f32, std::f32::consts::TAU);
+
+        let mut wrapped = 0;
+        for _ in 0..1000 {
+            let mut value = std::f32::consts::TAU - 0.1;
+            Mutator::new(0.1, &mut rng).with(&mut wrapper, &mut value);
+
+            assert!((0.0..std::f32::consts::TAU).contains(&value));
+            // The mutation step is at most `0.1 * TAU / 2`
+            if value < 1.0 {
+                wrapped += 1;
+            } else {
+                assert!(value > std::f32::consts::TAU - 0.5);
+            }
+        }
+
+        assert!(wrapped > 100);
+    }
+}